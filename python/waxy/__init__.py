@@ -2,14 +2,19 @@
 
 from waxy._waxy import (
     AUTO,
+    AUTO_FILL,
+    AUTO_FIT,
     MAX_CONTENT,
     MIN_CONTENT,
     AlignContent,
     AlignItems,
     Auto,
+    AutoFill,
+    AutoFit,
     AvailableSize,
     BoxSizing,
     ChildIndexOutOfBounds,
+    CycleDetected,
     Definite,
     Display,
     FitContent,
@@ -18,6 +23,8 @@ from waxy._waxy import (
     Fraction,
     GridAutoFlow,
     GridLine,
+    GridNamedLine,
+    GridNamedSpan,
     GridPlacement,
     GridSpan,
     InvalidChildNode,
@@ -33,6 +40,7 @@ from waxy._waxy import (
     Length,
     Line,
     MaxContent,
+    MeasureResult,
     MinContent,
     Minmax,
     NodeId,
@@ -41,12 +49,17 @@ from waxy._waxy import (
     Point,
     Position,
     Rect,
+    Repeat,
     Size,
     Style,
     TaffyException,
     TaffyTree,
     TextAlign,
     WaxyException,
+    WrongTree,
+    intern_style,
+    simple_measure,
+    tracks,
 )
 
 type AvailableSpaceValue = Definite | MinContent | MaxContent
@@ -55,8 +68,11 @@ type AvailableSpaceValue = Definite | MinContent | MaxContent
 type DimensionValue = Length | Percent | Auto
 """A dimension value used for sizes, margins, insets, and flex-basis: Length, Percent, or Auto."""
 
-type GridPlacementValue = GridLine | GridSpan | Auto
-"""A grid placement value used in GridPlacement start and end: GridLine, GridSpan, or Auto."""
+type GridPlacementValue = GridLine | GridSpan | GridNamedLine | GridNamedSpan | Auto
+"""A grid placement value used in GridPlacement start and end: GridLine, GridSpan, GridNamedLine, GridNamedSpan, or Auto."""
+
+type GridTemplateValue = GridTrackValue | Repeat
+"""A grid_template_* entry: a grid track or a Repeat of tracks."""
 
 type GridTrackMaxValue = Length | Percent | Auto | MinContent | MaxContent | Fraction | FitContent
 """Maximum sizing bound for a Minmax grid track."""
@@ -74,15 +90,20 @@ type LengthPercentageValue = Length | Percent
 
 __all__ = [
     "AUTO",
+    "AUTO_FILL",
+    "AUTO_FIT",
     "MAX_CONTENT",
     "MIN_CONTENT",
     "AlignContent",
     "AlignItems",
     "Auto",
+    "AutoFill",
+    "AutoFit",
     "AvailableSize",
     "AvailableSpaceValue",
     "BoxSizing",
     "ChildIndexOutOfBounds",
+    "CycleDetected",
     "Definite",
     "DimensionValue",
     "Display",
@@ -92,11 +113,14 @@ __all__ = [
     "Fraction",
     "GridAutoFlow",
     "GridLine",
+    "GridNamedLine",
+    "GridNamedSpan",
     "GridPlacement",
     "GridPlacementValue",
     "GridSpan",
     "GridTrackMaxValue",
     "GridTrackMinValue",
+    "GridTemplateValue",
     "GridTrackValue",
     "InvalidChildNode",
     "InvalidGridLine",
@@ -112,6 +136,7 @@ __all__ = [
     "LengthPercentageValue",
     "Line",
     "MaxContent",
+    "MeasureResult",
     "MinContent",
     "Minmax",
     "NodeId",
@@ -120,10 +145,15 @@ __all__ = [
     "Point",
     "Position",
     "Rect",
+    "Repeat",
     "Size",
     "Style",
     "TaffyException",
     "TaffyTree",
     "TextAlign",
     "WaxyException",
+    "WrongTree",
+    "intern_style",
+    "simple_measure",
+    "tracks",
 ]
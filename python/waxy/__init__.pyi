@@ -1,4 +1,7 @@
-from collections.abc import Callable, Iterator
+from collections.abc import Callable, Iterator, Sequence
+from contextlib import AbstractContextManager
+from types import TracebackType
+from typing import Literal, Self
 
 # Exceptions
 
@@ -23,6 +26,9 @@ class InvalidGridSpan(WaxyException, ValueError):
 class InvalidNodeId(TaffyException, KeyError):
     """Raised when a node ID is not valid (node may have been removed)."""
 
+class WrongTree(InvalidNodeId):
+    """Raised when a node ID created by one TaffyTree is passed to a different TaffyTree."""
+
 class ChildIndexOutOfBounds(TaffyException):
     """Child index is out of bounds."""
 
@@ -32,6 +38,9 @@ class InvalidParentNode(TaffyException):
 class InvalidChildNode(TaffyException):
     """Child node is invalid."""
 
+class CycleDetected(TaffyException):
+    """Raised when adding a child would make a node its own ancestor."""
+
 class InvalidInputNode(TaffyException):
     """Input node is invalid."""
 
@@ -44,6 +53,25 @@ class Size:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __bool__(self) -> bool:
+        """False when the area is zero, i.e. when the width or height is zero."""
+    def __iter__(self) -> Iterator[float]: ...
+    def __add__(self, other: Size) -> Size: ...
+    def __sub__(self, other: Size) -> Size: ...
+    def __mul__(self, scalar: float) -> Size: ...
+    def __rmul__(self, scalar: float) -> Size: ...
+    def __truediv__(self, scalar: float) -> Size: ...
+    @staticmethod
+    def checked(width: float, height: float) -> Size:
+        """
+        Create a Size, rejecting negative dimensions.
+
+        The regular constructor accepts any values (e.g. for deltas); use this when the
+        Size must describe real geometry.
+
+        Raises:
+            ValueError: If `width` or `height` is negative.
+        """
     @property
     def width(self) -> float: ...
     @property
@@ -51,6 +79,28 @@ class Size:
     @property
     def area(self) -> float:
         """The area (width * height)."""
+    def is_finite(self) -> bool:
+        """Check whether both width and height are finite (not NaN or infinite)."""
+    def floor(self) -> Size:
+        """Round both dimensions down."""
+    def ceil(self) -> Size:
+        """Round both dimensions up."""
+    def round(self) -> Size:
+        """Round both dimensions to the nearest integer, with halves rounded away from zero."""
+    def scale_to_fit(self, bounds: Size) -> Size:
+        """
+        The largest uniformly scaled copy of this size that fits within `bounds`
+        (like CSS `object-fit: contain`).
+
+        A size with zero width or height, or zero `bounds`, scales to `Size(0, 0)`.
+        """
+    def scale_to_cover(self, bounds: Size) -> Size:
+        """
+        The smallest uniformly scaled copy of this size that covers `bounds`
+        (like CSS `object-fit: cover`).
+
+        A size with zero width or height scales to `Size(0, 0)`.
+        """
 
 class Rect:
     """A rectangle with left, right, top, bottom edges."""
@@ -65,8 +115,32 @@ class Rect:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __bool__(self) -> bool:
+        """
+        False when the rectangle is empty, i.e. its width or height is zero or negative.
+
+        This is independent of `len()`, which counts integer points:
+        `Rect(0, 0, 0, 0)` is falsy but contains one point.
+        """
     def __iter__(self) -> Iterator[Point]: ...
     def __len__(self) -> int: ...
+    @staticmethod
+    def from_origin_size(origin: Point, size: Size) -> Rect:
+        """
+        The rectangle with its top-left corner at `origin` and the given `size`,
+        e.g. `Rect.from_origin_size(layout.location, layout.size)`.
+        """
+    @staticmethod
+    def checked(left: float, right: float, top: float, bottom: float) -> Rect:
+        """
+        Create a Rect, rejecting negative extents.
+
+        The regular constructor accepts any edges (e.g. for edge widths or deltas); use this
+        when the Rect must describe a real box.
+
+        Raises:
+            ValueError: If `right < left` or `bottom < top`.
+        """
     @property
     def left(self) -> float: ...
     @property
@@ -86,6 +160,13 @@ class Rect:
         """The size of the rectangle as a Size."""
     def contains(self, point: Point) -> bool:
         """Check if a point is inside this rectangle."""
+    def closest_point(self, point: Point) -> Point:
+        """
+        The point on or inside this rectangle nearest to `point`, found by clamping
+        each coordinate to the rectangle's edges. Points inside the rectangle are returned unchanged.
+        """
+    def is_finite(self) -> bool:
+        """Check whether all four edges are finite (not NaN or infinite)."""
     @property
     def top_left(self) -> Point:
         """The top-left corner point."""
@@ -98,6 +179,9 @@ class Rect:
     @property
     def bottom_left(self) -> Point:
         """The bottom-left corner point."""
+    @property
+    def center(self) -> Point:
+        """The center point."""
     def corners(self) -> tuple[Point, Point, Point, Point]:
         """Return the four corner points (top-left, top-right, bottom-right, bottom-left)."""
     def top_edge(self) -> Iterator[Point]:
@@ -122,20 +206,84 @@ class Rect:
 
         Each column is an iterator of Points with the same x coordinate.
         """
+    def pixels(self, order: Literal["row", "column"] = "row") -> Iterator[Point]:
+        """
+        Iterate over all integer pixel locations contained within this rectangle.
+
+        With `order="row"` (the default, same as iterating the Rect), every x is yielded for a y
+        before advancing y. With `order="column"`, every y is yielded for an x before advancing x.
+        """
     def intersection(self, other: Rect) -> Rect | None:
         """
         Return the intersection of this rectangle with another, or None if they are disjoint.
 
         Rectangles that only touch at an edge or corner return a zero-area Rect.
         """
+    def grid_cell(self, col: int, row: int, cols: int, rows: int, gap: float = 0.0) -> Rect:
+        """
+        Return the rectangle of one cell in a `cols` x `rows` grid of equal cells laid over
+        this rectangle, with `gap` between adjacent cells. `col` and `row` are 0-based.
+
+        Raises:
+            IndexError: If `col` or `row` is outside the grid.
+            ValueError: If `cols` or `rows` is 0.
+        """
+    def inflate(self, dx: float, dy: float) -> Rect:
+        """
+        Expand the left and right edges outward by `dx` and the top and bottom edges by `dy`.
+
+        Negative amounts shrink the rectangle. The result is not clamped, so shrinking by
+        more than half the width or height inverts the edges and gives a negative `width`
+        or `height`.
+        """
+    def deflate(self, dx: float, dy: float) -> Rect:
+        """
+        Move the left and right edges inward by `dx` and the top and bottom edges by `dy`;
+        the same as `inflate(-dx, -dy)`, and likewise not clamped.
+        """
+    def translate(self, dx: float, dy: float) -> Rect:
+        """Shift the rectangle by `dx` horizontally and `dy` vertically."""
+    def scale(self, factor: float) -> Rect:
+        """
+        Multiply all four edges by `factor`, e.g. by a device pixel ratio
+        to convert layout coordinates to physical pixels.
+        """
+    def floor(self) -> Rect:
+        """Round all four edges down."""
+    def ceil(self) -> Rect:
+        """Round all four edges up."""
+    def round(self) -> Rect:
+        """Round all four edges to the nearest integer, with halves rounded away from zero."""
+    def round_outward(self) -> Rect:
+        """
+        Round the left and top edges down and the right and bottom edges up.
+
+        The result always covers the original rectangle, which avoids gaps between
+        adjacent boxes when snapping fractional layouts to whole pixels.
+        """
+    def round_to_pixels(self) -> Rect:
+        """
+        Snap the rectangle to whole pixels; the same as `round_outward`.
+
+        The left and top edges are rounded down and the right and bottom edges up,
+        so the snapped box always contains the original. Two boxes that share a
+        fractional edge will therefore overlap by one pixel after snapping rather
+        than leave a gap between them.
+        """
 
 class Point:
-    """A 2D point with x and y coordinates."""
+    """
+    A 2D point with x and y coordinates.
+
+    Points are always truthy, including `Point(0, 0)`; compare against `Point()`
+    to check for the origin.
+    """
 
     def __init__(self, x: float = 0.0, y: float = 0.0) -> None: ...
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __iter__(self) -> Iterator[float]: ...
     def __add__(self, other: Point) -> Point: ...
     def __sub__(self, other: Point) -> Point: ...
     def __mul__(self, scalar: float) -> Point: ...
@@ -146,6 +294,16 @@ class Point:
     def x(self) -> float: ...
     @property
     def y(self) -> float: ...
+    def is_finite(self) -> bool:
+        """Check whether both coordinates are finite (not NaN or infinite)."""
+    def length(self) -> float:
+        """The Euclidean length of this point as a vector from the origin: `sqrt(x*x + y*y)`."""
+    def distance(self, other: Point) -> float:
+        """The Euclidean distance to another point."""
+    def dot(self, other: Point) -> float:
+        """The dot product with another point, treating both as vectors."""
+    def manhattan_distance(self, other: Point) -> float:
+        """The Manhattan (taxicab) distance to another point: `|dx| + |dy|`."""
 
 class Line:
     """A line segment with start and end values."""
@@ -154,6 +312,13 @@ class Line:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __bool__(self) -> bool:
+        """
+        False when the length is zero or negative.
+
+        This is independent of `len()`, which counts integer values:
+        `Line(0, 0)` is falsy but contains one value.
+        """
     def __iter__(self) -> Iterator[float]: ...
     def __len__(self) -> int: ...
     @property
@@ -165,6 +330,17 @@ class Line:
         """The length of the line segment (end - start)."""
     def contains(self, value: float) -> bool:
         """Check if a value is contained within this line segment."""
+    def intersection(self, other: Line) -> Line | None:
+        """
+        The overlapping segment, or `None` if the segments are disjoint.
+
+        Segments that only share an endpoint intersect in a zero-length segment:
+        `Line(0, 5).intersection(Line(5, 9)) == Line(5, 5)`.
+        """
+    def overlaps(self, other: Line) -> bool:
+        """Whether `intersection(other)` is not `None`, so segments sharing only an endpoint overlap."""
+    def clamp(self, value: float) -> float:
+        """Clamp `value` into `[start, end]`."""
 
 class KnownSize:
     """
@@ -185,6 +361,29 @@ class KnownSize:
     def width(self) -> float | None: ...
     @property
     def height(self) -> float | None: ...
+    def is_fully_known(self) -> bool:
+        """Whether both `width` and `height` are known."""
+    def unwrap_or(self, default: Size) -> Size:
+        """A `Size` with each unknown dimension taken from `default`."""
+
+class MeasureResult:
+    """
+    A measure function result: the measured `size` plus an optional `first_baseline`.
+
+    Measure functions may return this instead of a plain `Size`.
+    Note that taffy 0.9 has no way for measure functions to report baselines,
+    so `first_baseline` does not affect layout yet.
+    """
+
+    def __init__(self, size: Size, first_baseline: float | None = None) -> None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    @property
+    def size(self) -> Size: ...
+    @property
+    def first_baseline(self) -> float | None:
+        """Distance from the top of the node to its first baseline, if it has one."""
 
 type AvailableSpaceValue = Definite | MinContent | MaxContent
 """Available space value for measure functions: Definite, MinContent, or MaxContent."""
@@ -212,6 +411,34 @@ class AvailableSize:
     def width(self) -> AvailableSpaceValue: ...
     @property
     def height(self) -> AvailableSpaceValue: ...
+    def width_is_definite(self) -> bool:
+        """Whether the width is `Definite`."""
+    def height_is_definite(self) -> bool:
+        """Whether the height is `Definite`."""
+    def width_is_min_content(self) -> bool:
+        """Whether the width is `MinContent`."""
+    def height_is_min_content(self) -> bool:
+        """Whether the height is `MinContent`."""
+    def width_is_max_content(self) -> bool:
+        """Whether the width is `MaxContent`."""
+    def height_is_max_content(self) -> bool:
+        """Whether the height is `MaxContent`."""
+    def is_definite(self) -> tuple[bool, bool]:
+        """Whether each axis is `Definite`, as `(width, height)`."""
+    def definite_or(self, default_width: float, default_height: float) -> Size:
+        """
+        A `Size` of the definite values, with `MinContent` and `MaxContent` axes
+        replaced by `default_width` and `default_height`.
+        """
+    def map_definite(self, f: Callable[[float], float]) -> AvailableSize:
+        """Apply `f` to each `Definite` value, leaving `MinContent` and `MaxContent` as they are."""
+    def axis_mode(self, axis: int) -> Literal["definite", "min-content", "max-content"]:
+        """
+        The sizing mode of an axis: `0` for width, `1` for height (the same order as iteration).
+
+        Raises:
+            ValueError: If `axis` is not 0 or 1.
+        """
 
 # Value types
 
@@ -233,6 +460,13 @@ class Length:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __lt__(self, other: Length) -> bool: ...
+    def __le__(self, other: Length) -> bool: ...
+    def __gt__(self, other: Length) -> bool: ...
+    def __ge__(self, other: Length) -> bool: ...
+    def __getnewargs__(self) -> tuple[float]: ...
+    def __abs__(self) -> Length:
+        """A Length with the absolute value."""
     @property
     def value(self) -> float: ...
 
@@ -254,8 +488,25 @@ class Percent:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __lt__(self, other: Percent) -> bool: ...
+    def __le__(self, other: Percent) -> bool: ...
+    def __gt__(self, other: Percent) -> bool: ...
+    def __ge__(self, other: Percent) -> bool: ...
+    def __getnewargs__(self) -> tuple[float]: ...
+    def __abs__(self) -> Percent:
+        """Percent values are never negative, so this returns an equal Percent."""
     @property
     def value(self) -> float: ...
+    @classmethod
+    def from_percentage(cls, value: float) -> Percent:
+        """
+        Create a Percent from a value on the 0-100 scale: `Percent.from_percentage(50) == Percent(0.5)`.
+
+        Raises:
+            InvalidPercent: If `value` is outside the range [0.0, 100.0].
+        """
+    def as_percentage(self) -> float:
+        """The value on the 0-100 scale: `Percent(0.5).as_percentage() == 50.0`."""
 
 class Auto:
     """
@@ -270,6 +521,7 @@ class Auto:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[()]: ...
 
 class MinContent:
     """
@@ -286,6 +538,7 @@ class MinContent:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[()]: ...
 
 class MaxContent:
     """
@@ -302,6 +555,7 @@ class MaxContent:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[()]: ...
 
 AUTO: Auto
 """Module-level Auto singleton. Equivalent to Auto() but avoids repeated construction."""
@@ -330,6 +584,13 @@ class Definite:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __lt__(self, other: Definite) -> bool: ...
+    def __le__(self, other: Definite) -> bool: ...
+    def __gt__(self, other: Definite) -> bool: ...
+    def __ge__(self, other: Definite) -> bool: ...
+    def __getnewargs__(self) -> tuple[float]: ...
+    def __abs__(self) -> Definite:
+        """A Definite with the absolute value."""
     @property
     def value(self) -> float: ...
 
@@ -353,6 +614,13 @@ class Fraction:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __lt__(self, other: Fraction) -> bool: ...
+    def __le__(self, other: Fraction) -> bool: ...
+    def __gt__(self, other: Fraction) -> bool: ...
+    def __ge__(self, other: Fraction) -> bool: ...
+    def __getnewargs__(self) -> tuple[float]: ...
+    def __abs__(self) -> Fraction:
+        """A Fraction with the absolute value."""
     @property
     def value(self) -> float: ...
 
@@ -367,6 +635,23 @@ type GridTrackValue = (
 )
 """A grid track sizing value used in grid_template_* and grid_auto_* fields."""
 
+type GridTemplateValue = GridTrackValue | Repeat
+"""A grid_template_* entry: a grid track or a Repeat of tracks."""
+
+def tracks(spec: str) -> list[GridTemplateValue]:
+    """
+    Parse a CSS grid track list into grid track values.
+
+    Supports `<n>px`, `<n>%`, `<n>fr`, `auto`, `min-content`, `max-content`,
+    `minmax(<min>, <max>)`, `fit-content(<length-percentage>)`, and
+    `repeat(<count> | auto-fill | auto-fit, <tracks>)`, separated by whitespace.
+    For example, `tracks("100px 1fr minmax(100px, 1fr)")` is
+    `[Length(100), Fraction(1), Minmax(Length(100), Fraction(1))]`.
+    `repeat()` is only valid in `grid_template_*` fields.
+
+    Raises `ValueError` naming the first token that cannot be parsed.
+    """
+
 class FitContent:
     """
     CSS fit-content() grid track sizing function.
@@ -386,6 +671,7 @@ class FitContent:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[LengthPercentageValue]: ...
     @property
     def limit(self) -> Length | Percent: ...
 
@@ -410,11 +696,82 @@ class Minmax:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[GridTrackMinValue, GridTrackMaxValue]: ...
     @property
     def min(self) -> GridTrackMinValue: ...
     @property
     def max(self) -> GridTrackMaxValue: ...
 
+class AutoFill:
+    """
+    CSS auto-fill repetition count for Repeat.
+
+    Repeats the tracks as many times as fit in the container, keeping empty repetitions.
+
+    See: [taffy `RepetitionCount::AutoFill`](https://docs.rs/taffy/0.9.2/taffy/style/enum.RepetitionCount.html),
+    [MDN `auto-fill`](https://developer.mozilla.org/en-US/docs/Web/CSS/repeat#auto-fill)
+    """
+
+    def __init__(self) -> None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[()]: ...
+
+class AutoFit:
+    """
+    CSS auto-fit repetition count for Repeat.
+
+    Like auto-fill, but empty repetitions collapse to zero size.
+
+    See: [taffy `RepetitionCount::AutoFit`](https://docs.rs/taffy/0.9.2/taffy/style/enum.RepetitionCount.html),
+    [MDN `auto-fit`](https://developer.mozilla.org/en-US/docs/Web/CSS/repeat#auto-fit)
+    """
+
+    def __init__(self) -> None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[()]: ...
+
+AUTO_FILL: AutoFill
+"""Module-level AutoFill singleton. Equivalent to AutoFill() but avoids repeated construction."""
+
+AUTO_FIT: AutoFit
+"""Module-level AutoFit singleton. Equivalent to AutoFit() but avoids repeated construction."""
+
+class Repeat:
+    """
+    CSS repeat() in a grid template: a list of tracks repeated `count` times.
+
+    `count` is a positive int, `AUTO_FILL`, or `AUTO_FIT`, and `tracks` must not be empty.
+    Used only in grid_template_rows and grid_template_columns.
+
+    The repeat is forwarded to taffy as-is. Like CSS, taffy ignores the *whole* template
+    (leaving no explicit tracks) if it has more than one auto-repeat, or if it has an
+    auto-repeat and any track anywhere in the template lacks a fixed (Length or Percent)
+    minimum or maximum. So `Repeat(AUTO_FILL, [Fraction(1)])` is ignored, while
+    `Repeat(AUTO_FILL, [Minmax(Length(100), Fraction(1))])` works.
+
+    Raises `ValueError` if `count` is not between 1 and 65535 or `tracks` is empty.
+
+    See: [taffy `GridTemplateRepetition`](https://docs.rs/taffy/0.9.2/taffy/style/struct.GridTemplateRepetition.html),
+    [MDN `repeat()`](https://developer.mozilla.org/en-US/docs/Web/CSS/repeat)
+    """
+
+    __match_args__ = ("count", "tracks")
+
+    def __init__(self, count: int | AutoFill | AutoFit, tracks: Sequence[GridTrackValue]) -> None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[int | AutoFill | AutoFit, list[GridTrackValue]]: ...
+    @property
+    def count(self) -> int | AutoFill | AutoFit:
+        """The repetition count: an int, `AutoFill()`, or `AutoFit()`."""
+    @property
+    def tracks(self) -> list[GridTrackValue]: ...
+
 class GridLine:
     """
     A 1-based grid line index (negative indices count from the end).
@@ -434,6 +791,7 @@ class GridLine:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[int]: ...
     @property
     def index(self) -> int: ...
 
@@ -455,6 +813,57 @@ class GridSpan:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[int]: ...
+    @property
+    def count(self) -> int: ...
+
+class GridNamedLine:
+    """
+    A named grid line, optionally the index-th line with that name (negative indices count from the end).
+
+    Used in GridPlacement.start and GridPlacement.end.
+
+    Raises `ValueError` if `name` is empty,
+    and `InvalidGridLine` if `index` is 0 (omit it to use the first line with that name).
+
+    See: [taffy `GridPlacement::NamedLine`](https://docs.rs/taffy/0.9.2/taffy/style/enum.GridPlacement.html),
+    [MDN Named lines](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_grid_layout/Grid_layout_using_named_grid_lines)
+    """
+
+    __match_args__ = ("name", "index")
+
+    def __init__(self, name: str, index: int | None = None) -> None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[str, int | None]: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def index(self) -> int | None: ...
+
+class GridNamedSpan:
+    """
+    Span until the count-th grid line with the given name.
+
+    Used in GridPlacement.start and GridPlacement.end.
+
+    Raises `ValueError` if `name` is empty,
+    and `InvalidGridSpan` if `count` is 0 (must span at least 1 line).
+
+    See: [taffy `GridPlacement::NamedSpan`](https://docs.rs/taffy/0.9.2/taffy/style/enum.GridPlacement.html),
+    [MDN `grid-column-start` (span)](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-column-start)
+    """
+
+    __match_args__ = ("name", "count")
+
+    def __init__(self, name: str, count: int = 1) -> None: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[str, int]: ...
+    @property
+    def name(self) -> str: ...
     @property
     def count(self) -> int: ...
 
@@ -472,6 +881,25 @@ class Display:
     Nil: Display
     """No display (maps to CSS `display: none`)."""
 
+    @classmethod
+    def from_str(cls, name: str) -> Display:
+        """
+        Look up a variant by name, ignoring case (e.g. `Display.from_str("flex")`).
+        `"None"` is accepted as an alias for `Nil`, matching CSS `display: none`.
+
+        Raises:
+            ValueError: If `name` is not a variant of this enum.
+        """
+    @classmethod
+    def members(cls) -> list[Display]:
+        """Every variant, in declaration order."""
+    @property
+    def name(self) -> str:
+        """The variant's name, as accepted by `from_str`."""
+    @property
+    def css_name(self) -> str:
+        """The CSS keyword for this variant (e.g. `"none"` for `Nil`)."""
+
 class Position:
     """How the node should be positioned."""
 
@@ -480,6 +908,24 @@ class Position:
     Absolute: Position
     """Removed from normal flow and positioned relative to its containing block."""
 
+    @classmethod
+    def from_str(cls, name: str) -> Position:
+        """
+        Look up a variant by name, ignoring case (e.g. `Position.from_str("absolute")`).
+
+        Raises:
+            ValueError: If `name` is not a variant of this enum.
+        """
+    @classmethod
+    def members(cls) -> list[Position]:
+        """Every variant, in declaration order."""
+    @property
+    def name(self) -> str:
+        """The variant's name, as accepted by `from_str`."""
+    @property
+    def css_name(self) -> str:
+        """The CSS keyword for this variant (e.g. `"space-between"` for `SpaceBetween`)."""
+
 class FlexDirection:
     """The direction of a flex container's main axis."""
 
@@ -492,6 +938,24 @@ class FlexDirection:
     ColumnReverse: FlexDirection
     """Items are laid out in a column in reverse order (bottom to top)."""
 
+    @classmethod
+    def from_str(cls, name: str) -> FlexDirection:
+        """
+        Look up a variant by name, ignoring case (e.g. `FlexDirection.from_str("row")`).
+
+        Raises:
+            ValueError: If `name` is not a variant of this enum.
+        """
+    @classmethod
+    def members(cls) -> list[FlexDirection]:
+        """Every variant, in declaration order."""
+    @property
+    def name(self) -> str:
+        """The variant's name, as accepted by `from_str`."""
+    @property
+    def css_name(self) -> str:
+        """The CSS keyword for this variant (e.g. `"space-between"` for `SpaceBetween`)."""
+
 class FlexWrap:
     """Whether flex items wrap."""
 
@@ -502,6 +966,24 @@ class FlexWrap:
     WrapReverse: FlexWrap
     """Items wrap onto multiple lines in reverse order."""
 
+    @classmethod
+    def from_str(cls, name: str) -> FlexWrap:
+        """
+        Look up a variant by name, ignoring case (e.g. `FlexWrap.from_str("wrap")`).
+
+        Raises:
+            ValueError: If `name` is not a variant of this enum.
+        """
+    @classmethod
+    def members(cls) -> list[FlexWrap]:
+        """Every variant, in declaration order."""
+    @property
+    def name(self) -> str:
+        """The variant's name, as accepted by `from_str`."""
+    @property
+    def css_name(self) -> str:
+        """The CSS keyword for this variant (e.g. `"space-between"` for `SpaceBetween`)."""
+
 class AlignItems:
     """Alignment of items along the cross axis."""
 
@@ -520,6 +1002,24 @@ class AlignItems:
     Stretch: AlignItems
     """Stretch to fill the cross axis."""
 
+    @classmethod
+    def from_str(cls, name: str) -> AlignItems:
+        """
+        Look up a variant by name, ignoring case (e.g. `AlignItems.from_str("center")`).
+
+        Raises:
+            ValueError: If `name` is not a variant of this enum.
+        """
+    @classmethod
+    def members(cls) -> list[AlignItems]:
+        """Every variant, in declaration order."""
+    @property
+    def name(self) -> str:
+        """The variant's name, as accepted by `from_str`."""
+    @property
+    def css_name(self) -> str:
+        """The CSS keyword for this variant (e.g. `"space-between"` for `SpaceBetween`)."""
+
 class AlignContent:
     """Alignment of content within the container."""
 
@@ -542,6 +1042,24 @@ class AlignContent:
     SpaceAround: AlignContent
     """Distribute lines with half-size spaces on the edges."""
 
+    @classmethod
+    def from_str(cls, name: str) -> AlignContent:
+        """
+        Look up a variant by name, ignoring case (e.g. `AlignContent.from_str("spacebetween")`).
+
+        Raises:
+            ValueError: If `name` is not a variant of this enum.
+        """
+    @classmethod
+    def members(cls) -> list[AlignContent]:
+        """Every variant, in declaration order."""
+    @property
+    def name(self) -> str:
+        """The variant's name, as accepted by `from_str`."""
+    @property
+    def css_name(self) -> str:
+        """The CSS keyword for this variant (e.g. `"space-between"` for `SpaceBetween`)."""
+
 class Overflow:
     """How content overflows its container."""
 
@@ -554,6 +1072,24 @@ class Overflow:
     Scroll: Overflow
     """Content is clipped but scrollable."""
 
+    @classmethod
+    def from_str(cls, name: str) -> Overflow:
+        """
+        Look up a variant by name, ignoring case (e.g. `Overflow.from_str("hidden")`).
+
+        Raises:
+            ValueError: If `name` is not a variant of this enum.
+        """
+    @classmethod
+    def members(cls) -> list[Overflow]:
+        """Every variant, in declaration order."""
+    @property
+    def name(self) -> str:
+        """The variant's name, as accepted by `from_str`."""
+    @property
+    def css_name(self) -> str:
+        """The CSS keyword for this variant (e.g. `"space-between"` for `SpaceBetween`)."""
+
 class GridAutoFlow:
     """How grid items are auto-placed."""
 
@@ -566,6 +1102,24 @@ class GridAutoFlow:
     ColumnDense: GridAutoFlow
     """Place items by filling each column, backfilling gaps."""
 
+    @classmethod
+    def from_str(cls, name: str) -> GridAutoFlow:
+        """
+        Look up a variant by name, ignoring case (e.g. `GridAutoFlow.from_str("row")`).
+
+        Raises:
+            ValueError: If `name` is not a variant of this enum.
+        """
+    @classmethod
+    def members(cls) -> list[GridAutoFlow]:
+        """Every variant, in declaration order."""
+    @property
+    def name(self) -> str:
+        """The variant's name, as accepted by `from_str`."""
+    @property
+    def css_name(self) -> str:
+        """The CSS keyword for this variant (e.g. `"space-between"` for `SpaceBetween`)."""
+
 class BoxSizing:
     """Box sizing model."""
 
@@ -574,6 +1128,24 @@ class BoxSizing:
     ContentBox: BoxSizing
     """Width and height apply to the content area only."""
 
+    @classmethod
+    def from_str(cls, name: str) -> BoxSizing:
+        """
+        Look up a variant by name, ignoring case (e.g. `BoxSizing.from_str("borderbox")`).
+
+        Raises:
+            ValueError: If `name` is not a variant of this enum.
+        """
+    @classmethod
+    def members(cls) -> list[BoxSizing]:
+        """Every variant, in declaration order."""
+    @property
+    def name(self) -> str:
+        """The variant's name, as accepted by `from_str`."""
+    @property
+    def css_name(self) -> str:
+        """The CSS keyword for this variant (e.g. `"space-between"` for `SpaceBetween`)."""
+
 class TextAlign:
     """Text alignment."""
 
@@ -586,14 +1158,32 @@ class TextAlign:
     LegacyCenter: TextAlign
     """Centered text."""
 
-type GridPlacementValue = GridLine | GridSpan | Auto
-"""A grid placement value used in GridPlacement start and end: GridLine, GridSpan, or Auto."""
+    @classmethod
+    def from_str(cls, name: str) -> TextAlign:
+        """
+        Look up a variant by name, ignoring case (e.g. `TextAlign.from_str("auto")`).
+
+        Raises:
+            ValueError: If `name` is not a variant of this enum.
+        """
+    @classmethod
+    def members(cls) -> list[TextAlign]:
+        """Every variant, in declaration order."""
+    @property
+    def name(self) -> str:
+        """The variant's name, as accepted by `from_str`."""
+    @property
+    def css_name(self) -> str:
+        """The CSS keyword for this variant (e.g. `"space-between"` for `SpaceBetween`)."""
+
+type GridPlacementValue = GridLine | GridSpan | GridNamedLine | GridNamedSpan | Auto
+"""A grid placement value used in GridPlacement start and end: GridLine, GridSpan, GridNamedLine, GridNamedSpan, or Auto."""
 
 class GridPlacement:
     """
     A start/end pair of grid placements for a child item.
 
-    Each of start and end is a GridPlacementValue (GridLine | GridSpan | Auto).
+    Each of start and end is a GridPlacementValue (GridLine | GridSpan | GridNamedLine | GridNamedSpan | Auto).
     Defaults both to Auto (the CSS default for unplaced items).
 
     See: [taffy `Line<GridPlacement>`](https://docs.rs/taffy/0.9.2/taffy/geometry/struct.Line.html),
@@ -609,15 +1199,41 @@ class GridPlacement:
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[GridPlacementValue, GridPlacementValue]: ...
     @property
     def start(self) -> GridPlacementValue: ...
     @property
     def end(self) -> GridPlacementValue: ...
+    @staticmethod
+    def parse(s: str) -> GridPlacement:
+        """
+        Parse the CSS `grid-row` / `grid-column` shorthand.
+
+        Each side of an optional `/` is `auto`, a line number, or `span N`.
+        An omitted end defaults to `auto`.
+
+        ```python
+        GridPlacement.parse("1 / span 2")  # GridPlacement(start=GridLine(1), end=GridSpan(2))
+        GridPlacement.parse("span 3")      # GridPlacement(start=GridSpan(3), end=Auto())
+        ```
+
+        Named grid lines are not supported.
+
+        Raises:
+            ValueError: If the string is malformed.
+            InvalidGridLine: If a line number is 0.
+            InvalidGridSpan: If a span count is 0.
+        """
 
 # Node
 
 class NodeId:
-    """A handle to a node in the layout tree."""
+    """
+    A handle to a node in the layout tree.
+
+    Each NodeId remembers which TaffyTree created it; passing it to a different tree
+    raises `WrongTree`. NodeIds from different trees never compare equal.
+    """
 
     def __repr__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
@@ -643,7 +1259,8 @@ class Layout:
     - To compute the content box, subtract [`border`][waxy.Layout.border]
       and [`padding`][waxy.Layout.padding] from [`size`][waxy.Layout.size]
       (or use [`content_box_width()`][waxy.Layout.content_box_width]
-      / [`content_box_height()`][waxy.Layout.content_box_height]).
+      / [`content_box_height()`][waxy.Layout.content_box_height],
+      or [`content_box()`][waxy.Layout.content_box] for the full rectangle).
     - To compute the margin box, expand outward from ([`location`][waxy.Layout.location],
       [`size`][waxy.Layout.size]) by the [`margin`][waxy.Layout.margin] widths.
 
@@ -659,7 +1276,11 @@ class Layout:
     """
 
     def __repr__(self) -> str: ...
-    def __eq__(self, other: object) -> bool: ...
+    def __eq__(self, other: object) -> bool:
+        """
+        Exact equality of all eight fields. Floats are compared bit for bit, so `-0.0`
+        and `0.0` differ and identical NaNs are equal, keeping golden tests deterministic.
+        """
     def __hash__(self) -> int: ...
     @property
     def order(self) -> int:
@@ -689,6 +1310,52 @@ class Layout:
         """Width of the content box (size minus padding and border)."""
     def content_box_height(self) -> float:
         """Height of the content box (size minus padding and border)."""
+    def overflows(self) -> tuple[bool, bool]:
+        """
+        Whether the content overflows the node's padding box, as `(horizontal, vertical)`,
+        e.g. to decide whether to show scrollbars. See
+        [`scrollable_size()`][waxy.Layout.scrollable_size].
+        """
+    def scrollable_size(self) -> Size:
+        """
+        How far the content overflows the node's padding box on each axis,
+        i.e. the maximum scroll offset, or 0 on an axis where the content fits.
+
+        taffy measures [`content_size`][waxy.Layout.content_size] from the top-left corner of
+        the border box, including the leading border and the padding on both sides, so the
+        overflow is `content_size` minus `size` minus the right or bottom border width.
+        Comparing `content_size` directly against the content box would report padded
+        content that fits exactly as overflowing.
+        """
+    def border_box(self) -> Rect:
+        """
+        The border box as a Rect, positioned at [`location`][waxy.Layout.location]
+        (i.e. in the parent's coordinate space) with dimensions [`size`][waxy.Layout.size].
+        """
+    def contains(self, point: Point) -> bool:
+        """
+        Whether `point` lies within the [`border_box()`][waxy.Layout.border_box], for hit-testing.
+
+        `point` is in the same coordinate space as `location`: the parent's, or the
+        tree root's for a layout from `TaffyTree.absolute_layout`. The top and left edges
+        are inside and the bottom and right edges are outside, so adjacent boxes never
+        both claim a point on their shared edge, and an empty box contains no points.
+        """
+    def padding_box(self) -> Rect:
+        """The padding box: [`border_box()`][waxy.Layout.border_box] inset by the border widths."""
+    def content_box(self) -> Rect:
+        """
+        The content box: [`padding_box()`][waxy.Layout.padding_box] inset by the padding widths.
+
+        Its width and height match [`content_box_width()`][waxy.Layout.content_box_width]
+        and [`content_box_height()`][waxy.Layout.content_box_height].
+        """
+    def to_dict(self) -> dict[str, object]:
+        """
+        Serialize every field to plain data: `order` is an int, `location` is a
+        `{"x", "y"}` dict, the sizes are `{"width", "height"}` dicts, and `border`,
+        `padding`, and `margin` are `{"left", "right", "top", "bottom"}` dicts.
+        """
 
 # Style
 
@@ -705,6 +1372,11 @@ class Style:
     All fields are keyword-only. Passing None (or omitting) uses the taffy default.
     Style is immutable — construct a new instance to change fields, or use | to merge.
 
+    For chaining, every constructor argument except `clear` has a `with_<name>` method
+    that returns a copy with that argument applied, leaving the original unchanged:
+    `Style().with_display(Display.Flex).with_flex_grow(1.0)`. `style.with_margin(m)`
+    is the same as `style | Style(margin=m)`.
+
     See: [taffy `Style`](https://docs.rs/taffy/0.9.2/taffy/struct.Style.html)
     """
 
@@ -713,29 +1385,37 @@ class Style:
         *,
         display: Display | None = None,
         box_sizing: BoxSizing | None = None,
+        overflow: Overflow | tuple[Overflow, Overflow] | None = None,
         overflow_x: Overflow | None = None,
         overflow_y: Overflow | None = None,
         scrollbar_width: float | None = None,
         position: Position | None = None,
+        inset: DimensionValue | tuple[DimensionValue, DimensionValue, DimensionValue, DimensionValue] | None = None,
         inset_left: DimensionValue | None = None,
         inset_right: DimensionValue | None = None,
         inset_top: DimensionValue | None = None,
         inset_bottom: DimensionValue | None = None,
+        size: tuple[DimensionValue, DimensionValue] | None = None,
         size_width: DimensionValue | None = None,
         size_height: DimensionValue | None = None,
+        min_size: tuple[DimensionValue, DimensionValue] | None = None,
         min_size_width: DimensionValue | None = None,
         min_size_height: DimensionValue | None = None,
+        max_size: tuple[DimensionValue, DimensionValue] | None = None,
         max_size_width: DimensionValue | None = None,
         max_size_height: DimensionValue | None = None,
         aspect_ratio: float | None = None,
+        margin: DimensionValue | tuple[DimensionValue, DimensionValue, DimensionValue, DimensionValue] | None = None,
         margin_left: DimensionValue | None = None,
         margin_right: DimensionValue | None = None,
         margin_top: DimensionValue | None = None,
         margin_bottom: DimensionValue | None = None,
+        padding: LengthPercentageValue | tuple[LengthPercentageValue, LengthPercentageValue, LengthPercentageValue, LengthPercentageValue] | None = None,
         padding_left: LengthPercentageValue | None = None,
         padding_right: LengthPercentageValue | None = None,
         padding_top: LengthPercentageValue | None = None,
         padding_bottom: LengthPercentageValue | None = None,
+        border: LengthPercentageValue | tuple[LengthPercentageValue, LengthPercentageValue, LengthPercentageValue, LengthPercentageValue] | None = None,
         border_left: LengthPercentageValue | None = None,
         border_right: LengthPercentageValue | None = None,
         border_top: LengthPercentageValue | None = None,
@@ -746,6 +1426,7 @@ class Style:
         justify_self: AlignItems | None = None,
         align_content: AlignContent | None = None,
         justify_content: AlignContent | None = None,
+        gap: LengthPercentageValue | tuple[LengthPercentageValue, LengthPercentageValue] | None = None,
         gap_width: LengthPercentageValue | None = None,
         gap_height: LengthPercentageValue | None = None,
         text_align: TextAlign | None = None,
@@ -754,13 +1435,14 @@ class Style:
         flex_basis: DimensionValue | None = None,
         flex_grow: float | None = None,
         flex_shrink: float | None = None,
-        grid_template_rows: list[GridTrackValue] | None = None,
-        grid_template_columns: list[GridTrackValue] | None = None,
+        grid_template_rows: list[GridTemplateValue] | None = None,
+        grid_template_columns: list[GridTemplateValue] | None = None,
         grid_auto_rows: list[GridTrackValue] | None = None,
         grid_auto_columns: list[GridTrackValue] | None = None,
         grid_auto_flow: GridAutoFlow | None = None,
         grid_row: GridPlacement | None = None,
         grid_column: GridPlacement | None = None,
+        clear: Sequence[str] | None = None,
     ) -> None:
         """
         Construct a Style with the given fields set.
@@ -769,32 +1451,52 @@ class Style:
         the taffy default value for that field. See each property for documentation
         of individual fields.
 
+        The `inset`, `margin`, `padding`, and `border` shorthands set all four edges
+        at once; a per-edge argument such as `margin_top` overrides its edge of the shorthand.
+        Likewise `size`, `min_size`, `max_size`, and `gap` set both axes at once and are
+        overridden by `size_width`, `gap_height`, etc.
+
+        A few fields are nullable: `aspect_ratio`, `align_items`, `align_self`,
+        `justify_items`, `justify_self`, `align_content`, and `justify_content`.
+        Passing None for one of them marks it explicitly set to None, so it overrides
+        other styles when merged with `|`. For every other field, None is the same as
+        omitting the argument. To leave any field unset regardless of the other
+        arguments, name it in `clear`.
+
         Args:
             display: How the node is laid out (Block, Flex, Grid, or Nil). [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/display)
             box_sizing: Whether size includes border and padding (BorderBox) or not (ContentBox). [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/box-sizing)
+            overflow: Overflow handling for both axes: one value for both, or `(x, y)`. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/overflow)
             overflow_x: How overflowing content is handled horizontally. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/overflow-x)
             overflow_y: How overflowing content is handled vertically. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/overflow-y)
             scrollbar_width: Width of the scrollbar gutter in pixels. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/scrollbar-gutter)
             position: Whether the node is positioned relative to its parent or absolutely. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/position)
+            inset: All four inset edges: one value for every edge, or `(top, right, bottom, left)`. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/inset)
             inset_left: Left offset for absolutely-positioned nodes. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/left)
             inset_right: Right offset for absolutely-positioned nodes. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/right)
             inset_top: Top offset for absolutely-positioned nodes. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/top)
             inset_bottom: Bottom offset for absolutely-positioned nodes. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/bottom)
+            size: Preferred `(width, height)`. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/width)
             size_width: Preferred width of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/width)
             size_height: Preferred height of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/height)
+            min_size: Minimum `(width, height)`. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/min-width)
             min_size_width: Minimum width of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/min-width)
             min_size_height: Minimum height of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/min-height)
+            max_size: Maximum `(width, height)`. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/max-width)
             max_size_width: Maximum width of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/max-width)
             max_size_height: Maximum height of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/max-height)
-            aspect_ratio: Preferred aspect ratio (width / height), or None. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/aspect-ratio)
+            aspect_ratio: Preferred aspect ratio (width / height), or None. Must be positive and finite. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/aspect-ratio)
+            margin: All four margin edges: one value for every edge, or `(top, right, bottom, left)`. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/margin)
             margin_left: Left outer spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/margin-left)
             margin_right: Right outer spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/margin-right)
             margin_top: Top outer spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/margin-top)
             margin_bottom: Bottom outer spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/margin-bottom)
+            padding: All four padding edges: one value for every edge, or `(top, right, bottom, left)`. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/padding)
             padding_left: Left inner spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/padding-left)
             padding_right: Right inner spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/padding-right)
             padding_top: Top inner spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/padding-top)
             padding_bottom: Bottom inner spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/padding-bottom)
+            border: All four border widths: one value for every edge, or `(top, right, bottom, left)`. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/border-width)
             border_left: Left border width. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/border-left-width)
             border_right: Right border width. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/border-right-width)
             border_top: Top border width. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/border-top-width)
@@ -805,6 +1507,7 @@ class Style:
             justify_self: Override alignment of this node along the parent's main axis. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-self)
             align_content: Alignment of rows/columns when there is extra space in the cross axis. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/align-content)
             justify_content: Distribution of children along the main axis. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-content)
+            gap: Gap between grid/flex items: one value for both axes, or `(width, height)`. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/gap)
             gap_width: Horizontal gap between grid/flex items. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/column-gap)
             gap_height: Vertical gap between grid/flex items. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/row-gap)
             text_align: Text alignment within the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/text-align)
@@ -820,11 +1523,35 @@ class Style:
             grid_auto_flow: How auto-placed items are inserted in the grid. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-auto-flow)
             grid_row: Row placement of this item in a grid container. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row)
             grid_column: Column placement of this item in a grid container. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-column)
+            clear: Names of fields to reset to their defaults and leave unset, applied after all other arguments, like `unset()`.
+
+        Raises:
+            ValueError: If `aspect_ratio` is zero, negative, or not finite, or if `clear` names a field that doesn't exist.
+        """
+    def __repr__(self) -> str:
+        """Lists only the explicitly set fields, e.g. `Style(display=Display.Flex, size_width=Length(100))`."""
+    def __eq__(self, other: object) -> bool:
+        """
+        Styles are equal when they explicitly set the same fields to the same values.
+
+        A field set to its default value is not equal to the same field left unset,
+        since the two merge differently under `|`.
         """
-    def __repr__(self) -> str: ...
-    def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
+    def __copy__(self) -> Style: ...
+    def __deepcopy__(self, memo: dict[int, object]) -> Style: ...
+    def __reduce__(self) -> tuple[Callable[[dict[str, object]], Style], tuple[dict[str, object]]]:
+        """Pickle support: styles are rebuilt with `Style.from_dict(style.to_dict())`."""
     def __or__(self, other: Style) -> Style: ...
+    def __and__(self, other: Style) -> Style:
+        """
+        The overrides two styles have in common: fields set in both *to the same value*.
+
+        Fields set in both styles to different values are left unset, so folding `&` over
+        a group of sibling styles factors out exactly the properties they all share.
+        """
+    def __rich_repr__(self) -> list[tuple[str, object]]:
+        """`(name, value)` pairs for each explicitly set field, used by [rich](https://rich.readthedocs.io/) for structured output."""
     @property
     def display(self) -> Display:
         """How the node is laid out (Block, Flex, Grid, or Nil). [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/display)."""
@@ -838,12 +1565,18 @@ class Style:
     def overflow_y(self) -> Overflow:
         """How overflowing content is handled vertically. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/overflow-y)."""
     @property
+    def overflow(self) -> tuple[Overflow, Overflow]:
+        """Horizontal and vertical overflow as `(x, y)`."""
+    @property
     def scrollbar_width(self) -> float:
         """Width of the scrollbar gutter in pixels. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/scrollbar-gutter)."""
     @property
     def position(self) -> Position:
         """Whether the node is positioned relative to its parent or absolutely. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/position)."""
     @property
+    def inset(self) -> tuple[DimensionValue, DimensionValue, DimensionValue, DimensionValue]:
+        """All four inset edges as `(top, right, bottom, left)`."""
+    @property
     def inset_left(self) -> DimensionValue:
         """Left offset for absolutely-positioned nodes. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/left)."""
     @property
@@ -856,18 +1589,27 @@ class Style:
     def inset_bottom(self) -> DimensionValue:
         """Bottom offset for absolutely-positioned nodes. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/bottom)."""
     @property
+    def size(self) -> tuple[DimensionValue, DimensionValue]:
+        """Preferred width and height as `(width, height)`."""
+    @property
     def size_width(self) -> DimensionValue:
         """Preferred width of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/width)."""
     @property
     def size_height(self) -> DimensionValue:
         """Preferred height of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/height)."""
     @property
+    def min_size(self) -> tuple[DimensionValue, DimensionValue]:
+        """Minimum width and height as `(width, height)`."""
+    @property
     def min_size_width(self) -> DimensionValue:
         """Minimum width of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/min-width)."""
     @property
     def min_size_height(self) -> DimensionValue:
         """Minimum height of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/min-height)."""
     @property
+    def max_size(self) -> tuple[DimensionValue, DimensionValue]:
+        """Maximum width and height as `(width, height)`."""
+    @property
     def max_size_width(self) -> DimensionValue:
         """Maximum width of the node. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/max-width)."""
     @property
@@ -877,6 +1619,9 @@ class Style:
     def aspect_ratio(self) -> float | None:
         """Preferred aspect ratio (width / height), or None. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/aspect-ratio)."""
     @property
+    def margin(self) -> tuple[DimensionValue, DimensionValue, DimensionValue, DimensionValue]:
+        """All four margin edges as `(top, right, bottom, left)`."""
+    @property
     def margin_left(self) -> DimensionValue:
         """Left outer spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/margin-left)."""
     @property
@@ -889,6 +1634,9 @@ class Style:
     def margin_bottom(self) -> DimensionValue:
         """Bottom outer spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/margin-bottom)."""
     @property
+    def padding(self) -> tuple[LengthPercentageValue, LengthPercentageValue, LengthPercentageValue, LengthPercentageValue]:
+        """All four padding edges as `(top, right, bottom, left)`."""
+    @property
     def padding_left(self) -> LengthPercentageValue:
         """Left inner spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/padding-left)."""
     @property
@@ -901,6 +1649,9 @@ class Style:
     def padding_bottom(self) -> LengthPercentageValue:
         """Bottom inner spacing. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/padding-bottom)."""
     @property
+    def border(self) -> tuple[LengthPercentageValue, LengthPercentageValue, LengthPercentageValue, LengthPercentageValue]:
+        """All four border edges as `(top, right, bottom, left)`."""
+    @property
     def border_left(self) -> LengthPercentageValue:
         """Left border width. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/border-left-width)."""
     @property
@@ -931,6 +1682,9 @@ class Style:
     def justify_content(self) -> AlignContent | None:
         """Distribution of children along the main axis. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-content)."""
     @property
+    def gap(self) -> tuple[LengthPercentageValue, LengthPercentageValue]:
+        """Horizontal and vertical gaps as `(width, height)`."""
+    @property
     def gap_width(self) -> LengthPercentageValue:
         """Horizontal gap between grid/flex items. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/column-gap)."""
     @property
@@ -955,10 +1709,10 @@ class Style:
     def flex_shrink(self) -> float:
         """Rate at which a flex item shrinks when space is tight. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/flex-shrink)."""
     @property
-    def grid_template_rows(self) -> list[GridTrackValue]:
+    def grid_template_rows(self) -> list[GridTemplateValue]:
         """Explicit row track sizing in a grid container. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-rows)."""
     @property
-    def grid_template_columns(self) -> list[GridTrackValue]:
+    def grid_template_columns(self) -> list[GridTemplateValue]:
         """Explicit column track sizing in a grid container. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-columns)."""
     @property
     def grid_auto_rows(self) -> list[GridTrackValue]:
@@ -975,6 +1729,278 @@ class Style:
     @property
     def grid_column(self) -> GridPlacement:
         """Column placement of this item in a grid container. [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-column)."""
+    def to_dict(self) -> dict[str, object]:
+        """
+        Serialize the explicitly set fields to a dict.
+
+        Keys are the keyword argument names accepted by `Style(...)`. Dimension and track
+        values are the public value types (`Length`, `Percent`, `Auto`, ...); enum values
+        are their variant names (e.g. `"Flex"`). Unset fields are omitted.
+        """
+    @classmethod
+    def from_dict(cls, data: dict[str, object]) -> Style:
+        """
+        Reconstruct a Style from the output of `to_dict()`.
+
+        Enum values may be given as variant names or as enum members.
+        `Style.from_dict(style.to_dict()) == style` for any Style.
+
+        Raises:
+            ValueError: If a key is not a Style field or an enum name is not a valid variant.
+        """
+    @classmethod
+    def from_css(cls, text: str) -> Style:
+        """
+        Parse a CSS declaration block such as `"display: flex; gap: 8px;"`.
+
+        Property names map to the corresponding fields (`min-width` to `min_size_width`,
+        `column-gap` to `gap_width`, and so on), and the `inset`, `margin`, `padding`,
+        `border-width`, `gap`, and `overflow` shorthands are supported.
+        Values use the syntax produced by `to_css()`: `px`, `%`, `fr`, `auto`, CSS keywords,
+        track lists, and grid line placements. Only the declared properties are marked set,
+        and later declarations override earlier ones.
+
+        Raises:
+            ValueError: If a property is not supported or a value cannot be parsed.
+        """
+    @classmethod
+    def flex_row(cls, **overrides: object) -> Style:
+        """
+        A flex container laid out in a row: `display=Display.Flex` and
+        `flex_direction=FlexDirection.Row`.
+
+        This is a waxy convenience with no taffy equivalent. `overrides` are any `Style`
+        keyword arguments; they are applied on top of the presets, and both the presets and
+        the overrides are marked as set.
+        """
+    @classmethod
+    def flex_column(cls, **overrides: object) -> Style:
+        """
+        A flex container laid out in a column: `display=Display.Flex` and
+        `flex_direction=FlexDirection.Column`.
+
+        This is a waxy convenience with no taffy equivalent; see `flex_row`.
+        """
+    @staticmethod
+    def aspect_ratio_from(width: float, height: float) -> float:
+        """
+        The `aspect_ratio` for a `width` by `height` box, e.g. `Style.aspect_ratio_from(16, 9)`.
+
+        Raises:
+            ValueError: If `height` is zero or the ratio is not positive and finite.
+        """
+    @classmethod
+    def grid(cls, **overrides: object) -> Style:
+        """
+        A grid container: `display=Display.Grid`.
+
+        This is a waxy convenience with no taffy equivalent; see `flex_row`.
+        """
+    def stable_hash(self) -> int:
+        """
+        A deterministic 64-bit hash of the style, suitable as a key for on-disk caches.
+
+        Unlike `hash()`, which is only meaningful within a single process, the result is
+        identical across processes and platforms for a given waxy version. Like `hash()`,
+        it covers which fields are explicitly set, so `stable_hash` agrees with `==`.
+        """
+    def is_visible(self) -> bool:
+        """Whether a node with this style is displayed at all, i.e. `display` is not `Display.Nil`."""
+    def is_set(self, name: str) -> bool:
+        """
+        Whether the named field was explicitly set (even to its default value).
+
+        Raises:
+            ValueError: If `name` is not a Style field.
+        """
+    def set_field_names(self) -> list[str]:
+        """The names of the explicitly set fields, sorted alphabetically."""
+    def is_empty(self) -> bool:
+        """Whether no field is explicitly set, i.e. the style equals `Style()`."""
+    def validate(self) -> None:
+        """
+        Raise `ValueError` describing the first numeric field that is out of range.
+
+        The checks are:
+
+        - `flex_grow`, `flex_shrink` and `scrollbar_width` must be non-negative and finite.
+        - `aspect_ratio`, if not `None`, must be positive and finite.
+
+        Unset fields hold valid defaults, so only explicitly set fields can fail.
+        `TaffyTree.lint` reports the same problems (every one, not just the first) alongside
+        problems that depend on how fields combine or where the node sits in the tree.
+        """
+    def to_css(self) -> str:
+        """
+        Render the explicitly set fields as a CSS declaration block,
+        e.g. `display: flex; width: 100px; margin-top: auto;`, for comparing layouts with a browser.
+
+        Lengths are emitted in `px`, percentages as `%` (so `Percent(0.5)` is `50%`), fractions in `fr`,
+        and enums by their `css_name`. Grid templates are space-separated track lists,
+        and unset `align_*`/`justify_*` values are emitted as `normal`. Unset fields are omitted,
+        so an empty style renders as `""`.
+        """
+    def interpolate(self, other: Style, t: float) -> Style:
+        """
+        Interpolate between this style (`t = 0`) and `other` (`t = 1`).
+
+        `t` is clamped to `[0, 1]`. Fields set in both styles are linearly interpolated when
+        they are numeric: plain floats (`flex_grow`, `aspect_ratio`, ...) and dimensions whose
+        values are both `Length` or both `Percent`. Other fields set in both styles (enums,
+        grid tracks, `Auto`, mixed units) switch from this style's value to `other`'s at `t = 0.5`.
+        Fields set in only one of the styles are taken from that style.
+
+        Raises:
+            ValueError: If `t` is NaN.
+        """
+    @staticmethod
+    def animate(frames: list[tuple[float, Style]], t: float) -> Style:
+        """
+        Interpolate across keyframes given as `(offset, style)` pairs sorted by offset.
+
+        Finds the pair of keyframes bracketing `t` and interpolates between them with
+        `interpolate()`. Before the first keyframe the first style is returned; after the
+        last keyframe the last style is returned.
+
+        ```python
+        frames = [(0.0, Style(size_width=Length(0))), (1.0, Style(size_width=Length(100)))]
+        Style.animate(frames, 0.25).size_width  # Length(25)
+        ```
+
+        Raises:
+            ValueError: If `frames` is empty or not sorted by offset, or `t` is NaN.
+        """
+    def resolve_padding(self, container_inline_size: float) -> Rect:
+        """
+        Resolve the four padding edges to absolute lengths.
+
+        Per CSS, percentage padding on every edge — including top and bottom — resolves
+        against the containing block's inline size (its width), not its height.
+        [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/padding#values)
+
+        ```python
+        Style(padding_top=Percent(0.1)).resolve_padding(200.0).top  # 20.0
+        ```
+        """
+    @staticmethod
+    def merge_all(*styles: Style) -> Style:
+        """
+        Merge styles left to right, equivalent to `styles[0] | styles[1] | ...`.
+
+        Folding many layers with `|` builds an intermediate Style per layer;
+        `merge_all` applies every layer to a single result instead.
+        With no arguments, returns `Style()`.
+        """
+    def diff(self, other: Style) -> Style:
+        """
+        The fields that changed from `self` to `other`, e.g. for deciding whether to `mark_dirty`.
+
+        A field is included when its value differs or it is set in only one of the two
+        styles. Included fields are marked set and hold `other`'s values, so
+        `self | self.diff(other)` has `other`'s values. If nothing differs,
+        `set_field_names()` of the result is empty.
+        """
+    def unset(self, *names: str) -> Style:
+        """
+        Return a copy with the named fields reset to their defaults and no longer explicitly set.
+
+        The result no longer overrides those fields when merged with `|`,
+        so a lower-precedence style shows through again.
+
+        Raises:
+            ValueError: If a name is not a Style field.
+        """
+    def with_display(self, value: Display | None) -> Style: ...
+    def with_box_sizing(self, value: BoxSizing | None) -> Style: ...
+    def with_overflow(self, value: Overflow | tuple[Overflow, Overflow] | None) -> Style: ...
+    def with_overflow_x(self, value: Overflow | None) -> Style: ...
+    def with_overflow_y(self, value: Overflow | None) -> Style: ...
+    def with_scrollbar_width(self, value: float | None) -> Style: ...
+    def with_position(self, value: Position | None) -> Style: ...
+    def with_inset(self, value: DimensionValue | tuple[DimensionValue, DimensionValue, DimensionValue, DimensionValue] | None) -> Style: ...
+    def with_inset_left(self, value: DimensionValue | None) -> Style: ...
+    def with_inset_right(self, value: DimensionValue | None) -> Style: ...
+    def with_inset_top(self, value: DimensionValue | None) -> Style: ...
+    def with_inset_bottom(self, value: DimensionValue | None) -> Style: ...
+    def with_size(self, value: tuple[DimensionValue, DimensionValue] | None) -> Style: ...
+    def with_size_width(self, value: DimensionValue | None) -> Style: ...
+    def with_size_height(self, value: DimensionValue | None) -> Style: ...
+    def with_min_size(self, value: tuple[DimensionValue, DimensionValue] | None) -> Style: ...
+    def with_min_size_width(self, value: DimensionValue | None) -> Style: ...
+    def with_min_size_height(self, value: DimensionValue | None) -> Style: ...
+    def with_max_size(self, value: tuple[DimensionValue, DimensionValue] | None) -> Style: ...
+    def with_max_size_width(self, value: DimensionValue | None) -> Style: ...
+    def with_max_size_height(self, value: DimensionValue | None) -> Style: ...
+    def with_aspect_ratio(self, value: float | None) -> Style: ...
+    def with_margin(self, value: DimensionValue | tuple[DimensionValue, DimensionValue, DimensionValue, DimensionValue] | None) -> Style: ...
+    def with_margin_left(self, value: DimensionValue | None) -> Style: ...
+    def with_margin_right(self, value: DimensionValue | None) -> Style: ...
+    def with_margin_top(self, value: DimensionValue | None) -> Style: ...
+    def with_margin_bottom(self, value: DimensionValue | None) -> Style: ...
+    def with_padding(self, value: LengthPercentageValue | tuple[LengthPercentageValue, LengthPercentageValue, LengthPercentageValue, LengthPercentageValue] | None) -> Style: ...
+    def with_padding_left(self, value: LengthPercentageValue | None) -> Style: ...
+    def with_padding_right(self, value: LengthPercentageValue | None) -> Style: ...
+    def with_padding_top(self, value: LengthPercentageValue | None) -> Style: ...
+    def with_padding_bottom(self, value: LengthPercentageValue | None) -> Style: ...
+    def with_border(self, value: LengthPercentageValue | tuple[LengthPercentageValue, LengthPercentageValue, LengthPercentageValue, LengthPercentageValue] | None) -> Style: ...
+    def with_border_left(self, value: LengthPercentageValue | None) -> Style: ...
+    def with_border_right(self, value: LengthPercentageValue | None) -> Style: ...
+    def with_border_top(self, value: LengthPercentageValue | None) -> Style: ...
+    def with_border_bottom(self, value: LengthPercentageValue | None) -> Style: ...
+    def with_align_items(self, value: AlignItems | None) -> Style: ...
+    def with_align_self(self, value: AlignItems | None) -> Style: ...
+    def with_justify_items(self, value: AlignItems | None) -> Style: ...
+    def with_justify_self(self, value: AlignItems | None) -> Style: ...
+    def with_align_content(self, value: AlignContent | None) -> Style: ...
+    def with_justify_content(self, value: AlignContent | None) -> Style: ...
+    def with_gap(self, value: LengthPercentageValue | tuple[LengthPercentageValue, LengthPercentageValue] | None) -> Style: ...
+    def with_gap_width(self, value: LengthPercentageValue | None) -> Style: ...
+    def with_gap_height(self, value: LengthPercentageValue | None) -> Style: ...
+    def with_text_align(self, value: TextAlign | None) -> Style: ...
+    def with_flex_direction(self, value: FlexDirection | None) -> Style: ...
+    def with_flex_wrap(self, value: FlexWrap | None) -> Style: ...
+    def with_flex_basis(self, value: DimensionValue | None) -> Style: ...
+    def with_flex_grow(self, value: float | None) -> Style: ...
+    def with_flex_shrink(self, value: float | None) -> Style: ...
+    def with_grid_template_rows(self, value: list[GridTemplateValue] | None) -> Style: ...
+    def with_grid_template_columns(self, value: list[GridTemplateValue] | None) -> Style: ...
+    def with_grid_auto_rows(self, value: list[GridTrackValue] | None) -> Style: ...
+    def with_grid_auto_columns(self, value: list[GridTrackValue] | None) -> Style: ...
+    def with_grid_auto_flow(self, value: GridAutoFlow | None) -> Style: ...
+    def with_grid_row(self, value: GridPlacement | None) -> Style: ...
+    def with_grid_column(self, value: GridPlacement | None) -> Style: ...
+
+def intern_style(style: Style) -> Style:
+    """
+    Return a canonical instance for `style`, so that many nodes sharing identical styles
+    can share one object: interning equal styles (by `==`) on the same thread returns
+    the same object.
+
+    Styles are immutable, so sharing an interned instance is always safe. The table holds
+    up to 4096 distinct styles per thread; once it is full, interning a new style evicts
+    the least recently interned one, after which an equal style may intern to a new
+    (still equal) instance.
+    """
+
+def simple_measure(
+    func: Callable[[KnownSize, AvailableSize], Size | MeasureResult],
+) -> Callable[[KnownSize, AvailableSize, object], Size | MeasureResult]:
+    """
+    Adapt a measure function that doesn't need the node context, `func(known, available)`,
+    into the three-argument form that `TaffyTree.compute_layout` expects.
+
+    Works as a decorator:
+
+    ```python
+    @waxy.simple_measure
+    def measure(known: waxy.KnownSize, available: waxy.AvailableSize) -> waxy.Size:
+        return waxy.Size(40, 12)
+
+    tree.compute_layout(root, measure=measure)
+    ```
+
+    Only leaves with a context are measured, as with any other measure function.
+    """
 
 # Tree
 
@@ -984,6 +2010,20 @@ class TaffyTree[NodeContext = object]:
     def __init__(self) -> None:
         """Create a new empty layout tree."""
     def __repr__(self) -> str: ...
+    def __copy__(self) -> TaffyTree[NodeContext]:
+        """Same as `clone()`, for `copy.copy(tree)`."""
+    def __enter__(self) -> Self: ...
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_value: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None:
+        """
+        Clear the tree when leaving a `with` block, dropping every node and releasing
+        their contexts promptly rather than when the tree is garbage collected.
+        Exceptions are not suppressed.
+        """
     @staticmethod
     def with_capacity(capacity: int) -> TaffyTree[NodeContext]:
         """Create a new layout tree with pre-allocated capacity."""
@@ -991,42 +2031,210 @@ class TaffyTree[NodeContext = object]:
         """Create a new leaf node with the given style."""
     def new_leaf_with_context(self, style: Style, context: NodeContext) -> NodeId:
         """Create a new leaf node with the given style and context."""
+    def new_leaves(self, styles: Sequence[Style]) -> list[NodeId]:
+        """
+        Create a leaf node for each style, returning their ids in the same order.
+
+        Much faster than calling `new_leaf` in a Python loop for large batches.
+        """
+    def new_leaves_with_context(self, pairs: Sequence[tuple[Style, NodeContext]]) -> list[NodeId]:
+        """
+        Create a leaf node for each `(style, context)` pair, returning their ids in the same order.
+        """
     def get_node_context(self, node: NodeId) -> NodeContext | None:
         """Get the context attached to a node, if any."""
     def set_node_context(self, node: NodeId, context: NodeContext | None) -> None:
         """Set or clear the context attached to a node."""
+    def node_label(self, node: NodeId) -> str | None:
+        """Get the debugging label attached to a node, if any."""
+    def set_node_label(self, node: NodeId, label: str | None) -> None:
+        """
+        Set or clear a human-readable label for a node, shown by `format_tree` and `print_tree`.
+
+        Labels are independent of the node's context, and are dropped when the node
+        is removed or the tree is cleared.
+        """
+    def set_context_factory(self, factory: Callable[[NodeId], NodeContext | None] | None) -> None:
+        """
+        Set or clear the factory used to produce contexts for leaves that lack one.
+
+        When `compute_layout` is called with a measure function and taffy needs to measure
+        a leaf that has no context attached, the factory is called with its `NodeId`.
+        Leaves that taffy doesn't measure (because they are hidden with `Display.Nil` or
+        have a cached layout) and leaves whose `size` is a `Length` in both axes are never
+        passed to the factory.
+
+        A non-None return value becomes the node's context: it is passed to the measure
+        function and returned by `get_node_context` from then on, without marking the node
+        dirty. Returning None leaves the node without context.
+        """
     def new_with_children(self, style: Style, children: list[NodeId]) -> NodeId:
         """Create a new node with children."""
+    def component(
+        self,
+        style: Style,
+        builder: Callable[[TaffyTree[NodeContext], NodeId], object],
+    ) -> NodeId:
+        """
+        Create a container node with the given style, call `builder(tree, node)` to
+        populate it, and return the node.
+
+        The builder adds children with the usual methods (`add_child`, `new_leaf`, ...)
+        and may itself call `component` to build nested subtrees. Its return value is ignored.
+        If it raises, the exception propagates and the partially built container is left
+        in the tree.
+        """
     def add_child(self, parent: NodeId, child: NodeId) -> None:
-        """Add a child to a parent node."""
+        """
+        Add a child to a parent node.
+
+        Raises:
+            CycleDetected: If `child` is `parent` itself or one of its ancestors.
+        """
     def insert_child_at_index(self, parent: NodeId, child_index: int, child: NodeId) -> None:
-        """Insert a child at a specific index."""
+        """
+        Insert a child at a specific index.
+
+        Raises:
+            CycleDetected: If `child` is `parent` itself or one of its ancestors.
+        """
     def set_children(self, parent: NodeId, children: list[NodeId]) -> None:
-        """Set the children of a node, replacing any existing children."""
+        """
+        Set the children of a node, replacing any existing children.
+
+        Raises:
+            CycleDetected: If any of `children` is `parent` itself or one of its ancestors.
+        """
     def remove_child(self, parent: NodeId, child: NodeId) -> NodeId:
         """Remove a specific child from a parent."""
     def remove_child_at_index(self, parent: NodeId, child_index: int) -> NodeId:
         """Remove a child at a specific index."""
     def replace_child_at_index(self, parent: NodeId, child_index: int, new_child: NodeId) -> NodeId:
-        """Replace the child at a specific index with a new child."""
+        """
+        Replace the child at a specific index with a new child.
+
+        Raises:
+            CycleDetected: If `new_child` is `parent` itself or one of its ancestors.
+        """
     def child_at_index(self, parent: NodeId, child_index: int) -> NodeId:
         """Get the child at a specific index."""
     def children(self, parent: NodeId) -> list[NodeId]:
         """Get all children of a node."""
+    def children_iter(self, parent: NodeId) -> Iterator[NodeId]:
+        """
+        Lazily iterate over the children of a node, without building a list like `children()`.
+
+        The tree should not be modified while iterating.
+        """
+    def descendants(self, root: NodeId, order: Literal["dfs", "bfs"] = "dfs") -> Iterator[NodeId]:
+        """
+        Iterate over `root` and all of its descendants, starting with `root`.
+
+        `order="dfs"` walks depth-first in pre-order (each node before its children);
+        `order="bfs"` walks breadth-first, level by level. Children are visited in order.
+        The walk is lazy, so the tree should not be modified while iterating.
+
+        Raises:
+            ValueError: If `order` is not `"dfs"` or `"bfs"`.
+        """
+    def find(self, root: NodeId, predicate: Callable[[NodeId], object]) -> NodeId | None:
+        """
+        The first node in `root`'s subtree, in depth-first pre-order, for which `predicate`
+        returns a truthy value, or None if there is no such node.
+
+        Exceptions raised by `predicate` propagate to the caller.
+        """
+    def find_all(self, root: NodeId, predicate: Callable[[NodeId], object]) -> list[NodeId]:
+        """
+        Every node in `root`'s subtree, in depth-first pre-order, for which `predicate`
+        returns a truthy value.
+
+        Exceptions raised by `predicate` propagate to the caller.
+        """
     def child_count(self, parent: NodeId) -> int:
         """Get the number of children of a node."""
     def parent(self, child: NodeId) -> NodeId | None:
         """Get the parent of a node, if any."""
+    def ancestors(self, node: NodeId) -> list[NodeId]:
+        """The ancestors of a node, from its immediate parent up to the root (empty for a root)."""
+    def depth(self, node: NodeId) -> int:
+        """The number of edges between a node and its root (0 for a root)."""
     def total_node_count(self) -> int:
         """Get the total number of nodes in the tree."""
+    def subtree_node_count(self, node: NodeId) -> int:
+        """Get the number of nodes in the subtree rooted at `node`, including `node` itself."""
     def remove(self, node: NodeId) -> NodeId:
         """Remove a node from the tree."""
     def clear(self) -> None:
         """Clear all nodes from the tree."""
+    def contains(self, node: NodeId) -> bool:
+        """
+        Whether `node` is still in this tree. False for removed nodes, nodes dropped by
+        `clear`, and nodes from other trees; use it to guard `style`, `layout`, etc.,
+        which raise `InvalidNodeId` for such nodes.
+        """
+    def roots(self) -> list[NodeId]:
+        """
+        Every node without a parent, sorted by id. A freshly created leaf that hasn't
+        been added to a parent counts as a root, as do children orphaned by `remove`.
+        """
+    def clone(self) -> TaffyTree[NodeContext]:
+        """
+        An independent copy of the tree, with the same structure, styles, computed layouts,
+        and context factory. Contexts are shared by reference, not copied.
+
+        The copy is a separate tree, so passing it a `NodeId` from the original raises
+        `WrongTree`. Use [`translate()`][waxy.TaffyTree.translate] on the copy to find the
+        node corresponding to one from the original.
+        """
+    def translate(self, node: NodeId) -> NodeId:
+        """
+        The node in this tree that corresponds to `node`, where `node` belongs either to this
+        tree (and is returned unchanged) or to the tree this one was `clone()`d from.
+
+        ```python
+        experiment = tree.clone()
+        experiment.set_style(experiment.translate(root), Style(display=Display.Grid))
+        ```
+
+        Raises:
+            WrongTree: If `node` belongs to any other tree.
+            InvalidNodeId: If `node` was created in the original after the clone was made.
+        """
     def set_style(self, node: NodeId, style: Style) -> None:
         """Set the style of a node."""
+    def set_styles(self, updates: Sequence[tuple[NodeId, Style]]) -> None:
+        """
+        Set the styles of many nodes in one call, applying the updates in order.
+
+        Much faster than calling `set_style` in a Python loop for large batches.
+
+        Raises:
+            WrongTree: If any node belongs to a different tree. Nothing is applied.
+            InvalidNodeId: If a node has been removed. Updates before it have already
+                been applied; it and the updates after it are not.
+        """
     def style(self, node: NodeId) -> Style:
-        """Get the style of a node."""
+        """
+        Get the style of a node.
+
+        The tree remembers which fields the style it was given set explicitly, so the
+        returned style compares equal to that one.
+        """
+    def set_child_style(self, parent: NodeId, child_index: int, style: Style) -> None:
+        """
+        Set the style of the child at `child_index` of `parent`, without looking up its `NodeId`.
+
+        Raises:
+            ChildIndexOutOfBounds: If `parent` has no child at `child_index`.
+        """
+    def child_style(self, parent: NodeId, child_index: int) -> Style:
+        """
+        Get the style of the child at `child_index` of `parent`.
+
+        Raises:
+            ChildIndexOutOfBounds: If `parent` has no child at `child_index`.
+        """
     def mark_dirty(self, node: NodeId) -> None:
         """Mark a node as dirty (needing re-layout)."""
     def dirty(self, node: NodeId) -> bool:
@@ -1034,17 +2242,181 @@ class TaffyTree[NodeContext = object]:
     def compute_layout(
         self,
         node: NodeId,
-        available: AvailableSize | None = None,
-        measure: Callable[[KnownSize, AvailableSize, NodeContext], Size] | None = None,
+        available: AvailableSize | tuple[float, float] | float | None = None,
+        measure: Callable[[KnownSize, AvailableSize, NodeContext], Size | MeasureResult] | None = None,
+    ) -> None:
+        """
+        Compute the layout of a tree rooted at the given node.
+
+        `available` is an `AvailableSize`, a `(width, height)` pair of floats, or a single
+        float for both axes; plain floats are `Definite`. It defaults to `MaxContent` on both
+        axes. The same forms are accepted by `compute_and_get_layout` and `measure_node`.
+
+        If given, `measure` is called for leaves with a context to size their content.
+        It must return a `Size` or a `MeasureResult`.
+
+        Recomputing with a new `available` (e.g. after a window resize) needs no
+        `mark_dirty`: taffy caches each node's layout by the space it is given, so subtrees
+        whose constraints don't change are reused rather than laid out or measured again.
+
+        Raises:
+            TypeError: If `measure` returns anything else, including None.
+                The message names the node being measured.
+        """
+    def compute_layout_all(
+        self,
+        roots: Sequence[NodeId],
+        available: AvailableSize | tuple[float, float] | float | None = None,
+        measure: Callable[[KnownSize, AvailableSize, NodeContext], Size | MeasureResult] | None = None,
     ) -> None:
-        """Compute the layout of a tree rooted at the given node."""
+        """
+        Compute the layout of several independent trees in one call, e.g. one per window.
+
+        Each root is laid out in order, as by `compute_layout`, with the same `available`
+        space and `measure` function. Layout stops at the first error, leaving the
+        remaining roots uncomputed. `last_layout_stats()` covers every root laid out.
+
+        Raises:
+            TypeError: If `measure` returns anything other than a `Size` or `MeasureResult`.
+        """
+    def compute_and_get_layout(
+        self,
+        node: NodeId,
+        available: AvailableSize | tuple[float, float] | float | None = None,
+        measure: Callable[[KnownSize, AvailableSize, NodeContext], Size | MeasureResult] | None = None,
+    ) -> Layout:
+        """
+        Compute the layout of a tree rooted at the given node and return that node's `Layout`.
+
+        Equivalent to `compute_layout(node, available, measure)` followed by `layout(node)`.
+        """
+    def measure_node(
+        self,
+        node: NodeId,
+        available: AvailableSize | tuple[float, float] | float | None = None,
+        measure: Callable[[KnownSize, AvailableSize, NodeContext], Size | MeasureResult] | None = None,
+    ) -> Size:
+        """
+        Measure a single node by laying it out as a root against the given available space.
+
+        Useful for sizing content (tooltips, popovers) whose node lives in the tree but
+        is not part of the main layout pass. Laying the node out as a root overwrites its
+        stored layout, so it is marked dirty afterwards; the next `compute_layout` of its
+        real root lays it out in place again.
+        """
+    def last_layout_stats(self) -> dict[str, int]:
+        """
+        Counters from the most recent layout run (`compute_layout`, `compute_layout_all`,
+        `compute_and_get_layout`, or `measure_node`), reset at the start of each run:
+
+        - `nodes_laid_out`: nodes with no cached layout when the run started. A warm
+          recompute of an unchanged tree reports 0.
+        - `measure_calls`: calls into the Python measure function.
+
+        Both are 0 before the first layout.
+        """
     def layout(self, node: NodeId) -> Layout:
         """Get the computed layout of a node."""
+    def to_dict(self, root: NodeId) -> dict[str, object]:
+        """
+        Serialize the subtree rooted at `root` to nested dicts, e.g. for golden-file tests.
+
+        Each node becomes `{"node_id": int, "style": ..., "layout": ..., "children": [...]}`,
+        where `style` is `Style.to_dict()` of the node's style and `layout` is
+        `Layout.to_dict()`. `layout` is omitted for nodes that are dirty (not yet laid out,
+        or changed since the last layout). Children are in tree order.
+        """
+    def to_svg(self, root: NodeId) -> str:
+        """
+        Render the computed layout of the subtree rooted at `root` as a standalone SVG document,
+        for visual debugging.
+
+        Each node is drawn at its absolute position (layout locations summed down from `root`)
+        as two `<rect>`s, its border box (solid black) and its padding box (dashed blue),
+        labelled with its node id.
+
+        Raises:
+            ValueError: If the layout of `root` has not been computed (it is dirty).
+        """
+    def lint(self, root: NodeId) -> list[tuple[NodeId, str]]:
+        """
+        Check the styles in the subtree rooted at `root` for likely mistakes, returning
+        `(node, message)` pairs in depth-first pre-order (empty if nothing was found).
+
+        Each node's style is checked on its own (e.g. grid track fields on a non-grid
+        container, or `min_size_width` larger than `max_size_width`) and against its parent
+        (e.g. `grid_column` on a child of a non-grid container, `flex_grow` on a child of
+        a non-flex container, or line-based grid placement without explicit grid templates).
+        Out-of-range numeric fields are reported with the same checks and messages as
+        [`Style.validate()`][waxy.Style.validate].
+        Linting never changes the tree or affects layout.
+        """
+    def visible_layouts(self, root: NodeId) -> list[tuple[NodeId, Layout]]:
+        """
+        The layouts of `root` and its descendants in depth-first pre-order,
+        skipping any subtree whose root has `display=Display.Nil`.
+
+        taffy keeps hidden nodes in the tree with zero-sized layouts; this lets renderers
+        skip them without checking each node's style.
+        """
+    def absolute_layout(self, node: NodeId) -> Layout:
+        """
+        Get the computed layout of a node with `location` relative to the tree root
+        instead of the parent.
+
+        The location is the sum of the relative locations of the node and each of its
+        ancestors, so a root node's layout is returned unchanged. All other fields are
+        the same as `layout()`.
+        """
+    def iter_layout(self, root: NodeId) -> Iterator[tuple[NodeId, Layout, Point]]:
+        """
+        Iterate over `(node, layout, absolute_location)` for `root` and its descendants in
+        depth-first pre-order, in a single walk.
+
+        `layout` is the same as `layout(node)` and `absolute_location` is the same as
+        `absolute_layout(node).location`.
+
+        Raises:
+            ValueError: If the layout of `root` has not been computed (it is dirty).
+        """
+    def visible_nodes(self, root: NodeId, viewport: Rect) -> list[NodeId]:
+        """
+        The nodes under `root`, including `root`, whose absolute border box overlaps `viewport`,
+        in depth-first pre-order. Useful for culling nodes scrolled out of view.
+
+        Boxes are positioned as in `iter_layout`. Partially visible nodes are included;
+        nodes that lie entirely outside the viewport, or only touch its edge, are not.
+        Descendants are checked even if their parent is outside the viewport,
+        since they may overflow it.
+
+        Raises:
+            ValueError: If the layout of `root` has not been computed (it is dirty).
+        """
     def unrounded_layout(self, node: NodeId) -> Layout:
         """Get the unrounded layout of a node."""
     def enable_rounding(self) -> None:
         """Enable rounding of layout values."""
     def disable_rounding(self) -> None:
         """Disable rounding of layout values."""
+    def is_rounding_enabled(self) -> bool:
+        """Whether layout values are rounded. Rounding is enabled by default."""
+    def rounding_disabled(self) -> AbstractContextManager[None]:
+        """
+        A context manager that disables rounding on entry
+        and restores the previous setting on exit, even if an exception was raised.
+
+        ```python
+        with tree.rounding_disabled():
+            tree.compute_layout(root)
+        ```
+        """
     def print_tree(self, root: NodeId) -> None:
-        """Print the layout tree for debugging."""
+        """Print the layout tree for debugging (the output of `format_tree`) to `sys.stdout`."""
+    def format_tree(self, root: NodeId) -> str:
+        """
+        Render the layout tree rooted at `root` for debugging.
+
+        The first line is `TREE`, followed by one line per node with its display type,
+        computed location, size, content size, border, padding, and node id,
+        indented to show the tree structure.
+        """
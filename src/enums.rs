@@ -1,4 +1,118 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+/// A Python-exposed enum whose variants can be looked up by their Python names.
+pub(crate) trait NamedEnum: Clone + 'static {
+    /// The Python class name.
+    const TYPE_NAME: &'static str;
+
+    /// Every variant in declaration order, paired with its Python name.
+    const VARIANTS: &'static [(Self, &'static str)];
+
+    /// Alternative spellings accepted by `from_str` (e.g. `"None"` for `Display.Nil`).
+    const ALIASES: &'static [(Self, &'static str)] = &[];
+
+    /// The Python name of this variant (e.g. `"Flex"`, `"Nil"`).
+    fn name(&self) -> &'static str;
+
+    /// The CSS keyword for this variant (e.g. `"flex"`, `"none"`, `"space-between"`).
+    fn css_name(&self) -> &'static str;
+
+    /// Look up a variant by its exact Python name.
+    fn from_name(name: &str) -> Option<Self> {
+        Self::VARIANTS
+            .iter()
+            .find(|(_, n)| *n == name)
+            .map(|(v, _)| v.clone())
+    }
+
+    /// Look up a variant by its Python name or an alias, ignoring ASCII case.
+    fn from_name_insensitive(name: &str) -> PyResult<Self> {
+        Self::VARIANTS
+            .iter()
+            .chain(Self::ALIASES)
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|(v, _)| v.clone())
+            .ok_or_else(|| {
+                let valid: Vec<&str> = Self::VARIANTS.iter().map(|(_, n)| *n).collect();
+                PyValueError::new_err(format!(
+                    "unknown {} variant: {name:?} (expected one of: {})",
+                    Self::TYPE_NAME,
+                    valid.join(", ")
+                ))
+            })
+    }
+
+    /// Look up a variant by its CSS keyword, ignoring ASCII case.
+    fn from_css_name(css: &str) -> PyResult<Self> {
+        Self::VARIANTS
+            .iter()
+            .map(|(v, _)| v)
+            .find(|v| v.css_name().eq_ignore_ascii_case(css))
+            .cloned()
+            .ok_or_else(|| {
+                let valid: Vec<&str> = Self::VARIANTS.iter().map(|(v, _)| v.css_name()).collect();
+                PyValueError::new_err(format!(
+                    "unknown {} keyword: {css:?} (expected one of: {})",
+                    Self::TYPE_NAME,
+                    valid.join(", ")
+                ))
+            })
+    }
+}
+
+macro_rules! named_enum {
+    ($ty:ident { $($variant:ident => $name:literal as $css:literal),+ $(,)? } $(aliases { $($alias_variant:ident => $alias:literal),+ $(,)? })?) => {
+        impl NamedEnum for $ty {
+            const TYPE_NAME: &'static str = stringify!($ty);
+            const VARIANTS: &'static [(Self, &'static str)] = &[$(($ty::$variant, $name)),+];
+            $(const ALIASES: &'static [(Self, &'static str)] = &[$(($ty::$alias_variant, $alias)),+];)?
+
+            fn name(&self) -> &'static str {
+                match self {
+                    $($ty::$variant => $name),+
+                }
+            }
+
+            fn css_name(&self) -> &'static str {
+                match self {
+                    $($ty::$variant => $css),+
+                }
+            }
+        }
+
+        #[pymethods]
+        impl $ty {
+            /// Look up a variant by name, ignoring case.
+            #[classmethod]
+            fn from_str(_cls: &Bound<'_, PyType>, name: &str) -> PyResult<Self> {
+                <Self as NamedEnum>::from_name_insensitive(name)
+            }
+
+            /// Every variant in declaration order.
+            #[classmethod]
+            fn members(_cls: &Bound<'_, PyType>) -> Vec<Self> {
+                <Self as NamedEnum>::VARIANTS
+                    .iter()
+                    .map(|(v, _)| v.clone())
+                    .collect()
+            }
+
+            /// The variant's name.
+            #[getter(name)]
+            fn py_name(&self) -> &'static str {
+                NamedEnum::name(self)
+            }
+
+            /// The CSS keyword for this variant.
+            #[getter]
+            fn css_name(&self) -> &'static str {
+                NamedEnum::css_name(self)
+            }
+        }
+    };
+}
 
 /// How the node should be displayed.
 #[pyclass(eq, eq_int, from_py_object, module = "waxy")]
@@ -11,6 +125,15 @@ pub enum Display {
     None = 3,
 }
 
+named_enum!(Display {
+    Block => "Block" as "block",
+    Flex => "Flex" as "flex",
+    Grid => "Grid" as "grid",
+    None => "Nil" as "none",
+} aliases {
+    None => "None",
+});
+
 impl From<taffy::Display> for Display {
     fn from(d: taffy::Display) -> Self {
         match d {
@@ -41,6 +164,11 @@ pub enum Position {
     Absolute = 1,
 }
 
+named_enum!(Position {
+    Relative => "Relative" as "relative",
+    Absolute => "Absolute" as "absolute",
+});
+
 impl From<taffy::Position> for Position {
     fn from(p: taffy::Position) -> Self {
         match p {
@@ -69,6 +197,13 @@ pub enum FlexDirection {
     ColumnReverse = 3,
 }
 
+named_enum!(FlexDirection {
+    Row => "Row" as "row",
+    Column => "Column" as "column",
+    RowReverse => "RowReverse" as "row-reverse",
+    ColumnReverse => "ColumnReverse" as "column-reverse",
+});
+
 impl From<taffy::FlexDirection> for FlexDirection {
     fn from(d: taffy::FlexDirection) -> Self {
         match d {
@@ -100,6 +235,12 @@ pub enum FlexWrap {
     WrapReverse = 2,
 }
 
+named_enum!(FlexWrap {
+    NoWrap => "NoWrap" as "nowrap",
+    Wrap => "Wrap" as "wrap",
+    WrapReverse => "WrapReverse" as "wrap-reverse",
+});
+
 impl From<taffy::FlexWrap> for FlexWrap {
     fn from(w: taffy::FlexWrap) -> Self {
         match w {
@@ -133,6 +274,16 @@ pub enum AlignItems {
     Stretch = 6,
 }
 
+named_enum!(AlignItems {
+    Start => "Start" as "start",
+    End => "End" as "end",
+    FlexStart => "FlexStart" as "flex-start",
+    FlexEnd => "FlexEnd" as "flex-end",
+    Center => "Center" as "center",
+    Baseline => "Baseline" as "baseline",
+    Stretch => "Stretch" as "stretch",
+});
+
 impl From<taffy::AlignItems> for AlignItems {
     fn from(a: taffy::AlignItems) -> Self {
         match a {
@@ -176,6 +327,18 @@ pub enum AlignContent {
     SpaceAround = 8,
 }
 
+named_enum!(AlignContent {
+    Start => "Start" as "start",
+    End => "End" as "end",
+    FlexStart => "FlexStart" as "flex-start",
+    FlexEnd => "FlexEnd" as "flex-end",
+    Center => "Center" as "center",
+    Stretch => "Stretch" as "stretch",
+    SpaceBetween => "SpaceBetween" as "space-between",
+    SpaceEvenly => "SpaceEvenly" as "space-evenly",
+    SpaceAround => "SpaceAround" as "space-around",
+});
+
 impl From<taffy::AlignContent> for AlignContent {
     fn from(a: taffy::AlignContent) -> Self {
         match a {
@@ -218,6 +381,13 @@ pub enum Overflow {
     Scroll = 3,
 }
 
+named_enum!(Overflow {
+    Visible => "Visible" as "visible",
+    Clip => "Clip" as "clip",
+    Hidden => "Hidden" as "hidden",
+    Scroll => "Scroll" as "scroll",
+});
+
 impl From<taffy::Overflow> for Overflow {
     fn from(o: taffy::Overflow) -> Self {
         match o {
@@ -250,6 +420,13 @@ pub enum GridAutoFlow {
     ColumnDense = 3,
 }
 
+named_enum!(GridAutoFlow {
+    Row => "Row" as "row",
+    Column => "Column" as "column",
+    RowDense => "RowDense" as "row dense",
+    ColumnDense => "ColumnDense" as "column dense",
+});
+
 impl From<taffy::GridAutoFlow> for GridAutoFlow {
     fn from(g: taffy::GridAutoFlow) -> Self {
         match g {
@@ -280,6 +457,11 @@ pub enum BoxSizing {
     ContentBox = 1,
 }
 
+named_enum!(BoxSizing {
+    BorderBox => "BorderBox" as "border-box",
+    ContentBox => "ContentBox" as "content-box",
+});
+
 impl From<taffy::BoxSizing> for BoxSizing {
     fn from(b: taffy::BoxSizing) -> Self {
         match b {
@@ -308,6 +490,13 @@ pub enum TextAlign {
     LegacyCenter = 3,
 }
 
+named_enum!(TextAlign {
+    Auto => "Auto" as "auto",
+    LegacyLeft => "LegacyLeft" as "-webkit-left",
+    LegacyRight => "LegacyRight" as "-webkit-right",
+    LegacyCenter => "LegacyCenter" as "-webkit-center",
+});
+
 impl From<taffy::TextAlign> for TextAlign {
     fn from(t: taffy::TextAlign) -> Self {
         match t {
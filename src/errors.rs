@@ -39,12 +39,24 @@ create_exception!(
     TaffyException,
     "Input node is invalid."
 );
+create_exception!(
+    waxy,
+    CycleDetected,
+    TaffyException,
+    "Adding a child would make a node its own ancestor."
+);
 create_exception!(
     waxy,
     InvalidNodeId,
     TaffyException,
     "Node ID is not valid (node may have been removed)."
 );
+create_exception!(
+    waxy,
+    WrongTree,
+    InvalidNodeId,
+    "Node ID belongs to a different TaffyTree."
+);
 // InvalidPercent, InvalidLength, InvalidGridLine, InvalidGridSpan are defined as subclasses
 // of WaxyException initially; we set __bases__ to (WaxyException, ValueError) in register()
 // to achieve multi-inheritance.
@@ -163,6 +175,7 @@ pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("InvalidParentNode", py.get_type::<InvalidParentNode>())?;
     m.add("InvalidChildNode", py.get_type::<InvalidChildNode>())?;
     m.add("InvalidInputNode", py.get_type::<InvalidInputNode>())?;
+    m.add("CycleDetected", py.get_type::<CycleDetected>())?;
 
     // Set __bases__ = (TaffyException, KeyError) for InvalidNodeId.
     let taffy_exc_type = py.get_type::<TaffyException>();
@@ -172,6 +185,7 @@ pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     let invalid_node_id_type = py.get_type::<InvalidNodeId>();
     invalid_node_id_type.setattr("__bases__", &node_id_bases)?;
     m.add("InvalidNodeId", invalid_node_id_type)?;
+    m.add("WrongTree", py.get_type::<WrongTree>())?;
 
     // Set __bases__ = (WaxyException, ValueError) for multi-inheritance on validation errors.
     let waxy_exc_type = py.get_type::<WaxyException>();
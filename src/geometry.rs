@@ -1,3 +1,4 @@
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
 
 use crate::values::{available_space_to_py, AvailableSpaceInput};
@@ -28,6 +29,17 @@ impl Size {
         Self { width, height }
     }
 
+    /// Like the constructor, but reject negative dimensions.
+    #[staticmethod]
+    fn checked(width: f32, height: f32) -> PyResult<Self> {
+        if width < 0.0 || height < 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "Size dimensions must not be negative, got width={width}, height={height}"
+            )));
+        }
+        Ok(Self { width, height })
+    }
+
     fn __repr__(&self) -> String {
         format!("Size(width={}, height={})", self.width, self.height)
     }
@@ -43,11 +55,95 @@ impl Size {
         std::hash::Hasher::finish(&hasher)
     }
 
+    /// False when the area is zero.
+    fn __bool__(&self) -> bool {
+        self.area() != 0.0
+    }
+
+    fn __iter__(&self) -> PairIter {
+        PairIter::new(self.width, self.height)
+    }
+
+    fn __add__(&self, other: &Size) -> Size {
+        Size {
+            width: self.width + other.width,
+            height: self.height + other.height,
+        }
+    }
+
+    fn __sub__(&self, other: &Size) -> Size {
+        Size {
+            width: self.width - other.width,
+            height: self.height - other.height,
+        }
+    }
+
+    fn __mul__(&self, scalar: f32) -> Size {
+        self.map(|v| v * scalar)
+    }
+
+    fn __rmul__(&self, scalar: f32) -> Size {
+        self.__mul__(scalar)
+    }
+
+    fn __truediv__(&self, scalar: f32) -> Size {
+        self.map(|v| v / scalar)
+    }
+
     /// The area (width * height).
     #[getter]
     fn area(&self) -> f32 {
         self.width * self.height
     }
+
+    /// Check whether both width and height are finite (not NaN or infinite).
+    fn is_finite(&self) -> bool {
+        self.width.is_finite() && self.height.is_finite()
+    }
+
+    /// Round both dimensions down.
+    fn floor(&self) -> Size {
+        self.map(f32::floor)
+    }
+
+    /// Round both dimensions up.
+    fn ceil(&self) -> Size {
+        self.map(f32::ceil)
+    }
+
+    /// Round both dimensions to the nearest integer, with halves rounded away from zero.
+    fn round(&self) -> Size {
+        self.map(f32::round)
+    }
+
+    /// The largest uniformly scaled copy of this size that fits within `bounds`.
+    fn scale_to_fit(&self, bounds: &Size) -> Size {
+        self.scale_by_ratio(bounds, f32::min)
+    }
+
+    /// The smallest uniformly scaled copy of this size that covers `bounds`.
+    fn scale_to_cover(&self, bounds: &Size) -> Size {
+        self.scale_by_ratio(bounds, f32::max)
+    }
+}
+
+impl Size {
+    fn map(&self, f: impl Fn(f32) -> f32) -> Size {
+        Size {
+            width: f(self.width),
+            height: f(self.height),
+        }
+    }
+
+    /// Scale uniformly by `pick` of the width and height ratios to `bounds`.
+    /// A size with no width or height has no aspect ratio to preserve, so it scales to zero.
+    fn scale_by_ratio(&self, bounds: &Size, pick: fn(f32, f32) -> f32) -> Size {
+        if self.width == 0.0 || self.height == 0.0 {
+            return Size::new(0.0, 0.0);
+        }
+        let scale = pick(bounds.width / self.width, bounds.height / self.height);
+        self.map(|v| v * scale)
+    }
 }
 
 impl From<taffy::Size<f32>> for Size {
@@ -95,6 +191,33 @@ impl Rect {
         }
     }
 
+    /// The rectangle with its top-left corner at `origin` and the given size.
+    #[staticmethod]
+    fn from_origin_size(origin: &Point, size: &Size) -> Self {
+        Self {
+            left: origin.x,
+            right: origin.x + size.width,
+            top: origin.y,
+            bottom: origin.y + size.height,
+        }
+    }
+
+    /// Like the constructor, but reject rectangles with negative width or height.
+    #[staticmethod]
+    fn checked(left: f32, right: f32, top: f32, bottom: f32) -> PyResult<Self> {
+        if right < left {
+            return Err(PyValueError::new_err(format!(
+                "Rect right ({right}) must not be less than left ({left})"
+            )));
+        }
+        if bottom < top {
+            return Err(PyValueError::new_err(format!(
+                "Rect bottom ({bottom}) must not be less than top ({top})"
+            )));
+        }
+        Ok(Self::new(left, right, top, bottom))
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "Rect(left={}, right={}, top={}, bottom={})",
@@ -118,6 +241,11 @@ impl Rect {
         std::hash::Hasher::finish(&hasher)
     }
 
+    /// False when the rectangle is empty (zero or negative width or height).
+    fn __bool__(&self) -> bool {
+        self.width() > 0.0 && self.height() > 0.0
+    }
+
     /// The width of the rectangle (right - left).
     #[getter]
     fn width(&self) -> f32 {
@@ -147,6 +275,22 @@ impl Rect {
             && point.y <= self.bottom
     }
 
+    /// The point on or inside this rectangle nearest to `point`, found by clamping each axis.
+    fn closest_point(&self, point: &Point) -> Point {
+        Point {
+            x: point.x.max(self.left).min(self.right),
+            y: point.y.max(self.top).min(self.bottom),
+        }
+    }
+
+    /// Check whether all four edges are finite (not NaN or infinite).
+    fn is_finite(&self) -> bool {
+        self.left.is_finite()
+            && self.right.is_finite()
+            && self.top.is_finite()
+            && self.bottom.is_finite()
+    }
+
     /// The top-left corner point.
     #[getter]
     fn top_left(&self) -> Point {
@@ -183,6 +327,15 @@ impl Rect {
         }
     }
 
+    /// The center point.
+    #[getter]
+    fn center(&self) -> Point {
+        Point {
+            x: (self.left + self.right) / 2.0,
+            y: (self.top + self.bottom) / 2.0,
+        }
+    }
+
     /// Return the four corner points (top-left, top-right, bottom-right, bottom-left).
     fn corners(&self) -> (Point, Point, Point, Point) {
         (
@@ -199,9 +352,11 @@ impl Rect {
         PixelIter {
             x_start: self.left.ceil() as i32,
             x_end: self.right.floor() as i32,
+            y_start: y,
             y_end: y,
             x: self.left.ceil() as i32,
             y,
+            column_major: false,
         }
     }
 
@@ -211,9 +366,11 @@ impl Rect {
         PixelIter {
             x_start: self.left.ceil() as i32,
             x_end: self.right.floor() as i32,
+            y_start: y,
             y_end: y,
             x: self.left.ceil() as i32,
             y,
+            column_major: false,
         }
     }
 
@@ -223,9 +380,11 @@ impl Rect {
         PixelIter {
             x_start: x,
             x_end: x,
+            y_start: self.top.ceil() as i32,
             y_end: self.bottom.floor() as i32,
             x,
             y: self.top.ceil() as i32,
+            column_major: false,
         }
     }
 
@@ -235,9 +394,11 @@ impl Rect {
         PixelIter {
             x_start: x,
             x_end: x,
+            y_start: self.top.ceil() as i32,
             y_end: self.bottom.floor() as i32,
             x,
             y: self.top.ceil() as i32,
+            column_major: false,
         }
     }
 
@@ -268,6 +429,24 @@ impl Rect {
         }
     }
 
+    /// Iterate over all integer pixel locations, row by row (`"row"`) or column by column (`"column"`).
+    #[pyo3(signature = (order="row"))]
+    fn pixels(&self, order: &str) -> PyResult<PixelIter> {
+        let column_major = match order {
+            "row" => false,
+            "column" => true,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "order must be \"row\" or \"column\", got {order:?}"
+                )))
+            }
+        };
+        Ok(PixelIter {
+            column_major,
+            ..self.__iter__()
+        })
+    }
+
     /// Iterate over all integer pixel locations contained within this rectangle.
     fn __iter__(&self) -> PixelIter {
         let x_start = self.left.ceil() as i32;
@@ -277,9 +456,11 @@ impl Rect {
         PixelIter {
             x_start,
             x_end,
+            y_start,
             y_end,
             x: x_start,
             y: y_start,
+            column_major: false,
         }
     }
 
@@ -311,6 +492,107 @@ impl Rect {
             None
         }
     }
+
+    /// The rectangle of one cell in a `cols` x `rows` grid laid over this rectangle,
+    /// with `gap` between adjacent cells.
+    #[pyo3(signature = (col, row, cols, rows, gap=0.0))]
+    fn grid_cell(
+        &self,
+        col: usize,
+        row: usize,
+        cols: usize,
+        rows: usize,
+        gap: f32,
+    ) -> PyResult<Rect> {
+        if cols == 0 || rows == 0 {
+            return Err(PyValueError::new_err(
+                "grid must have at least one column and one row",
+            ));
+        }
+        if col >= cols || row >= rows {
+            return Err(PyIndexError::new_err(format!(
+                "cell ({col}, {row}) is outside a {cols}x{rows} grid"
+            )));
+        }
+        let cell_width = (self.width() - gap * (cols - 1) as f32) / cols as f32;
+        let cell_height = (self.height() - gap * (rows - 1) as f32) / rows as f32;
+        let left = self.left + col as f32 * (cell_width + gap);
+        let top = self.top + row as f32 * (cell_height + gap);
+        Ok(Rect {
+            left,
+            right: left + cell_width,
+            top,
+            bottom: top + cell_height,
+        })
+    }
+
+    /// Expand all four edges outward by `dx` horizontally and `dy` vertically.
+    fn inflate(&self, dx: f32, dy: f32) -> Rect {
+        Rect {
+            left: self.left - dx,
+            right: self.right + dx,
+            top: self.top - dy,
+            bottom: self.bottom + dy,
+        }
+    }
+
+    /// Move all four edges inward by `dx` horizontally and `dy` vertically.
+    fn deflate(&self, dx: f32, dy: f32) -> Rect {
+        self.inflate(-dx, -dy)
+    }
+
+    /// Shift the rectangle by `dx` horizontally and `dy` vertically.
+    fn translate(&self, dx: f32, dy: f32) -> Rect {
+        Rect {
+            left: self.left + dx,
+            right: self.right + dx,
+            top: self.top + dy,
+            bottom: self.bottom + dy,
+        }
+    }
+
+    /// Multiply all four edges by `factor`, e.g. to convert to device pixels.
+    fn scale(&self, factor: f32) -> Rect {
+        self.map(|v| v * factor, |v| v * factor)
+    }
+
+    /// Round all four edges down.
+    fn floor(&self) -> Rect {
+        self.map(f32::floor, f32::floor)
+    }
+
+    /// Round all four edges up.
+    fn ceil(&self) -> Rect {
+        self.map(f32::ceil, f32::ceil)
+    }
+
+    /// Round all four edges to the nearest integer, with halves rounded away from zero.
+    fn round(&self) -> Rect {
+        self.map(f32::round, f32::round)
+    }
+
+    /// Round the left and top edges down and the right and bottom edges up,
+    /// so the result always covers this rectangle.
+    fn round_outward(&self) -> Rect {
+        self.map(f32::floor, f32::ceil)
+    }
+
+    /// Snap to whole pixels; the same as `round_outward`.
+    fn round_to_pixels(&self) -> Rect {
+        self.round_outward()
+    }
+}
+
+impl Rect {
+    /// Apply `start` to the left and top edges and `end` to the right and bottom edges.
+    fn map(&self, start: impl Fn(f32) -> f32, end: impl Fn(f32) -> f32) -> Rect {
+        Rect {
+            left: start(self.left),
+            right: end(self.right),
+            top: start(self.top),
+            bottom: end(self.bottom),
+        }
+    }
 }
 
 impl From<taffy::Rect<f32>> for Rect {
@@ -368,6 +650,10 @@ impl Point {
         std::hash::Hasher::finish(&hasher)
     }
 
+    fn __iter__(&self) -> PairIter {
+        PairIter::new(self.x, self.y)
+    }
+
     fn __add__(&self, other: &Point) -> Point {
         Point {
             x: self.x + other.x,
@@ -406,6 +692,31 @@ impl Point {
             y: -self.y,
         }
     }
+
+    /// Check whether both coordinates are finite (not NaN or infinite).
+    fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// The Euclidean length of this point as a vector from the origin.
+    fn length(&self) -> f32 {
+        self.x.hypot(self.y)
+    }
+
+    /// The Euclidean distance to another point.
+    fn distance(&self, other: &Point) -> f32 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    /// The dot product with another point, treating both as vectors.
+    fn dot(&self, other: &Point) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The Manhattan (taxicab) distance to another point: `|dx| + |dy|`.
+    fn manhattan_distance(&self, other: &Point) -> f32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
 }
 
 impl From<taffy::Point<f32>> for Point {
@@ -453,6 +764,11 @@ impl Line {
         std::hash::Hasher::finish(&hasher)
     }
 
+    /// False when the length is zero or negative.
+    fn __bool__(&self) -> bool {
+        self.length() > 0.0
+    }
+
     /// The length of the line segment (end - start).
     #[getter]
     fn length(&self) -> f32 {
@@ -464,6 +780,24 @@ impl Line {
         value >= self.start && value <= self.end
     }
 
+    /// The overlapping segment, or `None` if the segments are disjoint.
+    /// Segments that only share an endpoint intersect in a zero-length segment.
+    fn intersection(&self, other: &Line) -> Option<Line> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        (start <= end).then_some(Line { start, end })
+    }
+
+    /// Whether the segments intersect, including when they only share an endpoint.
+    fn overlaps(&self, other: &Line) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Clamp `value` into `[start, end]`.
+    fn clamp(&self, value: f32) -> f32 {
+        value.max(self.start).min(self.end)
+    }
+
     /// Iterate over integer values contained within this line segment.
     fn __iter__(&self) -> IntIter {
         IntIter {
@@ -482,6 +816,35 @@ impl Line {
     }
 }
 
+/// Iterator over the two components of a 2D value, for tuple unpacking.
+#[pyclass(module = "waxy")]
+struct PairIter {
+    values: [f32; 2],
+    index: usize,
+}
+
+impl PairIter {
+    fn new(first: f32, second: f32) -> Self {
+        Self {
+            values: [first, second],
+            index: 0,
+        }
+    }
+}
+
+#[pymethods]
+impl PairIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<f32> {
+        let val = self.values.get(self.index).copied();
+        self.index += 1;
+        val
+    }
+}
+
 /// Iterator over a range of integer values.
 #[pyclass(module = "waxy")]
 struct IntIter {
@@ -510,9 +873,12 @@ impl IntIter {
 struct PixelIter {
     x_start: i32,
     x_end: i32,
+    y_start: i32,
     y_end: i32,
     x: i32,
     y: i32,
+    /// Yield every y for an x before advancing x, instead of every x for a y.
+    column_major: bool,
 }
 
 #[pymethods]
@@ -529,10 +895,18 @@ impl PixelIter {
             x: self.x as f32,
             y: self.y as f32,
         };
-        self.x += 1;
-        if self.x > self.x_end {
-            self.x = self.x_start;
+        if self.column_major {
             self.y += 1;
+            if self.y > self.y_end {
+                self.y = self.y_start;
+                self.x += 1;
+            }
+        } else {
+            self.x += 1;
+            if self.x > self.x_end {
+                self.x = self.x_start;
+                self.y += 1;
+            }
         }
         Some(point)
     }
@@ -560,9 +934,11 @@ impl RowIter {
         let iter = PixelIter {
             x_start: self.x_start,
             x_end: self.x_end,
+            y_start: self.y,
             y_end: self.y,
             x: self.x_start,
             y: self.y,
+            column_major: false,
         };
         self.y += 1;
         Some(iter)
@@ -591,15 +967,69 @@ impl ColumnIter {
         let iter = PixelIter {
             x_start: self.x,
             x_end: self.x,
+            y_start: self.y_start,
             y_end: self.y_end,
             x: self.x,
             y: self.y_start,
+            column_major: false,
         };
         self.x += 1;
         Some(iter)
     }
 }
 
+/// A measure function result carrying a size plus an optional first baseline.
+#[pyclass(frozen, from_py_object, module = "waxy")]
+#[derive(Clone, Debug)]
+pub struct MeasureResult {
+    #[pyo3(get)]
+    pub size: Size,
+    #[pyo3(get)]
+    pub first_baseline: Option<f32>,
+}
+
+#[pymethods]
+impl MeasureResult {
+    #[new]
+    #[pyo3(signature = (size, first_baseline=None))]
+    fn new(size: Size, first_baseline: Option<f32>) -> Self {
+        Self {
+            size,
+            first_baseline,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MeasureResult(size={}, first_baseline={})",
+            self.size.__repr__(),
+            match self.first_baseline {
+                Some(v) => v.to_string(),
+                None => "None".to_string(),
+            }
+        )
+    }
+
+    fn __eq__(&self, other: &MeasureResult) -> bool {
+        self.size.__eq__(&other.size) && self.first_baseline == other.first_baseline
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        hash_f32(self.size.width, &mut hasher);
+        hash_f32(self.size.height, &mut hasher);
+        match self.first_baseline {
+            Some(v) => {
+                1u8.hash(&mut hasher);
+                hash_f32(v, &mut hasher);
+            }
+            None => 0u8.hash(&mut hasher),
+        }
+        hasher.finish()
+    }
+}
+
 /// Known dimensions passed to measure functions (independently-optional width/height).
 #[pyclass(frozen, from_py_object, module = "waxy")]
 #[derive(Clone, Debug)]
@@ -671,6 +1101,19 @@ impl KnownSize {
     fn height(&self) -> Option<f32> {
         self.height
     }
+
+    /// Whether both dimensions are known.
+    fn is_fully_known(&self) -> bool {
+        self.width.is_some() && self.height.is_some()
+    }
+
+    /// A `Size` with each unknown dimension taken from `default`.
+    fn unwrap_or(&self, default: &Size) -> Size {
+        Size {
+            width: self.width.unwrap_or(default.width),
+            height: self.height.unwrap_or(default.height),
+        }
+    }
 }
 
 impl From<taffy::Size<Option<f32>>> for KnownSize {
@@ -775,6 +1218,85 @@ impl AvailableSize {
     fn height(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         available_space_to_py(py, self.height)
     }
+
+    /// Whether the width is `Definite`.
+    fn width_is_definite(&self) -> bool {
+        self.width.is_definite()
+    }
+
+    /// Whether the height is `Definite`.
+    fn height_is_definite(&self) -> bool {
+        self.height.is_definite()
+    }
+
+    /// Whether the width is `MinContent`.
+    fn width_is_min_content(&self) -> bool {
+        self.width == taffy::AvailableSpace::MinContent
+    }
+
+    /// Whether the height is `MinContent`.
+    fn height_is_min_content(&self) -> bool {
+        self.height == taffy::AvailableSpace::MinContent
+    }
+
+    /// Whether the width is `MaxContent`.
+    fn width_is_max_content(&self) -> bool {
+        self.width == taffy::AvailableSpace::MaxContent
+    }
+
+    /// Whether the height is `MaxContent`.
+    fn height_is_max_content(&self) -> bool {
+        self.height == taffy::AvailableSpace::MaxContent
+    }
+
+    /// Whether each axis is `Definite`, as `(width, height)`.
+    fn is_definite(&self) -> (bool, bool) {
+        (self.width.is_definite(), self.height.is_definite())
+    }
+
+    /// A `Size` of the definite values, with `MinContent` and `MaxContent` axes
+    /// replaced by the given defaults.
+    fn definite_or(&self, default_width: f32, default_height: f32) -> Size {
+        Size {
+            width: self.width.unwrap_or(default_width),
+            height: self.height.unwrap_or(default_height),
+        }
+    }
+
+    /// Apply `f` to each definite value, leaving `MinContent` and `MaxContent` as they are.
+    fn map_definite(&self, f: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let map = |space: taffy::AvailableSpace| -> PyResult<taffy::AvailableSpace> {
+            Ok(match space {
+                taffy::AvailableSpace::Definite(v) => {
+                    taffy::AvailableSpace::Definite(f.call1((v,))?.extract()?)
+                }
+                other => other,
+            })
+        };
+        Ok(Self {
+            width: map(self.width)?,
+            height: map(self.height)?,
+        })
+    }
+
+    /// The sizing mode of an axis (0 for width, 1 for height):
+    /// `"definite"`, `"min-content"`, or `"max-content"`.
+    fn axis_mode(&self, axis: usize) -> PyResult<&'static str> {
+        let space = match axis {
+            0 => self.width,
+            1 => self.height,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "axis must be 0 (width) or 1 (height), got {axis}"
+                )))
+            }
+        };
+        Ok(match space {
+            taffy::AvailableSpace::Definite(_) => "definite",
+            taffy::AvailableSpace::MinContent => "min-content",
+            taffy::AvailableSpace::MaxContent => "max-content",
+        })
+    }
 }
 
 impl From<taffy::Size<taffy::AvailableSpace>> for AvailableSize {
@@ -795,6 +1317,31 @@ impl From<&AvailableSize> for taffy::Size<taffy::AvailableSpace> {
     }
 }
 
+/// Accepts `AvailableSize`, a `(width, height)` pair of floats, or a single float
+/// for both axes; plain floats are `Definite`.
+#[derive(FromPyObject)]
+pub enum AvailableSizeInput {
+    AvailableSize(AvailableSize),
+    Pair(f32, f32),
+    Both(f32),
+}
+
+impl From<&AvailableSizeInput> for taffy::Size<taffy::AvailableSpace> {
+    fn from(a: &AvailableSizeInput) -> Self {
+        match a {
+            AvailableSizeInput::AvailableSize(a) => a.into(),
+            AvailableSizeInput::Pair(width, height) => taffy::Size {
+                width: taffy::AvailableSpace::Definite(*width),
+                height: taffy::AvailableSpace::Definite(*height),
+            },
+            AvailableSizeInput::Both(v) => taffy::Size {
+                width: taffy::AvailableSpace::Definite(*v),
+                height: taffy::AvailableSpace::Definite(*v),
+            },
+        }
+    }
+}
+
 #[pyclass(module = "waxy")]
 struct AvailableSizeIter {
     width: taffy::AvailableSpace,
@@ -825,6 +1372,7 @@ pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Point>()?;
     m.add_class::<Line>()?;
     m.add_class::<KnownSize>()?;
+    m.add_class::<MeasureResult>()?;
     m.add_class::<AvailableSize>()?;
     Ok(())
 }
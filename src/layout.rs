@@ -1,4 +1,5 @@
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 use crate::geometry::{hash_f32, Point, Rect, Size};
 
@@ -30,57 +31,61 @@ impl Layout {
         format!("Layout(location={:?}, size={:?})", self.location, self.size)
     }
 
+    /// Exact comparison: floats are compared bit for bit, so `-0.0 != 0.0` and a NaN
+    /// equals an identical NaN. This keeps golden-file comparisons deterministic.
     fn __eq__(&self, other: &Layout) -> bool {
         self.order == other.order
-            && self.location.x == other.location.x
-            && self.location.y == other.location.y
-            && self.size.width == other.size.width
-            && self.size.height == other.size.height
-            && self.content_size.width == other.content_size.width
-            && self.content_size.height == other.content_size.height
-            && self.scrollbar_size.width == other.scrollbar_size.width
-            && self.scrollbar_size.height == other.scrollbar_size.height
-            && self.border.left == other.border.left
-            && self.border.right == other.border.right
-            && self.border.top == other.border.top
-            && self.border.bottom == other.border.bottom
-            && self.padding.left == other.padding.left
-            && self.padding.right == other.padding.right
-            && self.padding.top == other.padding.top
-            && self.padding.bottom == other.padding.bottom
-            && self.margin.left == other.margin.left
-            && self.margin.right == other.margin.right
-            && self.margin.top == other.margin.top
-            && self.margin.bottom == other.margin.bottom
+            && self
+                .floats()
+                .iter()
+                .zip(other.floats())
+                .all(|(a, b)| a.to_bits() == b.to_bits())
     }
 
     fn __hash__(&self) -> u64 {
         use std::hash::{Hash, Hasher};
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
         self.order.hash(&mut hasher);
-        hash_f32(self.location.x, &mut hasher);
-        hash_f32(self.location.y, &mut hasher);
-        hash_f32(self.size.width, &mut hasher);
-        hash_f32(self.size.height, &mut hasher);
-        hash_f32(self.content_size.width, &mut hasher);
-        hash_f32(self.content_size.height, &mut hasher);
-        hash_f32(self.scrollbar_size.width, &mut hasher);
-        hash_f32(self.scrollbar_size.height, &mut hasher);
-        hash_f32(self.border.left, &mut hasher);
-        hash_f32(self.border.right, &mut hasher);
-        hash_f32(self.border.top, &mut hasher);
-        hash_f32(self.border.bottom, &mut hasher);
-        hash_f32(self.padding.left, &mut hasher);
-        hash_f32(self.padding.right, &mut hasher);
-        hash_f32(self.padding.top, &mut hasher);
-        hash_f32(self.padding.bottom, &mut hasher);
-        hash_f32(self.margin.left, &mut hasher);
-        hash_f32(self.margin.right, &mut hasher);
-        hash_f32(self.margin.top, &mut hasher);
-        hash_f32(self.margin.bottom, &mut hasher);
+        for value in self.floats() {
+            hash_f32(value, &mut hasher);
+        }
         hasher.finish()
     }
 
+    /// Serialize every field to nested dicts of plain numbers.
+    pub(crate) fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        fn size_dict<'py>(py: Python<'py>, size: &Size) -> PyResult<Bound<'py, PyDict>> {
+            let dict = PyDict::new(py);
+            dict.set_item("width", size.width)?;
+            dict.set_item("height", size.height)?;
+            Ok(dict)
+        }
+
+        fn rect_dict<'py>(py: Python<'py>, rect: &Rect) -> PyResult<Bound<'py, PyDict>> {
+            let dict = PyDict::new(py);
+            dict.set_item("left", rect.left)?;
+            dict.set_item("right", rect.right)?;
+            dict.set_item("top", rect.top)?;
+            dict.set_item("bottom", rect.bottom)?;
+            Ok(dict)
+        }
+
+        let location = PyDict::new(py);
+        location.set_item("x", self.location.x)?;
+        location.set_item("y", self.location.y)?;
+
+        let dict = PyDict::new(py);
+        dict.set_item("order", self.order)?;
+        dict.set_item("location", location)?;
+        dict.set_item("size", size_dict(py, &self.size)?)?;
+        dict.set_item("content_size", size_dict(py, &self.content_size)?)?;
+        dict.set_item("scrollbar_size", size_dict(py, &self.scrollbar_size)?)?;
+        dict.set_item("border", rect_dict(py, &self.border)?)?;
+        dict.set_item("padding", rect_dict(py, &self.padding)?)?;
+        dict.set_item("margin", rect_dict(py, &self.margin)?)?;
+        Ok(dict)
+    }
+
     /// Width of the content box (size minus padding and border).
     fn content_box_width(&self) -> f32 {
         self.size.width
@@ -98,6 +103,89 @@ impl Layout {
             - self.border.top
             - self.border.bottom
     }
+
+    /// Whether the content overflows the padding box, as `(horizontal, vertical)`.
+    fn overflows(&self) -> (bool, bool) {
+        let scrollable = self.scrollable_size();
+        (scrollable.width > 0.0, scrollable.height > 0.0)
+    }
+
+    /// How far the content overflows the padding box on each axis, or 0 if it fits.
+    ///
+    /// taffy measures `content_size` from the border box's top-left corner, including the
+    /// leading border and both paddings, so it is compared against `size` minus the
+    /// trailing border rather than against the content box.
+    fn scrollable_size(&self) -> Size {
+        Size {
+            width: (self.content_size.width - (self.size.width - self.border.right)).max(0.0),
+            height: (self.content_size.height - (self.size.height - self.border.bottom)).max(0.0),
+        }
+    }
+
+    /// The border box as a Rect positioned at `location`.
+    pub(crate) fn border_box(&self) -> Rect {
+        Rect {
+            left: self.location.x,
+            right: self.location.x + self.size.width,
+            top: self.location.y,
+            bottom: self.location.y + self.size.height,
+        }
+    }
+
+    /// The padding box: the border box inset by the border widths.
+    pub(crate) fn padding_box(&self) -> Rect {
+        inset(&self.border_box(), &self.border)
+    }
+
+    /// The content box: the padding box inset by the padding widths.
+    fn content_box(&self) -> Rect {
+        inset(&self.padding_box(), &self.padding)
+    }
+
+    /// Whether `point` lies within the border box, including its top and left edges
+    /// but not its bottom and right edges.
+    fn contains(&self, point: &Point) -> bool {
+        let b = self.border_box();
+        point.x >= b.left && point.x < b.right && point.y >= b.top && point.y < b.bottom
+    }
+}
+
+impl Layout {
+    /// Every float field, in declaration order.
+    fn floats(&self) -> [f32; 20] {
+        [
+            self.location.x,
+            self.location.y,
+            self.size.width,
+            self.size.height,
+            self.content_size.width,
+            self.content_size.height,
+            self.scrollbar_size.width,
+            self.scrollbar_size.height,
+            self.border.left,
+            self.border.right,
+            self.border.top,
+            self.border.bottom,
+            self.padding.left,
+            self.padding.right,
+            self.padding.top,
+            self.padding.bottom,
+            self.margin.left,
+            self.margin.right,
+            self.margin.top,
+            self.margin.bottom,
+        ]
+    }
+}
+
+/// Shrink `rect` by the edge widths in `by`.
+fn inset(rect: &Rect, by: &Rect) -> Rect {
+    Rect {
+        left: rect.left + by.left,
+        right: rect.right - by.right,
+        top: rect.top + by.top,
+        bottom: rect.bottom - by.bottom,
+    }
 }
 
 impl From<&taffy::Layout> for Layout {
@@ -5,17 +5,19 @@ use pyo3::prelude::*;
 #[derive(Clone, Debug)]
 pub struct NodeId {
     pub(crate) inner: taffy::NodeId,
+    /// Identifies the `TaffyTree` that created this node.
+    pub(crate) tree: u64,
 }
 
 #[pymethods]
 impl NodeId {
-    fn __repr__(&self) -> String {
+    pub(crate) fn __repr__(&self) -> String {
         let val: u64 = self.inner.into();
         format!("NodeId({val})")
     }
 
     fn __eq__(&self, other: &NodeId) -> bool {
-        self.inner == other.inner
+        self.inner == other.inner && self.tree == other.tree
     }
 
     fn __hash__(&self) -> u64 {
@@ -24,12 +26,6 @@ impl NodeId {
     }
 }
 
-impl From<taffy::NodeId> for NodeId {
-    fn from(id: taffy::NodeId) -> Self {
-        Self { inner: id }
-    }
-}
-
 impl From<&NodeId> for taffy::NodeId {
     fn from(id: &NodeId) -> Self {
         id.inner
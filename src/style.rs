@@ -1,16 +1,23 @@
+use pyo3::conversion::FromPyObjectOwned;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyType};
+use pyo3::{PyClass, PyClassInitializer};
+use taffy::style::{CompactLength, Dimension, LengthPercentage, LengthPercentageAuto};
+use taffy::ResolveOrZero;
 
 use crate::enums::{
-    AlignContent, AlignItems, BoxSizing, Display, FlexDirection, FlexWrap, GridAutoFlow, Overflow,
-    Position, TextAlign,
+    AlignContent, AlignItems, BoxSizing, Display, FlexDirection, FlexWrap, GridAutoFlow, NamedEnum,
+    Overflow, Position, TextAlign,
 };
-use crate::geometry::hash_f32;
+use crate::geometry::{hash_f32, Rect};
 use crate::values::{
-    dimension_to_py, grid_track_to_py, hash_taffy_compact_length, hash_taffy_grid_placement,
+    compact_length_to_css, dimension_to_py, grid_placement_to_css, grid_template_to_css,
+    grid_track_to_css, grid_track_to_py, hash_taffy_compact_length, hash_taffy_grid_placement,
     hash_taffy_template_area, hash_taffy_template_component, hash_taffy_track_sizing_function,
-    length_percentage_auto_to_py, length_percentage_to_py, DimensionInput, GridPlacement,
-    GridTrackInput, LengthPercentageAutoInput, LengthPercentageInput,
+    length_percentage_auto_to_py, length_percentage_to_py, tracks, Auto, DimensionInput,
+    GridPlacement, GridTemplateInput, GridTrackInput, Length, LengthPercentageAutoInput,
+    LengthPercentageInput, Percent, Repeat,
 };
 
 // Bit positions for tracking which fields are explicitly set.
@@ -65,6 +72,64 @@ const F_GRID_AUTO_FLOW: u64 = 1 << 47;
 const F_GRID_ROW: u64 = 1 << 48;
 const F_GRID_COLUMN: u64 = 1 << 49;
 
+/// Mask with every field's bit set.
+const F_ALL: u64 = (1 << 50) - 1;
+
+/// Every Style field, in declaration order, paired with its set-field bit.
+/// Names match the keyword arguments accepted by `Style(...)`.
+const FIELDS: [(&str, u64); 50] = [
+    ("display", F_DISPLAY),
+    ("box_sizing", F_BOX_SIZING),
+    ("overflow_x", F_OVERFLOW_X),
+    ("overflow_y", F_OVERFLOW_Y),
+    ("scrollbar_width", F_SCROLLBAR_WIDTH),
+    ("position", F_POSITION),
+    ("inset_left", F_INSET_LEFT),
+    ("inset_right", F_INSET_RIGHT),
+    ("inset_top", F_INSET_TOP),
+    ("inset_bottom", F_INSET_BOTTOM),
+    ("size_width", F_SIZE_WIDTH),
+    ("size_height", F_SIZE_HEIGHT),
+    ("min_size_width", F_MIN_SIZE_WIDTH),
+    ("min_size_height", F_MIN_SIZE_HEIGHT),
+    ("max_size_width", F_MAX_SIZE_WIDTH),
+    ("max_size_height", F_MAX_SIZE_HEIGHT),
+    ("aspect_ratio", F_ASPECT_RATIO),
+    ("margin_left", F_MARGIN_LEFT),
+    ("margin_right", F_MARGIN_RIGHT),
+    ("margin_top", F_MARGIN_TOP),
+    ("margin_bottom", F_MARGIN_BOTTOM),
+    ("padding_left", F_PADDING_LEFT),
+    ("padding_right", F_PADDING_RIGHT),
+    ("padding_top", F_PADDING_TOP),
+    ("padding_bottom", F_PADDING_BOTTOM),
+    ("border_left", F_BORDER_LEFT),
+    ("border_right", F_BORDER_RIGHT),
+    ("border_top", F_BORDER_TOP),
+    ("border_bottom", F_BORDER_BOTTOM),
+    ("align_items", F_ALIGN_ITEMS),
+    ("align_self", F_ALIGN_SELF),
+    ("justify_items", F_JUSTIFY_ITEMS),
+    ("justify_self", F_JUSTIFY_SELF),
+    ("align_content", F_ALIGN_CONTENT),
+    ("justify_content", F_JUSTIFY_CONTENT),
+    ("gap_width", F_GAP_WIDTH),
+    ("gap_height", F_GAP_HEIGHT),
+    ("text_align", F_TEXT_ALIGN),
+    ("flex_direction", F_FLEX_DIRECTION),
+    ("flex_wrap", F_FLEX_WRAP),
+    ("flex_basis", F_FLEX_BASIS),
+    ("flex_grow", F_FLEX_GROW),
+    ("flex_shrink", F_FLEX_SHRINK),
+    ("grid_template_rows", F_GRID_TEMPLATE_ROWS),
+    ("grid_template_columns", F_GRID_TEMPLATE_COLUMNS),
+    ("grid_auto_rows", F_GRID_AUTO_ROWS),
+    ("grid_auto_columns", F_GRID_AUTO_COLUMNS),
+    ("grid_auto_flow", F_GRID_AUTO_FLOW),
+    ("grid_row", F_GRID_ROW),
+    ("grid_column", F_GRID_COLUMN),
+];
+
 /// Style properties for a layout node.
 #[pyclass(unsendable, frozen, from_py_object, module = "waxy")]
 #[derive(Clone, Debug)]
@@ -78,6 +143,700 @@ impl Style {
     pub(crate) fn to_taffy(&self) -> taffy::Style {
         self.inner.clone()
     }
+
+    /// The bitmask of explicitly set fields, for storing alongside `to_taffy()`.
+    pub(crate) fn set_fields(&self) -> u64 {
+        self.set_fields
+    }
+
+    /// Rebuild a Style from a taffy style and the set-field mask it was created with.
+    pub(crate) fn from_taffy(inner: &taffy::Style, set_fields: u64) -> Self {
+        Self {
+            inner: inner.clone(),
+            set_fields,
+        }
+    }
+
+    /// Build a Style from preset fields, then apply `overrides` through the constructor
+    /// so that both end up marked as set.
+    fn preset(
+        py: Python<'_>,
+        display: Display,
+        flex_direction: Option<FlexDirection>,
+        overrides: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("display", Bound::new(py, display)?)?;
+        if let Some(flex_direction) = flex_direction {
+            kwargs.set_item("flex_direction", Bound::new(py, flex_direction)?)?;
+        }
+        if let Some(overrides) = overrides {
+            kwargs.update(overrides.as_mapping())?;
+        }
+        Self::new(py, Some(&kwargs))
+    }
+
+    /// A copy with one constructor argument (a field or shorthand) applied on top.
+    fn with_field(&self, name: &str, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        let kwargs = PyDict::new(value.py());
+        kwargs.set_item(name, value)?;
+        Ok(self.__or__(&Self::new(value.py(), Some(&kwargs))?))
+    }
+
+    /// Feed every field and the set-field mask into `h`.
+    fn hash_into<H: std::hash::Hasher>(&self, h: &mut H) {
+        use std::hash::Hash;
+        use std::mem::discriminant;
+        let s = &self.inner;
+
+        self.set_fields.hash(h);
+
+        // Enum fields — use discriminant since taffy enums don't derive Hash.
+        discriminant(&s.display).hash(h);
+        discriminant(&s.box_sizing).hash(h);
+        discriminant(&s.overflow.x).hash(h);
+        discriminant(&s.overflow.y).hash(h);
+        discriminant(&s.position).hash(h);
+        discriminant(&s.text_align).hash(h);
+        discriminant(&s.flex_direction).hash(h);
+        discriminant(&s.flex_wrap).hash(h);
+        discriminant(&s.grid_auto_flow).hash(h);
+
+        // Option<enum> fields.
+        s.align_items.map(|v| discriminant(&v)).hash(h);
+        s.align_self.map(|v| discriminant(&v)).hash(h);
+        s.justify_items.map(|v| discriminant(&v)).hash(h);
+        s.justify_self.map(|v| discriminant(&v)).hash(h);
+        s.align_content.map(|v| discriminant(&v)).hash(h);
+        s.justify_content.map(|v| discriminant(&v)).hash(h);
+
+        // Plain f32 fields — normalize -0.0 (consistent with f32's IEEE
+        // float equality used by taffy's derived PartialEq for these fields).
+        hash_f32(s.scrollbar_width, h);
+        hash_f32(s.flex_grow, h);
+        hash_f32(s.flex_shrink, h);
+        match s.aspect_ratio {
+            Some(v) => {
+                1u8.hash(h);
+                hash_f32(v, h);
+            }
+            None => 0u8.hash(h),
+        }
+
+        // CompactLength fields — bit-exact, matching CompactLength's PartialEq.
+        // Each field type (Dimension, LengthPercentage, etc.) wraps CompactLength;
+        // into_raw() extracts it.
+        hash_taffy_compact_length(s.inset.left.into_raw(), h);
+        hash_taffy_compact_length(s.inset.right.into_raw(), h);
+        hash_taffy_compact_length(s.inset.top.into_raw(), h);
+        hash_taffy_compact_length(s.inset.bottom.into_raw(), h);
+        hash_taffy_compact_length(s.size.width.into_raw(), h);
+        hash_taffy_compact_length(s.size.height.into_raw(), h);
+        hash_taffy_compact_length(s.min_size.width.into_raw(), h);
+        hash_taffy_compact_length(s.min_size.height.into_raw(), h);
+        hash_taffy_compact_length(s.max_size.width.into_raw(), h);
+        hash_taffy_compact_length(s.max_size.height.into_raw(), h);
+        hash_taffy_compact_length(s.margin.left.into_raw(), h);
+        hash_taffy_compact_length(s.margin.right.into_raw(), h);
+        hash_taffy_compact_length(s.margin.top.into_raw(), h);
+        hash_taffy_compact_length(s.margin.bottom.into_raw(), h);
+        hash_taffy_compact_length(s.padding.left.into_raw(), h);
+        hash_taffy_compact_length(s.padding.right.into_raw(), h);
+        hash_taffy_compact_length(s.padding.top.into_raw(), h);
+        hash_taffy_compact_length(s.padding.bottom.into_raw(), h);
+        hash_taffy_compact_length(s.border.left.into_raw(), h);
+        hash_taffy_compact_length(s.border.right.into_raw(), h);
+        hash_taffy_compact_length(s.border.top.into_raw(), h);
+        hash_taffy_compact_length(s.border.bottom.into_raw(), h);
+        hash_taffy_compact_length(s.gap.width.into_raw(), h);
+        hash_taffy_compact_length(s.gap.height.into_raw(), h);
+        hash_taffy_compact_length(s.flex_basis.into_raw(), h);
+
+        // Grid track Vecs.
+        s.grid_auto_rows.len().hash(h);
+        for tsf in &s.grid_auto_rows {
+            hash_taffy_track_sizing_function(*tsf, h);
+        }
+        s.grid_auto_columns.len().hash(h);
+        for tsf in &s.grid_auto_columns {
+            hash_taffy_track_sizing_function(*tsf, h);
+        }
+        s.grid_template_rows.len().hash(h);
+        for comp in &s.grid_template_rows {
+            hash_taffy_template_component(comp, h);
+        }
+        s.grid_template_columns.len().hash(h);
+        for comp in &s.grid_template_columns {
+            hash_taffy_template_component(comp, h);
+        }
+        s.grid_template_areas.len().hash(h);
+        for area in &s.grid_template_areas {
+            hash_taffy_template_area(area, h);
+        }
+
+        // Grid placement fields.
+        hash_taffy_grid_placement(&s.grid_row.start, h);
+        hash_taffy_grid_placement(&s.grid_row.end, h);
+        hash_taffy_grid_placement(&s.grid_column.start, h);
+        hash_taffy_grid_placement(&s.grid_column.end, h);
+    }
+
+    /// `(name, value)` pairs for every explicitly set field, in declaration order.
+    fn set_field_items(
+        &self,
+        py: Python<'_>,
+        enum_names: bool,
+    ) -> PyResult<Vec<(&'static str, Py<PyAny>)>> {
+        FIELDS
+            .iter()
+            .filter(|(_, flag)| self.set_fields & flag != 0)
+            .map(|(name, _)| Ok((*name, self.field_to_py(py, name, enum_names)?)))
+            .collect()
+    }
+
+    /// The Python value of a field, by keyword name. With `enum_names`, enum values
+    /// are returned as their variant names rather than enum members.
+    fn field_to_py(&self, py: Python<'_>, name: &str, enum_names: bool) -> PyResult<Py<PyAny>> {
+        fn enum_to_py<E: NamedEnum + PyClass + Into<PyClassInitializer<E>>>(
+            py: Python<'_>,
+            value: E,
+            enum_names: bool,
+        ) -> PyResult<Py<PyAny>> {
+            if enum_names {
+                Ok(PyString::new(py, value.name()).into_any().unbind())
+            } else {
+                Ok(Bound::new(py, value)?.into_any().unbind())
+            }
+        }
+
+        fn opt_enum_to_py<E: NamedEnum + PyClass + Into<PyClassInitializer<E>>>(
+            py: Python<'_>,
+            value: Option<E>,
+            enum_names: bool,
+        ) -> PyResult<Py<PyAny>> {
+            match value {
+                Some(v) => enum_to_py(py, v, enum_names),
+                None => Ok(py.None()),
+            }
+        }
+
+        fn list_to_py(py: Python<'_>, items: Vec<Py<PyAny>>) -> PyResult<Py<PyAny>> {
+            Ok(PyList::new(py, items)?.into_any().unbind())
+        }
+
+        match name {
+            "display" => enum_to_py(py, self.get_display(), enum_names),
+            "box_sizing" => enum_to_py(py, self.get_box_sizing(), enum_names),
+            "overflow_x" => enum_to_py(py, self.get_overflow_x(), enum_names),
+            "overflow_y" => enum_to_py(py, self.get_overflow_y(), enum_names),
+            "scrollbar_width" => Ok(self
+                .get_scrollbar_width()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
+            "position" => enum_to_py(py, self.get_position(), enum_names),
+            "inset_left" => self.get_inset_left(py),
+            "inset_right" => self.get_inset_right(py),
+            "inset_top" => self.get_inset_top(py),
+            "inset_bottom" => self.get_inset_bottom(py),
+            "size_width" => self.get_size_width(py),
+            "size_height" => self.get_size_height(py),
+            "min_size_width" => self.get_min_size_width(py),
+            "min_size_height" => self.get_min_size_height(py),
+            "max_size_width" => self.get_max_size_width(py),
+            "max_size_height" => self.get_max_size_height(py),
+            "aspect_ratio" => Ok(self
+                .get_aspect_ratio()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
+            "margin_left" => self.get_margin_left(py),
+            "margin_right" => self.get_margin_right(py),
+            "margin_top" => self.get_margin_top(py),
+            "margin_bottom" => self.get_margin_bottom(py),
+            "padding_left" => self.get_padding_left(py),
+            "padding_right" => self.get_padding_right(py),
+            "padding_top" => self.get_padding_top(py),
+            "padding_bottom" => self.get_padding_bottom(py),
+            "border_left" => self.get_border_left(py),
+            "border_right" => self.get_border_right(py),
+            "border_top" => self.get_border_top(py),
+            "border_bottom" => self.get_border_bottom(py),
+            "align_items" => opt_enum_to_py(py, self.get_align_items(), enum_names),
+            "align_self" => opt_enum_to_py(py, self.get_align_self(), enum_names),
+            "justify_items" => opt_enum_to_py(py, self.get_justify_items(), enum_names),
+            "justify_self" => opt_enum_to_py(py, self.get_justify_self(), enum_names),
+            "align_content" => opt_enum_to_py(py, self.get_align_content(), enum_names),
+            "justify_content" => opt_enum_to_py(py, self.get_justify_content(), enum_names),
+            "gap_width" => self.get_gap_width(py),
+            "gap_height" => self.get_gap_height(py),
+            "text_align" => enum_to_py(py, self.get_text_align(), enum_names),
+            "flex_direction" => enum_to_py(py, self.get_flex_direction(), enum_names),
+            "flex_wrap" => enum_to_py(py, self.get_flex_wrap(), enum_names),
+            "flex_basis" => self.get_flex_basis(py),
+            "flex_grow" => Ok(self.get_flex_grow().into_pyobject(py)?.into_any().unbind()),
+            "flex_shrink" => Ok(self
+                .get_flex_shrink()
+                .into_pyobject(py)?
+                .into_any()
+                .unbind()),
+            "grid_template_rows" => list_to_py(py, self.get_grid_template_rows(py)?),
+            "grid_template_columns" => list_to_py(py, self.get_grid_template_columns(py)?),
+            "grid_auto_rows" => list_to_py(py, self.get_grid_auto_rows(py)?),
+            "grid_auto_columns" => list_to_py(py, self.get_grid_auto_columns(py)?),
+            "grid_auto_flow" => enum_to_py(py, self.get_grid_auto_flow(), enum_names),
+            "grid_row" => Ok(Bound::new(py, self.get_grid_row())?.into_any().unbind()),
+            "grid_column" => Ok(Bound::new(py, self.get_grid_column())?.into_any().unbind()),
+            _ => Err(unknown_field(name)),
+        }
+    }
+
+    /// The CSS property name and value text of a field, by keyword name.
+    fn field_to_css(&self, name: &str) -> (&'static str, String) {
+        fn keyword<E: NamedEnum>(value: E) -> String {
+            value.css_name().to_owned()
+        }
+
+        fn opt_keyword<E: NamedEnum>(value: Option<E>) -> String {
+            value.map_or_else(|| "normal".to_owned(), keyword)
+        }
+
+        let s = &self.inner;
+        match name {
+            "display" => ("display", keyword(self.get_display())),
+            "box_sizing" => ("box-sizing", keyword(self.get_box_sizing())),
+            "overflow_x" => ("overflow-x", keyword(self.get_overflow_x())),
+            "overflow_y" => ("overflow-y", keyword(self.get_overflow_y())),
+            "scrollbar_width" => ("scrollbar-width", format!("{}px", s.scrollbar_width)),
+            "position" => ("position", keyword(self.get_position())),
+            "inset_left" => ("left", compact_length_to_css(s.inset.left.into_raw())),
+            "inset_right" => ("right", compact_length_to_css(s.inset.right.into_raw())),
+            "inset_top" => ("top", compact_length_to_css(s.inset.top.into_raw())),
+            "inset_bottom" => ("bottom", compact_length_to_css(s.inset.bottom.into_raw())),
+            "size_width" => ("width", compact_length_to_css(s.size.width.into_raw())),
+            "size_height" => ("height", compact_length_to_css(s.size.height.into_raw())),
+            "min_size_width" => (
+                "min-width",
+                compact_length_to_css(s.min_size.width.into_raw()),
+            ),
+            "min_size_height" => (
+                "min-height",
+                compact_length_to_css(s.min_size.height.into_raw()),
+            ),
+            "max_size_width" => (
+                "max-width",
+                compact_length_to_css(s.max_size.width.into_raw()),
+            ),
+            "max_size_height" => (
+                "max-height",
+                compact_length_to_css(s.max_size.height.into_raw()),
+            ),
+            "aspect_ratio" => (
+                "aspect-ratio",
+                s.aspect_ratio
+                    .map_or_else(|| "auto".to_owned(), |r| r.to_string()),
+            ),
+            "margin_left" => (
+                "margin-left",
+                compact_length_to_css(s.margin.left.into_raw()),
+            ),
+            "margin_right" => (
+                "margin-right",
+                compact_length_to_css(s.margin.right.into_raw()),
+            ),
+            "margin_top" => ("margin-top", compact_length_to_css(s.margin.top.into_raw())),
+            "margin_bottom" => (
+                "margin-bottom",
+                compact_length_to_css(s.margin.bottom.into_raw()),
+            ),
+            "padding_left" => (
+                "padding-left",
+                compact_length_to_css(s.padding.left.into_raw()),
+            ),
+            "padding_right" => (
+                "padding-right",
+                compact_length_to_css(s.padding.right.into_raw()),
+            ),
+            "padding_top" => (
+                "padding-top",
+                compact_length_to_css(s.padding.top.into_raw()),
+            ),
+            "padding_bottom" => (
+                "padding-bottom",
+                compact_length_to_css(s.padding.bottom.into_raw()),
+            ),
+            "border_left" => (
+                "border-left-width",
+                compact_length_to_css(s.border.left.into_raw()),
+            ),
+            "border_right" => (
+                "border-right-width",
+                compact_length_to_css(s.border.right.into_raw()),
+            ),
+            "border_top" => (
+                "border-top-width",
+                compact_length_to_css(s.border.top.into_raw()),
+            ),
+            "border_bottom" => (
+                "border-bottom-width",
+                compact_length_to_css(s.border.bottom.into_raw()),
+            ),
+            "align_items" => ("align-items", opt_keyword(self.get_align_items())),
+            "align_self" => ("align-self", opt_keyword(self.get_align_self())),
+            "justify_items" => ("justify-items", opt_keyword(self.get_justify_items())),
+            "justify_self" => ("justify-self", opt_keyword(self.get_justify_self())),
+            "align_content" => ("align-content", opt_keyword(self.get_align_content())),
+            "justify_content" => ("justify-content", opt_keyword(self.get_justify_content())),
+            "gap_width" => ("column-gap", compact_length_to_css(s.gap.width.into_raw())),
+            "gap_height" => ("row-gap", compact_length_to_css(s.gap.height.into_raw())),
+            "text_align" => ("text-align", keyword(self.get_text_align())),
+            "flex_direction" => ("flex-direction", keyword(self.get_flex_direction())),
+            "flex_wrap" => ("flex-wrap", keyword(self.get_flex_wrap())),
+            "flex_basis" => ("flex-basis", compact_length_to_css(s.flex_basis.into_raw())),
+            "flex_grow" => ("flex-grow", s.flex_grow.to_string()),
+            "flex_shrink" => ("flex-shrink", s.flex_shrink.to_string()),
+            "grid_template_rows" => (
+                "grid-template-rows",
+                grid_template_to_css(&s.grid_template_rows),
+            ),
+            "grid_template_columns" => (
+                "grid-template-columns",
+                grid_template_to_css(&s.grid_template_columns),
+            ),
+            "grid_auto_rows" => ("grid-auto-rows", auto_tracks_to_css(&s.grid_auto_rows)),
+            "grid_auto_columns" => (
+                "grid-auto-columns",
+                auto_tracks_to_css(&s.grid_auto_columns),
+            ),
+            "grid_auto_flow" => ("grid-auto-flow", keyword(self.get_grid_auto_flow())),
+            "grid_row" => ("grid-row", grid_line_to_css(&s.grid_row)),
+            "grid_column" => ("grid-column", grid_line_to_css(&s.grid_column)),
+            _ => unreachable!("FIELDS and field_to_css are out of sync: {name}"),
+        }
+    }
+}
+
+fn auto_tracks_to_css(tracks: &[taffy::style::TrackSizingFunction]) -> String {
+    if tracks.is_empty() {
+        return "auto".to_owned();
+    }
+    tracks
+        .iter()
+        .map(|t| grid_track_to_css(*t))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn grid_line_to_css(line: &taffy::Line<taffy::style::GridPlacement>) -> String {
+    format!(
+        "{} / {}",
+        grid_placement_to_css(&line.start),
+        grid_placement_to_css(&line.end)
+    )
+}
+
+/// Parses one CSS property value into a `Style(...)` keyword argument.
+type CssParser = fn(Python<'_>, &str) -> PyResult<(&'static str, Py<PyAny>)>;
+
+/// The parser for a (lowercase) CSS property, or None if it is not supported.
+fn css_property_parser(property: &str) -> Option<CssParser> {
+    let parser: CssParser = match property {
+        "display" => |py, v| Ok(("display", css_keyword::<Display>(py, v)?)),
+        "box-sizing" => |py, v| Ok(("box_sizing", css_keyword::<BoxSizing>(py, v)?)),
+        "overflow" => |py, v| {
+            let (x, y) = css_pair(py, v, css_keyword::<Overflow>)?;
+            Ok(("overflow", PyTuple::new(py, [x, y])?.into_any().unbind()))
+        },
+        "overflow-x" => |py, v| Ok(("overflow_x", css_keyword::<Overflow>(py, v)?)),
+        "overflow-y" => |py, v| Ok(("overflow_y", css_keyword::<Overflow>(py, v)?)),
+        "scrollbar-width" => |py, v| {
+            let width = css_px(v)?;
+            Ok((
+                "scrollbar_width",
+                width.into_pyobject(py)?.into_any().unbind(),
+            ))
+        },
+        "position" => |py, v| Ok(("position", css_keyword::<Position>(py, v)?)),
+        "inset" => |py, v| Ok(("inset", css_edges(py, v)?)),
+        "left" => |py, v| Ok(("inset_left", css_length(py, v)?)),
+        "right" => |py, v| Ok(("inset_right", css_length(py, v)?)),
+        "top" => |py, v| Ok(("inset_top", css_length(py, v)?)),
+        "bottom" => |py, v| Ok(("inset_bottom", css_length(py, v)?)),
+        "width" => |py, v| Ok(("size_width", css_length(py, v)?)),
+        "height" => |py, v| Ok(("size_height", css_length(py, v)?)),
+        "min-width" => |py, v| Ok(("min_size_width", css_length(py, v)?)),
+        "min-height" => |py, v| Ok(("min_size_height", css_length(py, v)?)),
+        "max-width" => |py, v| Ok(("max_size_width", css_length(py, v)?)),
+        "max-height" => |py, v| Ok(("max_size_height", css_length(py, v)?)),
+        "aspect-ratio" => |py, v| {
+            let ratio = match v.split_once('/') {
+                _ if v.eq_ignore_ascii_case("auto") => None,
+                Some((w, h)) => Some(css_number(w)? / css_number(h)?),
+                None => Some(css_number(v)?),
+            };
+            Ok(("aspect_ratio", ratio.into_pyobject(py)?.into_any().unbind()))
+        },
+        "margin" => |py, v| Ok(("margin", css_edges(py, v)?)),
+        "margin-left" => |py, v| Ok(("margin_left", css_length(py, v)?)),
+        "margin-right" => |py, v| Ok(("margin_right", css_length(py, v)?)),
+        "margin-top" => |py, v| Ok(("margin_top", css_length(py, v)?)),
+        "margin-bottom" => |py, v| Ok(("margin_bottom", css_length(py, v)?)),
+        "padding" => |py, v| Ok(("padding", css_edges(py, v)?)),
+        "padding-left" => |py, v| Ok(("padding_left", css_length(py, v)?)),
+        "padding-right" => |py, v| Ok(("padding_right", css_length(py, v)?)),
+        "padding-top" => |py, v| Ok(("padding_top", css_length(py, v)?)),
+        "padding-bottom" => |py, v| Ok(("padding_bottom", css_length(py, v)?)),
+        "border-width" => |py, v| Ok(("border", css_edges(py, v)?)),
+        "border-left-width" => |py, v| Ok(("border_left", css_length(py, v)?)),
+        "border-right-width" => |py, v| Ok(("border_right", css_length(py, v)?)),
+        "border-top-width" => |py, v| Ok(("border_top", css_length(py, v)?)),
+        "border-bottom-width" => |py, v| Ok(("border_bottom", css_length(py, v)?)),
+        "align-items" => |py, v| Ok(("align_items", css_opt_keyword::<AlignItems>(py, v)?)),
+        "align-self" => |py, v| Ok(("align_self", css_opt_keyword::<AlignItems>(py, v)?)),
+        "justify-items" => |py, v| Ok(("justify_items", css_opt_keyword::<AlignItems>(py, v)?)),
+        "justify-self" => |py, v| Ok(("justify_self", css_opt_keyword::<AlignItems>(py, v)?)),
+        "align-content" => |py, v| Ok(("align_content", css_opt_keyword::<AlignContent>(py, v)?)),
+        "justify-content" => {
+            |py, v| Ok(("justify_content", css_opt_keyword::<AlignContent>(py, v)?))
+        }
+        // CSS `gap` is `<row-gap> <column-gap>`, i.e. height first.
+        "gap" => |py, v| {
+            let (row, column) = css_pair(py, v, css_length)?;
+            Ok(("gap", PyTuple::new(py, [column, row])?.into_any().unbind()))
+        },
+        "column-gap" => |py, v| Ok(("gap_width", css_length(py, v)?)),
+        "row-gap" => |py, v| Ok(("gap_height", css_length(py, v)?)),
+        "text-align" => |py, v| Ok(("text_align", css_keyword::<TextAlign>(py, v)?)),
+        "flex-direction" => |py, v| Ok(("flex_direction", css_keyword::<FlexDirection>(py, v)?)),
+        "flex-wrap" => |py, v| Ok(("flex_wrap", css_keyword::<FlexWrap>(py, v)?)),
+        "flex-basis" => |py, v| Ok(("flex_basis", css_length(py, v)?)),
+        "flex-grow" => |py, v| {
+            let grow = css_number(v)?;
+            Ok(("flex_grow", grow.into_pyobject(py)?.into_any().unbind()))
+        },
+        "flex-shrink" => |py, v| {
+            let shrink = css_number(v)?;
+            Ok(("flex_shrink", shrink.into_pyobject(py)?.into_any().unbind()))
+        },
+        "grid-template-rows" => |py, v| Ok(("grid_template_rows", css_tracks(py, v)?)),
+        "grid-template-columns" => |py, v| Ok(("grid_template_columns", css_tracks(py, v)?)),
+        "grid-auto-rows" => |py, v| Ok(("grid_auto_rows", css_tracks(py, v)?)),
+        "grid-auto-columns" => |py, v| Ok(("grid_auto_columns", css_tracks(py, v)?)),
+        "grid-auto-flow" => |py, v| Ok(("grid_auto_flow", css_keyword::<GridAutoFlow>(py, v)?)),
+        "grid-row" => |py, v| {
+            let placement = Bound::new(py, GridPlacement::parse(v)?)?;
+            Ok(("grid_row", placement.into_any().unbind()))
+        },
+        "grid-column" => |py, v| {
+            let placement = Bound::new(py, GridPlacement::parse(v)?)?;
+            Ok(("grid_column", placement.into_any().unbind()))
+        },
+        _ => return None,
+    };
+    Some(parser)
+}
+
+fn css_keyword<E: NamedEnum + PyClass + Into<PyClassInitializer<E>>>(
+    py: Python<'_>,
+    value: &str,
+) -> PyResult<Py<PyAny>> {
+    Ok(Bound::new(py, E::from_css_name(value)?)?
+        .into_any()
+        .unbind())
+}
+
+/// Like `css_keyword`, but `normal` clears the (optional) alignment.
+fn css_opt_keyword<E: NamedEnum + PyClass + Into<PyClassInitializer<E>>>(
+    py: Python<'_>,
+    value: &str,
+) -> PyResult<Py<PyAny>> {
+    if value.eq_ignore_ascii_case("normal") {
+        Ok(py.None())
+    } else {
+        css_keyword::<E>(py, value)
+    }
+}
+
+fn css_number(value: &str) -> PyResult<f32> {
+    value
+        .trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| PyValueError::new_err(format!("expected a number, got {value:?}")))
+}
+
+/// A pixel length: `<n>px`, or a unitless `0`.
+fn css_px(value: &str) -> PyResult<f32> {
+    match value.strip_suffix("px") {
+        Some(number) => css_number(number),
+        None if value == "0" => Ok(0.0),
+        None => Err(PyValueError::new_err(format!(
+            "expected <n>px, <n>%, or auto, got {value:?}"
+        ))),
+    }
+}
+
+/// A `Length`, `Percent`, or `Auto`. Fields that don't accept `Auto` reject it when the
+/// keyword arguments are applied.
+fn css_length(py: Python<'_>, value: &str) -> PyResult<Py<PyAny>> {
+    if value.eq_ignore_ascii_case("auto") {
+        return Ok(Py::new(py, Auto::new())?.into_any());
+    }
+    if let Some(number) = value.strip_suffix('%') {
+        let percent = Percent::new(css_number(number)? / 100.0)?;
+        return Ok(Py::new(py, percent)?.into_any());
+    }
+    Ok(Py::new(py, Length::new(css_px(value)?)?)?.into_any())
+}
+
+/// One or two space-separated values, where a single value applies to both.
+fn css_pair(
+    py: Python<'_>,
+    value: &str,
+    parse: fn(Python<'_>, &str) -> PyResult<Py<PyAny>>,
+) -> PyResult<(Py<PyAny>, Py<PyAny>)> {
+    match value.split_whitespace().collect::<Vec<_>>()[..] {
+        [both] => {
+            let both = parse(py, both)?;
+            Ok((both.clone_ref(py), both))
+        }
+        [first, second] => Ok((parse(py, first)?, parse(py, second)?)),
+        _ => Err(PyValueError::new_err(format!(
+            "expected 1 or 2 values, got {value:?}"
+        ))),
+    }
+}
+
+/// A CSS edge shorthand with 1 to 4 values, as a `(top, right, bottom, left)` tuple.
+fn css_edges(py: Python<'_>, value: &str) -> PyResult<Py<PyAny>> {
+    let values = value
+        .split_whitespace()
+        .map(|v| css_length(py, v))
+        .collect::<PyResult<Vec<_>>>()?;
+    let (top, right, bottom, left) = match &values[..] {
+        [all] => (all, all, all, all),
+        [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+        [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+        [top, right, bottom, left] => (top, right, bottom, left),
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "expected 1 to 4 values, got {value:?}"
+            )))
+        }
+    };
+    Ok(PyTuple::new(py, [top, right, bottom, left])?
+        .into_any()
+        .unbind())
+}
+
+/// A space-separated track list, or `none` for no explicit tracks.
+fn css_tracks(py: Python<'_>, value: &str) -> PyResult<Py<PyAny>> {
+    let tracks = if value.eq_ignore_ascii_case("none") {
+        Vec::new()
+    } else {
+        tracks(py, value)?
+    };
+    Ok(PyList::new(py, tracks)?.into_any().unbind())
+}
+
+/// Extract a CSS-style edge shorthand: one value for all four edges, or a
+/// `(top, right, bottom, left)` tuple.
+fn extract_edges<'py, T: FromPyObjectOwned<'py>, U: Copy>(
+    value: &Bound<'py, PyAny>,
+    to_taffy: impl Fn(&T) -> U,
+) -> PyResult<taffy::Rect<U>> {
+    if value.is_instance_of::<PyTuple>() {
+        let (top, right, bottom, left) = value.extract::<(T, T, T, T)>()?;
+        Ok(taffy::Rect {
+            left: to_taffy(&left),
+            right: to_taffy(&right),
+            top: to_taffy(&top),
+            bottom: to_taffy(&bottom),
+        })
+    } else {
+        let v = to_taffy(&value.extract::<T>().map_err(Into::<PyErr>::into)?);
+        Ok(taffy::Rect {
+            left: v,
+            right: v,
+            top: v,
+            bottom: v,
+        })
+    }
+}
+
+/// Extract a `(width, height)` shorthand. With `allow_single`, a lone value applies to both axes.
+fn extract_axes<'py, T: FromPyObjectOwned<'py>, U: Copy>(
+    value: &Bound<'py, PyAny>,
+    allow_single: bool,
+    to_taffy: impl Fn(&T) -> U,
+) -> PyResult<taffy::Size<U>> {
+    if allow_single && !value.is_instance_of::<PyTuple>() {
+        let v = to_taffy(&value.extract::<T>().map_err(Into::<PyErr>::into)?);
+        return Ok(taffy::Size {
+            width: v,
+            height: v,
+        });
+    }
+    let (width, height) = value.extract::<(T, T)>()?;
+    Ok(taffy::Size {
+        width: to_taffy(&width),
+        height: to_taffy(&height),
+    })
+}
+
+/// Edge values in CSS shorthand order: `(top, right, bottom, left)`.
+type Edges = (Py<PyAny>, Py<PyAny>, Py<PyAny>, Py<PyAny>);
+
+fn unknown_field(name: &str) -> PyErr {
+    PyValueError::new_err(format!("unknown Style field: {name:?}"))
+}
+
+/// The set-field bit for a field, by keyword name.
+fn field_flag(name: &str) -> PyResult<u64> {
+    FIELDS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, flag)| *flag)
+        .ok_or_else(|| unknown_field(name))
+}
+
+/// Convert a variant name from `Style.to_dict()` back into the enum member for `field`.
+/// Returns `None` for fields that don't hold enums or values that aren't strings.
+fn enum_from_name(
+    py: Python<'_>,
+    field: &str,
+    value: &Bound<'_, PyAny>,
+) -> Option<PyResult<Py<PyAny>>> {
+    fn lookup<E: NamedEnum + PyClass + Into<PyClassInitializer<E>>>(
+        py: Python<'_>,
+        name: &str,
+    ) -> PyResult<Py<PyAny>> {
+        match E::from_name(name) {
+            Some(v) => Ok(Bound::new(py, v)?.into_any().unbind()),
+            None => Err(PyValueError::new_err(format!(
+                "unknown {} variant: {name:?}",
+                E::TYPE_NAME
+            ))),
+        }
+    }
+
+    let name = value.cast::<PyString>().ok()?.to_cow().ok()?;
+    Some(match field {
+        "display" => lookup::<Display>(py, &name),
+        "box_sizing" => lookup::<BoxSizing>(py, &name),
+        "overflow_x" | "overflow_y" => lookup::<Overflow>(py, &name),
+        "position" => lookup::<Position>(py, &name),
+        "align_items" | "align_self" | "justify_items" | "justify_self" => {
+            lookup::<AlignItems>(py, &name)
+        }
+        "align_content" | "justify_content" => lookup::<AlignContent>(py, &name),
+        "text_align" => lookup::<TextAlign>(py, &name),
+        "flex_direction" => lookup::<FlexDirection>(py, &name),
+        "flex_wrap" => lookup::<FlexWrap>(py, &name),
+        "grid_auto_flow" => lookup::<GridAutoFlow>(py, &name),
+        _ => return None,
+    })
 }
 
 fn opt_align_items_from_taffy(v: Option<taffy::AlignItems>) -> Option<AlignItems> {
@@ -98,12 +857,9 @@ fn opt_align_content_to_taffy(v: &Option<AlignContent>) -> Option<taffy::AlignCo
 
 fn tracks_to_taffy(
     py: Python<'_>,
-    tracks: &[GridTrackInput],
+    tracks: &[GridTemplateInput],
 ) -> Vec<taffy::style::GridTemplateComponent<String>> {
-    tracks
-        .iter()
-        .map(|t| taffy::style::GridTemplateComponent::Single(t.to_taffy(py)))
-        .collect()
+    tracks.iter().map(|t| t.to_taffy(py)).collect()
 }
 
 fn tracks_from_taffy(
@@ -112,9 +868,17 @@ fn tracks_from_taffy(
 ) -> PyResult<Vec<Py<PyAny>>> {
     tracks
         .iter()
-        .filter_map(|t| match t {
-            taffy::style::GridTemplateComponent::Single(tsf) => Some(grid_track_to_py(py, *tsf)),
-            _ => None, // Skip repeat() for now
+        .map(|t| match t {
+            taffy::style::GridTemplateComponent::Single(tsf) => grid_track_to_py(py, *tsf),
+            taffy::style::GridTemplateComponent::Repeat(rep) => {
+                let tracks = rep
+                    .tracks
+                    .iter()
+                    .map(|t| grid_track_to_py(py, *t))
+                    .collect::<PyResult<_>>()?;
+                let count = rep.count;
+                Ok(Py::new(py, Repeat { count, tracks })?.into_any())
+            }
         })
         .collect()
 }
@@ -182,6 +946,18 @@ impl Style {
         set_field!("box_sizing", F_BOX_SIZING, |v: BoxSizing| {
             style.box_sizing = (&v).into()
         });
+        // The `overflow` shorthand sets both axes, before the per-axis fields.
+        if let Some(py_val) = kwargs.get_item("overflow")? {
+            if !py_val.is_none() {
+                let overflow =
+                    extract_axes(&py_val, true, |v: &Overflow| taffy::Overflow::from(v))?;
+                style.overflow = taffy::Point {
+                    x: overflow.width,
+                    y: overflow.height,
+                };
+                set_fields |= F_OVERFLOW_X | F_OVERFLOW_Y;
+            }
+        }
         set_field!("overflow_x", F_OVERFLOW_X, |v: Overflow| {
             style.overflow.x = (&v).into()
         });
@@ -195,6 +971,86 @@ impl Style {
             style.position = (&v).into()
         });
 
+        /// Edge shorthands set all four edges at once. They are applied before the
+        /// per-edge fields, so e.g. `margin_top` overrides the top edge of `margin`.
+        macro_rules! set_edges {
+            ($key:literal, $field:ident, $input:ty, $flags:expr) => {
+                if let Some(py_val) = kwargs.get_item($key)? {
+                    if !py_val.is_none() {
+                        style.$field = extract_edges(&py_val, |v: &$input| v.to_taffy())?;
+                        set_fields |= $flags;
+                    }
+                }
+            };
+        }
+
+        set_edges!(
+            "inset",
+            inset,
+            LengthPercentageAutoInput,
+            F_INSET_LEFT | F_INSET_RIGHT | F_INSET_TOP | F_INSET_BOTTOM
+        );
+        set_edges!(
+            "margin",
+            margin,
+            LengthPercentageAutoInput,
+            F_MARGIN_LEFT | F_MARGIN_RIGHT | F_MARGIN_TOP | F_MARGIN_BOTTOM
+        );
+        set_edges!(
+            "padding",
+            padding,
+            LengthPercentageInput,
+            F_PADDING_LEFT | F_PADDING_RIGHT | F_PADDING_TOP | F_PADDING_BOTTOM
+        );
+        set_edges!(
+            "border",
+            border,
+            LengthPercentageInput,
+            F_BORDER_LEFT | F_BORDER_RIGHT | F_BORDER_TOP | F_BORDER_BOTTOM
+        );
+
+        /// Axis shorthands set width and height at once, before the per-axis fields.
+        macro_rules! set_axes {
+            ($key:literal, $field:ident, $input:ty, $allow_single:expr, $flags:expr) => {
+                if let Some(py_val) = kwargs.get_item($key)? {
+                    if !py_val.is_none() {
+                        style.$field =
+                            extract_axes(&py_val, $allow_single, |v: &$input| v.to_taffy())?;
+                        set_fields |= $flags;
+                    }
+                }
+            };
+        }
+
+        set_axes!(
+            "size",
+            size,
+            DimensionInput,
+            false,
+            F_SIZE_WIDTH | F_SIZE_HEIGHT
+        );
+        set_axes!(
+            "min_size",
+            min_size,
+            DimensionInput,
+            false,
+            F_MIN_SIZE_WIDTH | F_MIN_SIZE_HEIGHT
+        );
+        set_axes!(
+            "max_size",
+            max_size,
+            DimensionInput,
+            false,
+            F_MAX_SIZE_WIDTH | F_MAX_SIZE_HEIGHT
+        );
+        set_axes!(
+            "gap",
+            gap,
+            LengthPercentageInput,
+            true,
+            F_GAP_WIDTH | F_GAP_HEIGHT
+        );
+
         // Inset
         set_field!(
             "inset_left",
@@ -237,6 +1093,9 @@ impl Style {
         set_opt_field!("aspect_ratio", F_ASPECT_RATIO, |v: Option<f32>| {
             style.aspect_ratio = v
         });
+        if let Some(ratio) = style.aspect_ratio {
+            check_aspect_ratio(ratio)?;
+        }
 
         // Margin
         set_field!(
@@ -352,14 +1211,14 @@ impl Style {
 
         // Grid
         set_field!("grid_template_rows", F_GRID_TEMPLATE_ROWS, |v: Vec<
-            GridTrackInput,
+            GridTemplateInput,
         >| {
             style.grid_template_rows = tracks_to_taffy(py, &v)
         });
         set_field!(
             "grid_template_columns",
             F_GRID_TEMPLATE_COLUMNS,
-            |v: Vec<GridTrackInput>| { style.grid_template_columns = tracks_to_taffy(py, &v) }
+            |v: Vec<GridTemplateInput>| { style.grid_template_columns = tracks_to_taffy(py, &v) }
         );
         set_field!("grid_auto_rows", F_GRID_AUTO_ROWS, |v: Vec<
             GridTrackInput,
@@ -381,6 +1240,18 @@ impl Style {
             style.grid_column = (&v).into()
         });
 
+        // `clear` unsets fields after everything else, so it wins over any value given for them.
+        if let Some(py_val) = kwargs.get_item("clear")? {
+            if !py_val.is_none() {
+                let mut mask = 0;
+                for name in py_val.extract::<Vec<String>>()? {
+                    mask |= field_flag(&name)?;
+                }
+                copy_fields(&mut style, &taffy::Style::DEFAULT, mask);
+                set_fields &= !mask;
+            }
+        }
+
         Ok(Self {
             inner: style,
             set_fields,
@@ -409,6 +1280,12 @@ impl Style {
         self.inner.overflow.y.into()
     }
 
+    /// Horizontal and vertical overflow as `(x, y)`.
+    #[getter]
+    fn get_overflow(&self) -> (Overflow, Overflow) {
+        (self.get_overflow_x(), self.get_overflow_y())
+    }
+
     #[getter]
     fn get_scrollbar_width(&self) -> f32 {
         self.inner.scrollbar_width
@@ -437,6 +1314,17 @@ impl Style {
         length_percentage_auto_to_py(py, self.inner.inset.bottom)
     }
 
+    /// All four inset edges as `(top, right, bottom, left)`.
+    #[getter]
+    fn get_inset(&self, py: Python<'_>) -> PyResult<Edges> {
+        Ok((
+            self.get_inset_top(py)?,
+            self.get_inset_right(py)?,
+            self.get_inset_bottom(py)?,
+            self.get_inset_left(py)?,
+        ))
+    }
+
     // Size
     #[getter]
     fn get_size_width(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
@@ -446,6 +1334,12 @@ impl Style {
     fn get_size_height(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         dimension_to_py(py, self.inner.size.height)
     }
+
+    /// Preferred width and height as `(width, height)`.
+    #[getter]
+    fn get_size(&self, py: Python<'_>) -> PyResult<(Py<PyAny>, Py<PyAny>)> {
+        Ok((self.get_size_width(py)?, self.get_size_height(py)?))
+    }
     #[getter]
     fn get_min_size_width(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         dimension_to_py(py, self.inner.min_size.width)
@@ -454,6 +1348,12 @@ impl Style {
     fn get_min_size_height(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         dimension_to_py(py, self.inner.min_size.height)
     }
+
+    /// Minimum width and height as `(width, height)`.
+    #[getter]
+    fn get_min_size(&self, py: Python<'_>) -> PyResult<(Py<PyAny>, Py<PyAny>)> {
+        Ok((self.get_min_size_width(py)?, self.get_min_size_height(py)?))
+    }
     #[getter]
     fn get_max_size_width(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         dimension_to_py(py, self.inner.max_size.width)
@@ -463,6 +1363,12 @@ impl Style {
         dimension_to_py(py, self.inner.max_size.height)
     }
 
+    /// Maximum width and height as `(width, height)`.
+    #[getter]
+    fn get_max_size(&self, py: Python<'_>) -> PyResult<(Py<PyAny>, Py<PyAny>)> {
+        Ok((self.get_max_size_width(py)?, self.get_max_size_height(py)?))
+    }
+
     #[getter]
     fn get_aspect_ratio(&self) -> Option<f32> {
         self.inner.aspect_ratio
@@ -486,6 +1392,17 @@ impl Style {
         length_percentage_auto_to_py(py, self.inner.margin.bottom)
     }
 
+    /// All four margin edges as `(top, right, bottom, left)`.
+    #[getter]
+    fn get_margin(&self, py: Python<'_>) -> PyResult<Edges> {
+        Ok((
+            self.get_margin_top(py)?,
+            self.get_margin_right(py)?,
+            self.get_margin_bottom(py)?,
+            self.get_margin_left(py)?,
+        ))
+    }
+
     // Padding
     #[getter]
     fn get_padding_left(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
@@ -504,6 +1421,17 @@ impl Style {
         length_percentage_to_py(py, self.inner.padding.bottom)
     }
 
+    /// All four padding edges as `(top, right, bottom, left)`.
+    #[getter]
+    fn get_padding(&self, py: Python<'_>) -> PyResult<Edges> {
+        Ok((
+            self.get_padding_top(py)?,
+            self.get_padding_right(py)?,
+            self.get_padding_bottom(py)?,
+            self.get_padding_left(py)?,
+        ))
+    }
+
     // Border
     #[getter]
     fn get_border_left(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
@@ -522,6 +1450,17 @@ impl Style {
         length_percentage_to_py(py, self.inner.border.bottom)
     }
 
+    /// All four border edges as `(top, right, bottom, left)`.
+    #[getter]
+    fn get_border(&self, py: Python<'_>) -> PyResult<Edges> {
+        Ok((
+            self.get_border_top(py)?,
+            self.get_border_right(py)?,
+            self.get_border_bottom(py)?,
+            self.get_border_left(py)?,
+        ))
+    }
+
     // Alignment
     #[getter]
     fn get_align_items(&self) -> Option<AlignItems> {
@@ -563,6 +1502,12 @@ impl Style {
         length_percentage_to_py(py, self.inner.gap.height)
     }
 
+    /// Horizontal and vertical gaps as `(width, height)`.
+    #[getter]
+    fn get_gap(&self, py: Python<'_>) -> PyResult<(Py<PyAny>, Py<PyAny>)> {
+        Ok((self.get_gap_width(py)?, self.get_gap_height(py)?))
+    }
+
     // Block
     #[getter]
     fn get_text_align(&self) -> TextAlign {
@@ -631,226 +1576,1043 @@ impl Style {
         self.inner.grid_column.clone().into()
     }
 
-    fn __eq__(&self, other: &Style) -> bool {
-        self.inner == other.inner
+    // --- Builders ---
+    // Each `with_<field>` returns a copy with one constructor argument applied.
+
+    fn with_display(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("display", value)
     }
 
-    fn __hash__(&self) -> u64 {
-        use std::hash::{Hash, Hasher};
-        use std::mem::discriminant;
-        let mut h = std::collections::hash_map::DefaultHasher::new();
-        let s = &self.inner;
+    fn with_box_sizing(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("box_sizing", value)
+    }
 
-        // Enum fields — use discriminant since taffy enums don't derive Hash.
-        discriminant(&s.display).hash(&mut h);
-        discriminant(&s.box_sizing).hash(&mut h);
-        discriminant(&s.overflow.x).hash(&mut h);
-        discriminant(&s.overflow.y).hash(&mut h);
-        discriminant(&s.position).hash(&mut h);
-        discriminant(&s.text_align).hash(&mut h);
-        discriminant(&s.flex_direction).hash(&mut h);
-        discriminant(&s.flex_wrap).hash(&mut h);
-        discriminant(&s.grid_auto_flow).hash(&mut h);
+    fn with_overflow(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("overflow", value)
+    }
 
-        // Option<enum> fields.
-        s.align_items.map(|v| discriminant(&v)).hash(&mut h);
-        s.align_self.map(|v| discriminant(&v)).hash(&mut h);
-        s.justify_items.map(|v| discriminant(&v)).hash(&mut h);
-        s.justify_self.map(|v| discriminant(&v)).hash(&mut h);
-        s.align_content.map(|v| discriminant(&v)).hash(&mut h);
-        s.justify_content.map(|v| discriminant(&v)).hash(&mut h);
+    fn with_overflow_x(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("overflow_x", value)
+    }
 
-        // Plain f32 fields — normalize -0.0 (consistent with f32's IEEE
-        // float equality used by taffy's derived PartialEq for these fields).
-        hash_f32(s.scrollbar_width, &mut h);
-        hash_f32(s.flex_grow, &mut h);
-        hash_f32(s.flex_shrink, &mut h);
-        match s.aspect_ratio {
-            Some(v) => {
-                1u8.hash(&mut h);
-                hash_f32(v, &mut h);
-            }
-            None => 0u8.hash(&mut h),
-        }
+    fn with_overflow_y(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("overflow_y", value)
+    }
 
-        // CompactLength fields — bit-exact, matching CompactLength's PartialEq.
-        // Each field type (Dimension, LengthPercentage, etc.) wraps CompactLength;
-        // into_raw() extracts it.
-        hash_taffy_compact_length(s.inset.left.into_raw(), &mut h);
-        hash_taffy_compact_length(s.inset.right.into_raw(), &mut h);
-        hash_taffy_compact_length(s.inset.top.into_raw(), &mut h);
-        hash_taffy_compact_length(s.inset.bottom.into_raw(), &mut h);
-        hash_taffy_compact_length(s.size.width.into_raw(), &mut h);
-        hash_taffy_compact_length(s.size.height.into_raw(), &mut h);
-        hash_taffy_compact_length(s.min_size.width.into_raw(), &mut h);
-        hash_taffy_compact_length(s.min_size.height.into_raw(), &mut h);
-        hash_taffy_compact_length(s.max_size.width.into_raw(), &mut h);
-        hash_taffy_compact_length(s.max_size.height.into_raw(), &mut h);
-        hash_taffy_compact_length(s.margin.left.into_raw(), &mut h);
-        hash_taffy_compact_length(s.margin.right.into_raw(), &mut h);
-        hash_taffy_compact_length(s.margin.top.into_raw(), &mut h);
-        hash_taffy_compact_length(s.margin.bottom.into_raw(), &mut h);
-        hash_taffy_compact_length(s.padding.left.into_raw(), &mut h);
-        hash_taffy_compact_length(s.padding.right.into_raw(), &mut h);
-        hash_taffy_compact_length(s.padding.top.into_raw(), &mut h);
-        hash_taffy_compact_length(s.padding.bottom.into_raw(), &mut h);
-        hash_taffy_compact_length(s.border.left.into_raw(), &mut h);
-        hash_taffy_compact_length(s.border.right.into_raw(), &mut h);
-        hash_taffy_compact_length(s.border.top.into_raw(), &mut h);
-        hash_taffy_compact_length(s.border.bottom.into_raw(), &mut h);
-        hash_taffy_compact_length(s.gap.width.into_raw(), &mut h);
-        hash_taffy_compact_length(s.gap.height.into_raw(), &mut h);
-        hash_taffy_compact_length(s.flex_basis.into_raw(), &mut h);
+    fn with_scrollbar_width(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("scrollbar_width", value)
+    }
 
-        // Grid track Vecs.
-        s.grid_auto_rows.len().hash(&mut h);
-        for tsf in &s.grid_auto_rows {
-            hash_taffy_track_sizing_function(*tsf, &mut h);
-        }
-        s.grid_auto_columns.len().hash(&mut h);
-        for tsf in &s.grid_auto_columns {
-            hash_taffy_track_sizing_function(*tsf, &mut h);
-        }
-        s.grid_template_rows.len().hash(&mut h);
-        for comp in &s.grid_template_rows {
-            hash_taffy_template_component(comp, &mut h);
-        }
-        s.grid_template_columns.len().hash(&mut h);
-        for comp in &s.grid_template_columns {
-            hash_taffy_template_component(comp, &mut h);
-        }
-        s.grid_template_areas.len().hash(&mut h);
-        for area in &s.grid_template_areas {
-            hash_taffy_template_area(area, &mut h);
-        }
+    fn with_position(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("position", value)
+    }
 
-        // Grid placement fields.
-        hash_taffy_grid_placement(&s.grid_row.start, &mut h);
-        hash_taffy_grid_placement(&s.grid_row.end, &mut h);
-        hash_taffy_grid_placement(&s.grid_column.start, &mut h);
-        hash_taffy_grid_placement(&s.grid_column.end, &mut h);
+    fn with_inset(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("inset", value)
+    }
 
-        h.finish()
+    fn with_inset_left(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("inset_left", value)
     }
 
-    fn __repr__(&self) -> String {
-        format!(
-            "Style(display={:?}, position={:?})",
-            self.inner.display, self.inner.position
-        )
+    fn with_inset_right(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("inset_right", value)
     }
 
-    /// Merge two styles: `self | other`. Fields explicitly set in `other` override
-    /// those in `self`. Fields not set in `other` are preserved from `self`.
-    fn __or__(&self, other: &Style) -> Style {
-        let mut result = self.clone();
-        let rhs = other.set_fields;
+    fn with_inset_top(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("inset_top", value)
+    }
 
-        macro_rules! merge {
-            // Copy field: result.inner.$($path).+ = other.inner.$($path).+
-            ($flag:expr, $($path:ident).+) => {
-                if rhs & $flag != 0 {
-                    result.inner.$($path).+ = other.inner.$($path).+;
-                }
-            };
-            // Clone field (for non-Copy types like Vec)
-            (clone $flag:expr, $($path:ident).+) => {
-                if rhs & $flag != 0 {
-                    result.inner.$($path).+ = other.inner.$($path).+.clone();
-                }
-            };
-        }
+    fn with_inset_bottom(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("inset_bottom", value)
+    }
 
-        merge!(F_DISPLAY, display);
-        merge!(F_BOX_SIZING, box_sizing);
-        merge!(F_OVERFLOW_X, overflow.x);
-        merge!(F_OVERFLOW_Y, overflow.y);
-        merge!(F_SCROLLBAR_WIDTH, scrollbar_width);
-        merge!(F_POSITION, position);
+    fn with_size(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("size", value)
+    }
 
-        // Inset
-        merge!(F_INSET_LEFT, inset.left);
-        merge!(F_INSET_RIGHT, inset.right);
-        merge!(F_INSET_TOP, inset.top);
-        merge!(F_INSET_BOTTOM, inset.bottom);
+    fn with_size_width(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("size_width", value)
+    }
 
-        // Size
-        merge!(F_SIZE_WIDTH, size.width);
-        merge!(F_SIZE_HEIGHT, size.height);
-        merge!(F_MIN_SIZE_WIDTH, min_size.width);
-        merge!(F_MIN_SIZE_HEIGHT, min_size.height);
-        merge!(F_MAX_SIZE_WIDTH, max_size.width);
-        merge!(F_MAX_SIZE_HEIGHT, max_size.height);
-        merge!(F_ASPECT_RATIO, aspect_ratio);
+    fn with_size_height(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("size_height", value)
+    }
 
-        // Margin
-        merge!(F_MARGIN_LEFT, margin.left);
-        merge!(F_MARGIN_RIGHT, margin.right);
-        merge!(F_MARGIN_TOP, margin.top);
-        merge!(F_MARGIN_BOTTOM, margin.bottom);
+    fn with_min_size(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("min_size", value)
+    }
 
-        // Padding
-        merge!(F_PADDING_LEFT, padding.left);
-        merge!(F_PADDING_RIGHT, padding.right);
-        merge!(F_PADDING_TOP, padding.top);
-        merge!(F_PADDING_BOTTOM, padding.bottom);
+    fn with_min_size_width(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("min_size_width", value)
+    }
 
-        // Border
-        merge!(F_BORDER_LEFT, border.left);
-        merge!(F_BORDER_RIGHT, border.right);
-        merge!(F_BORDER_TOP, border.top);
-        merge!(F_BORDER_BOTTOM, border.bottom);
-
-        // Alignment
-        merge!(F_ALIGN_ITEMS, align_items);
-        merge!(F_ALIGN_SELF, align_self);
-        merge!(F_JUSTIFY_ITEMS, justify_items);
-        merge!(F_JUSTIFY_SELF, justify_self);
-        merge!(F_ALIGN_CONTENT, align_content);
-        merge!(F_JUSTIFY_CONTENT, justify_content);
+    fn with_min_size_height(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("min_size_height", value)
+    }
 
-        // Gap
-        merge!(F_GAP_WIDTH, gap.width);
-        merge!(F_GAP_HEIGHT, gap.height);
+    fn with_max_size(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("max_size", value)
+    }
 
-        // Block
-        merge!(F_TEXT_ALIGN, text_align);
+    fn with_max_size_width(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("max_size_width", value)
+    }
 
-        // Flexbox
-        merge!(F_FLEX_DIRECTION, flex_direction);
-        merge!(F_FLEX_WRAP, flex_wrap);
-        merge!(F_FLEX_BASIS, flex_basis);
-        merge!(F_FLEX_GROW, flex_grow);
-        merge!(F_FLEX_SHRINK, flex_shrink);
+    fn with_max_size_height(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("max_size_height", value)
+    }
 
-        // Grid
-        merge!(clone F_GRID_TEMPLATE_ROWS, grid_template_rows);
-        merge!(clone F_GRID_TEMPLATE_COLUMNS, grid_template_columns);
-        merge!(clone F_GRID_AUTO_ROWS, grid_auto_rows);
-        merge!(clone F_GRID_AUTO_COLUMNS, grid_auto_columns);
-        merge!(F_GRID_AUTO_FLOW, grid_auto_flow);
-        merge!(clone F_GRID_ROW, grid_row);
-        merge!(clone F_GRID_COLUMN, grid_column);
+    fn with_aspect_ratio(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("aspect_ratio", value)
+    }
+
+    fn with_margin(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("margin", value)
+    }
+
+    fn with_margin_left(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("margin_left", value)
+    }
+
+    fn with_margin_right(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("margin_right", value)
+    }
+
+    fn with_margin_top(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("margin_top", value)
+    }
+
+    fn with_margin_bottom(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("margin_bottom", value)
+    }
+
+    fn with_padding(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("padding", value)
+    }
+
+    fn with_padding_left(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("padding_left", value)
+    }
+
+    fn with_padding_right(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("padding_right", value)
+    }
+
+    fn with_padding_top(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("padding_top", value)
+    }
+
+    fn with_padding_bottom(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("padding_bottom", value)
+    }
+
+    fn with_border(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("border", value)
+    }
+
+    fn with_border_left(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("border_left", value)
+    }
+
+    fn with_border_right(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("border_right", value)
+    }
+
+    fn with_border_top(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("border_top", value)
+    }
+
+    fn with_border_bottom(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("border_bottom", value)
+    }
+
+    fn with_align_items(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("align_items", value)
+    }
+
+    fn with_align_self(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("align_self", value)
+    }
+
+    fn with_justify_items(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("justify_items", value)
+    }
+
+    fn with_justify_self(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("justify_self", value)
+    }
+
+    fn with_align_content(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("align_content", value)
+    }
+
+    fn with_justify_content(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("justify_content", value)
+    }
+
+    fn with_gap(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("gap", value)
+    }
+
+    fn with_gap_width(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("gap_width", value)
+    }
+
+    fn with_gap_height(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("gap_height", value)
+    }
+
+    fn with_text_align(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("text_align", value)
+    }
+
+    fn with_flex_direction(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("flex_direction", value)
+    }
+
+    fn with_flex_wrap(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("flex_wrap", value)
+    }
+
+    fn with_flex_basis(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("flex_basis", value)
+    }
+
+    fn with_flex_grow(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("flex_grow", value)
+    }
+
+    fn with_flex_shrink(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("flex_shrink", value)
+    }
+
+    fn with_grid_template_rows(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("grid_template_rows", value)
+    }
+
+    fn with_grid_template_columns(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("grid_template_columns", value)
+    }
+
+    fn with_grid_auto_rows(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("grid_auto_rows", value)
+    }
+
+    fn with_grid_auto_columns(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("grid_auto_columns", value)
+    }
+
+    fn with_grid_auto_flow(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("grid_auto_flow", value)
+    }
+
+    fn with_grid_row(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("grid_row", value)
+    }
+
+    fn with_grid_column(&self, value: &Bound<'_, PyAny>) -> PyResult<Style> {
+        self.with_field("grid_column", value)
+    }
+
+    /// Serialize the explicitly set fields to a dict.
+    pub(crate) fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (name, value) in self.set_field_items(py, true)? {
+            dict.set_item(name, value)?;
+        }
+        Ok(dict)
+    }
+
+    /// Reconstruct a Style from the output of `to_dict()`.
+    #[classmethod]
+    fn from_dict(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        data: &Bound<'_, PyDict>,
+    ) -> PyResult<Self> {
+        let kwargs = PyDict::new(py);
+        for (key, value) in data.iter() {
+            let key: String = key.extract()?;
+            field_flag(&key)?;
+            match enum_from_name(py, &key, &value) {
+                Some(member) => kwargs.set_item(&key, member?)?,
+                None => kwargs.set_item(&key, value)?,
+            }
+        }
+        Self::new(py, Some(&kwargs))
+    }
+
+    /// Parse a CSS declaration block such as `display: flex; gap: 8px;`.
+    ///
+    /// Only the declared properties are marked set, and later declarations override
+    /// earlier ones, as in CSS.
+    #[classmethod]
+    fn from_css(_cls: &Bound<'_, PyType>, py: Python<'_>, text: &str) -> PyResult<Self> {
+        let mut style = Self::new(py, None)?;
+        for declaration in text.split(';').map(str::trim).filter(|d| !d.is_empty()) {
+            let (property, value) = declaration.split_once(':').ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "expected a `property: value` declaration, got {declaration:?}"
+                ))
+            })?;
+            let property = property.trim().to_ascii_lowercase();
+            let value = value.trim();
+            let parse = css_property_parser(&property).ok_or_else(|| {
+                PyValueError::new_err(format!("unsupported CSS property {property:?}"))
+            })?;
+            let invalid = |err: PyErr| {
+                PyValueError::new_err(format!(
+                    "invalid value {value:?} for CSS property {property:?}: {}",
+                    err.value(py)
+                ))
+            };
+            let (key, parsed) = parse(py, value).map_err(invalid)?;
+            let kwargs = PyDict::new(py);
+            kwargs.set_item(key, parsed)?;
+            style = style.__or__(&Self::new(py, Some(&kwargs)).map_err(invalid)?);
+        }
+        Ok(style)
+    }
+
+    /// A flex container laid out in a row. Keyword arguments override the presets.
+    #[classmethod]
+    #[pyo3(signature = (**overrides))]
+    fn flex_row(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        overrides: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
+        Self::preset(py, Display::Flex, Some(FlexDirection::Row), overrides)
+    }
+
+    /// A flex container laid out in a column. Keyword arguments override the presets.
+    #[classmethod]
+    #[pyo3(signature = (**overrides))]
+    fn flex_column(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        overrides: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
+        Self::preset(py, Display::Flex, Some(FlexDirection::Column), overrides)
+    }
+
+    /// A grid container. Keyword arguments override the presets.
+    #[classmethod]
+    #[pyo3(signature = (**overrides))]
+    fn grid(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        overrides: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
+        Self::preset(py, Display::Grid, None, overrides)
+    }
+
+    /// The `aspect_ratio` for a `width` by `height` box, i.e. `width / height`.
+    #[staticmethod]
+    fn aspect_ratio_from(width: f32, height: f32) -> PyResult<f32> {
+        if height == 0.0 {
+            return Err(PyValueError::new_err(
+                "aspect_ratio_from() height must not be zero",
+            ));
+        }
+        let ratio = width / height;
+        check_aspect_ratio(ratio)?;
+        Ok(ratio)
+    }
+
+    /// Whether the node is displayed at all, i.e. `display` is not `Display.Nil`.
+    fn is_visible(&self) -> bool {
+        self.inner.display != taffy::Display::None
+    }
+
+    /// Whether the named field was explicitly set.
+    fn is_set(&self, name: &str) -> PyResult<bool> {
+        Ok(self.set_fields & field_flag(name)? != 0)
+    }
+
+    /// The names of the explicitly set fields, sorted alphabetically.
+    fn set_field_names(&self) -> Vec<&'static str> {
+        let mut names: Vec<_> = FIELDS
+            .iter()
+            .filter(|(_, flag)| self.set_fields & flag != 0)
+            .map(|(name, _)| *name)
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Whether no field is explicitly set.
+    fn is_empty(&self) -> bool {
+        self.set_fields == 0
+    }
+
+    /// Raise `ValueError` for the first numeric field that is out of range, using the
+    /// same checks as `TaffyTree.lint` (see `value_issues`).
+    fn validate(&self) -> PyResult<()> {
+        match value_issues(&self.inner).into_iter().next() {
+            Some(issue) => Err(PyValueError::new_err(issue)),
+            None => Ok(()),
+        }
+    }
+
+    /// A CSS declaration block for the explicitly set fields, in declaration order,
+    /// e.g. `display: flex; width: 100px; margin-top: auto;`.
+    fn to_css(&self) -> String {
+        FIELDS
+            .iter()
+            .filter(|(_, flag)| self.set_fields & flag != 0)
+            .map(|(name, _)| {
+                let (property, value) = self.field_to_css(name);
+                format!("{property}: {value};")
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Resolve the four padding edges against the containing block's inline size (width).
+    ///
+    /// Per CSS, percentage padding on *every* edge, including top and bottom, resolves
+    /// against the container's width.
+    fn resolve_padding(&self, container_inline_size: f32) -> Rect {
+        self.inner
+            .padding
+            .resolve_or_zero(Some(container_inline_size), |_, _| 0.0)
+            .into()
+    }
+
+    /// Interpolate between this style and `other` at `t` in `[0, 1]`.
+    fn interpolate(&self, other: &Style, t: f32) -> PyResult<Style> {
+        check_t(t)?;
+        Ok(interpolate_styles(self, other, t))
+    }
+
+    /// Interpolate across `(offset, style)` keyframes sorted by offset.
+    #[staticmethod]
+    fn animate(frames: Vec<(f32, Style)>, t: f32) -> PyResult<Style> {
+        check_t(t)?;
+        let (Some(first), Some(last)) = (frames.first(), frames.last()) else {
+            return Err(PyValueError::new_err(
+                "animate() requires at least one keyframe",
+            ));
+        };
+        if frames.windows(2).any(|w| w[0].0 > w[1].0) {
+            return Err(PyValueError::new_err(
+                "animate() keyframes must be sorted by offset",
+            ));
+        }
+        if t <= first.0 {
+            return Ok(first.1.clone());
+        }
+        if t >= last.0 {
+            return Ok(last.1.clone());
+        }
+        let i = frames.partition_point(|(offset, _)| *offset <= t) - 1;
+        let ((start, from), (end, to)) = (&frames[i], &frames[i + 1]);
+        Ok(interpolate_styles(from, to, (t - start) / (end - start)))
+    }
+
+    /// Merge any number of styles left to right, as `styles[0] | styles[1] | ...`,
+    /// without an intermediate Style per layer.
+    #[staticmethod]
+    #[pyo3(signature = (*styles))]
+    fn merge_all(styles: Vec<PyRef<'_, Style>>) -> Style {
+        let mut result = Style {
+            inner: taffy::Style::DEFAULT,
+            set_fields: 0,
+        };
+        for style in &styles {
+            copy_fields(&mut result.inner, &style.inner, style.set_fields);
+            result.set_fields |= style.set_fields;
+        }
+        result
+    }
+
+    /// The fields whose value or set-state differs between `self` and `other`,
+    /// marked set and holding `other`'s values.
+    fn diff(&self, other: &Style) -> Style {
+        let mask =
+            differing_fields(&self.inner, &other.inner) | (self.set_fields ^ other.set_fields);
+        let mut result = Style {
+            inner: taffy::Style::DEFAULT,
+            set_fields: mask,
+        };
+        copy_fields(&mut result.inner, &other.inner, mask);
+        result
+    }
+
+    /// Return a copy with the named fields reset to their defaults and marked unset.
+    #[pyo3(signature = (*names))]
+    fn unset(&self, names: Vec<String>) -> PyResult<Style> {
+        let mut mask = 0;
+        for name in &names {
+            mask |= field_flag(name)?;
+        }
+        let mut result = self.clone();
+        copy_fields(&mut result.inner, &taffy::Style::DEFAULT, mask);
+        result.set_fields &= !mask;
+        Ok(result)
+    }
+
+    fn __copy__(&self) -> Style {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Style {
+        self.clone()
+    }
+
+    /// Pickle via `from_dict(to_dict())`, which preserves the set-field mask.
+    fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyDict>,))> {
+        let from_dict = slf.get_type().getattr("from_dict")?;
+        Ok((from_dict, (slf.borrow().to_dict(slf.py())?,)))
+    }
+
+    /// Styles are equal when they set the same fields to the same values.
+    /// Unset fields always hold taffy's defaults, so comparing the full taffy style
+    /// alongside the mask is equivalent to comparing only the set fields.
+    fn __eq__(&self, other: &Style) -> bool {
+        self.set_fields == other.set_fields && self.inner == other.inner
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut h = std::collections::hash_map::DefaultHasher::new();
+        self.hash_into(&mut h);
+        std::hash::Hasher::finish(&h)
+    }
+
+    /// A deterministic 64-bit hash, identical across processes and platforms.
+    fn stable_hash(&self) -> u64 {
+        let mut h = StableHasher::default();
+        self.hash_into(&mut h);
+        std::hash::Hasher::finish(&h)
+    }
+
+    /// Lists only the explicitly set fields, e.g. `Style(display=Display.Flex, size_width=Length(100))`.
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let fields = self
+            .set_field_items(py, false)?
+            .into_iter()
+            .map(|(name, value)| Ok(format!("{name}={}", value.bind(py).repr()?)))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(format!("Style({})", fields.join(", ")))
+    }
+
+    /// `(name, value)` pairs for each explicitly set field, for the `rich` library.
+    fn __rich_repr__(&self, py: Python<'_>) -> PyResult<Vec<(&'static str, Py<PyAny>)>> {
+        self.set_field_items(py, false)
+    }
+
+    /// Merge two styles: `self | other`. Fields explicitly set in `other` override
+    /// those in `self`. Fields not set in `other` are preserved from `self`.
+    fn __or__(&self, other: &Style) -> Style {
+        // Fast paths: an empty overlay changes nothing, and a full overlay replaces everything.
+        if other.set_fields == 0 {
+            return self.clone();
+        }
+        if other.set_fields == F_ALL {
+            return other.clone();
+        }
+
+        let mut result = self.clone();
+        copy_fields(&mut result.inner, &other.inner, other.set_fields);
 
         // Merge the set_fields bitmask: result has everything self had plus everything other set.
-        result.set_fields |= rhs;
+        result.set_fields |= other.set_fields;
 
         result
     }
+
+    /// Common overrides: `self & other` keeps only the fields set in both styles
+    /// *to the same value*. Fields set in both but to different values are dropped.
+    fn __and__(&self, other: &Style) -> Style {
+        let mask =
+            self.set_fields & other.set_fields & !differing_fields(&self.inner, &other.inner);
+        let mut result = Style {
+            inner: taffy::Style::DEFAULT,
+            set_fields: mask,
+        };
+        copy_fields(&mut result.inner, &self.inner, mask);
+        result
+    }
 }
 
-impl From<&taffy::Style> for Style {
-    fn from(s: &taffy::Style) -> Self {
-        Self {
-            inner: s.clone(),
-            // When converting from a taffy::Style, we don't know which fields were
-            // explicitly set, so we mark all fields as set.
-            set_fields: u64::MAX,
+/// Problems with a single style that don't depend on where its node sits in the tree.
+pub(crate) fn style_issues(style: &taffy::Style) -> Vec<String> {
+    let mut issues = Vec::new();
+
+    if style.display != taffy::Display::Grid {
+        let grid_fields = [
+            ("grid_template_rows", style.grid_template_rows.is_empty()),
+            (
+                "grid_template_columns",
+                style.grid_template_columns.is_empty(),
+            ),
+            ("grid_auto_rows", style.grid_auto_rows.is_empty()),
+            ("grid_auto_columns", style.grid_auto_columns.is_empty()),
+            ("grid_template_areas", style.grid_template_areas.is_empty()),
+        ];
+        for (name, empty) in grid_fields {
+            if !empty {
+                issues.push(format!(
+                    "{name} has no effect unless display is Display.Grid"
+                ));
+            }
+        }
+    }
+
+    let axes = [
+        ("width", style.min_size.width, style.max_size.width),
+        ("height", style.min_size.height, style.max_size.height),
+    ];
+    for (axis, min, max) in axes {
+        let (min, max) = (min.into_raw(), max.into_raw());
+        if min.tag() == CompactLength::LENGTH_TAG
+            && max.tag() == CompactLength::LENGTH_TAG
+            && min.value() > max.value()
+        {
+            issues.push(format!(
+                "min_size_{axis} ({}) is larger than max_size_{axis} ({})",
+                min.value(),
+                max.value()
+            ));
         }
     }
+
+    issues.extend(value_issues(style));
+
+    issues
+}
+
+/// Numeric fields outside their valid range: `flex_grow`, `flex_shrink` and
+/// `scrollbar_width` must be non-negative and finite, and `aspect_ratio` positive and finite.
+/// Unset fields hold valid defaults, so checking every field only reports set ones.
+pub(crate) fn value_issues(style: &taffy::Style) -> Vec<String> {
+    let mut issues = Vec::new();
+    for (name, value) in [
+        ("flex_grow", style.flex_grow),
+        ("flex_shrink", style.flex_shrink),
+        ("scrollbar_width", style.scrollbar_width),
+    ] {
+        if !(value.is_finite() && value >= 0.0) {
+            issues.push(format!(
+                "{name} must be non-negative and finite, got {value}"
+            ));
+        }
+    }
+    if let Some(ratio) = style.aspect_ratio {
+        if !(ratio.is_finite() && ratio > 0.0) {
+            issues.push(format!(
+                "aspect_ratio must be positive and finite, got {ratio}"
+            ));
+        }
+    }
+    issues
+}
+
+fn check_t(t: f32) -> PyResult<()> {
+    if t.is_nan() {
+        return Err(PyValueError::new_err(
+            "interpolation parameter t must not be NaN",
+        ));
+    }
+    Ok(())
+}
+
+fn check_aspect_ratio(ratio: f32) -> PyResult<()> {
+    if !(ratio.is_finite() && ratio > 0.0) {
+        return Err(PyValueError::new_err(format!(
+            "aspect_ratio must be positive and finite, got {ratio}"
+        )));
+    }
+    Ok(())
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Interpolate between two styles at `t` (clamped to `[0, 1]`).
+///
+/// Fields set in both styles are interpolated when numeric (plain floats, and lengths or
+/// percentages of matching units); other shared fields switch from `a` to `b` at `t = 0.5`.
+/// Fields set in only one style are taken from that style.
+fn interpolate_styles(a: &Style, b: &Style, t: f32) -> Style {
+    let t = t.clamp(0.0, 1.0);
+    let both = a.set_fields & b.set_fields;
+    let mut result = a.clone();
+    copy_fields(&mut result.inner, &b.inner, b.set_fields & !a.set_fields);
+    if t >= 0.5 {
+        copy_fields(&mut result.inner, &b.inner, both);
+    }
+    result.set_fields = a.set_fields | b.set_fields;
+
+    let (x, y, out) = (&a.inner, &b.inner, &mut result.inner);
+
+    macro_rules! lerp_f32 {
+        ($flag:expr, $field:ident) => {
+            if both & $flag != 0 {
+                out.$field = lerp(x.$field, y.$field, t);
+            }
+        };
+    }
+
+    // Lengths interpolate with lengths and percentages with percentages; anything
+    // else (auto, calc, or mixed units) keeps the discrete pick made above.
+    macro_rules! lerp_length {
+        ($flag:expr, $ty:ty, $($path:ident).+) => {
+            if both & $flag != 0 {
+                let (from, to) = (x.$($path).+.into_raw(), y.$($path).+.into_raw());
+                if from.tag() == to.tag() {
+                    let value = lerp(from.value(), to.value(), t);
+                    match from.tag() {
+                        CompactLength::LENGTH_TAG => out.$($path).+ = <$ty>::length(value),
+                        CompactLength::PERCENT_TAG => out.$($path).+ = <$ty>::percent(value),
+                        _ => {}
+                    }
+                }
+            }
+        };
+    }
+
+    lerp_f32!(F_SCROLLBAR_WIDTH, scrollbar_width);
+    lerp_f32!(F_FLEX_GROW, flex_grow);
+    lerp_f32!(F_FLEX_SHRINK, flex_shrink);
+    if both & F_ASPECT_RATIO != 0 {
+        if let (Some(from), Some(to)) = (x.aspect_ratio, y.aspect_ratio) {
+            out.aspect_ratio = Some(lerp(from, to, t));
+        }
+    }
+
+    lerp_length!(F_INSET_LEFT, LengthPercentageAuto, inset.left);
+    lerp_length!(F_INSET_RIGHT, LengthPercentageAuto, inset.right);
+    lerp_length!(F_INSET_TOP, LengthPercentageAuto, inset.top);
+    lerp_length!(F_INSET_BOTTOM, LengthPercentageAuto, inset.bottom);
+    lerp_length!(F_SIZE_WIDTH, Dimension, size.width);
+    lerp_length!(F_SIZE_HEIGHT, Dimension, size.height);
+    lerp_length!(F_MIN_SIZE_WIDTH, Dimension, min_size.width);
+    lerp_length!(F_MIN_SIZE_HEIGHT, Dimension, min_size.height);
+    lerp_length!(F_MAX_SIZE_WIDTH, Dimension, max_size.width);
+    lerp_length!(F_MAX_SIZE_HEIGHT, Dimension, max_size.height);
+    lerp_length!(F_MARGIN_LEFT, LengthPercentageAuto, margin.left);
+    lerp_length!(F_MARGIN_RIGHT, LengthPercentageAuto, margin.right);
+    lerp_length!(F_MARGIN_TOP, LengthPercentageAuto, margin.top);
+    lerp_length!(F_MARGIN_BOTTOM, LengthPercentageAuto, margin.bottom);
+    lerp_length!(F_PADDING_LEFT, LengthPercentage, padding.left);
+    lerp_length!(F_PADDING_RIGHT, LengthPercentage, padding.right);
+    lerp_length!(F_PADDING_TOP, LengthPercentage, padding.top);
+    lerp_length!(F_PADDING_BOTTOM, LengthPercentage, padding.bottom);
+    lerp_length!(F_BORDER_LEFT, LengthPercentage, border.left);
+    lerp_length!(F_BORDER_RIGHT, LengthPercentage, border.right);
+    lerp_length!(F_BORDER_TOP, LengthPercentage, border.top);
+    lerp_length!(F_BORDER_BOTTOM, LengthPercentage, border.bottom);
+    lerp_length!(F_GAP_WIDTH, LengthPercentage, gap.width);
+    lerp_length!(F_GAP_HEIGHT, LengthPercentage, gap.height);
+    lerp_length!(F_FLEX_BASIS, Dimension, flex_basis);
+
+    result
+}
+
+/// Copy every field whose bit is in `mask` from `src` into `dst`.
+fn copy_fields(dst: &mut taffy::Style, src: &taffy::Style, mask: u64) {
+    macro_rules! merge {
+        // Copy field: dst.$($path).+ = src.$($path).+
+        ($flag:expr, $($path:ident).+) => {
+            if mask & $flag != 0 {
+                dst.$($path).+ = src.$($path).+;
+            }
+        };
+        // Clone field (for non-Copy types like Vec)
+        (clone $flag:expr, $($path:ident).+) => {
+            if mask & $flag != 0 {
+                dst.$($path).+ = src.$($path).+.clone();
+            }
+        };
+    }
+
+    merge!(F_DISPLAY, display);
+    merge!(F_BOX_SIZING, box_sizing);
+    merge!(F_OVERFLOW_X, overflow.x);
+    merge!(F_OVERFLOW_Y, overflow.y);
+    merge!(F_SCROLLBAR_WIDTH, scrollbar_width);
+    merge!(F_POSITION, position);
+
+    // Inset
+    merge!(F_INSET_LEFT, inset.left);
+    merge!(F_INSET_RIGHT, inset.right);
+    merge!(F_INSET_TOP, inset.top);
+    merge!(F_INSET_BOTTOM, inset.bottom);
+
+    // Size
+    merge!(F_SIZE_WIDTH, size.width);
+    merge!(F_SIZE_HEIGHT, size.height);
+    merge!(F_MIN_SIZE_WIDTH, min_size.width);
+    merge!(F_MIN_SIZE_HEIGHT, min_size.height);
+    merge!(F_MAX_SIZE_WIDTH, max_size.width);
+    merge!(F_MAX_SIZE_HEIGHT, max_size.height);
+    merge!(F_ASPECT_RATIO, aspect_ratio);
+
+    // Margin
+    merge!(F_MARGIN_LEFT, margin.left);
+    merge!(F_MARGIN_RIGHT, margin.right);
+    merge!(F_MARGIN_TOP, margin.top);
+    merge!(F_MARGIN_BOTTOM, margin.bottom);
+
+    // Padding
+    merge!(F_PADDING_LEFT, padding.left);
+    merge!(F_PADDING_RIGHT, padding.right);
+    merge!(F_PADDING_TOP, padding.top);
+    merge!(F_PADDING_BOTTOM, padding.bottom);
+
+    // Border
+    merge!(F_BORDER_LEFT, border.left);
+    merge!(F_BORDER_RIGHT, border.right);
+    merge!(F_BORDER_TOP, border.top);
+    merge!(F_BORDER_BOTTOM, border.bottom);
+
+    // Alignment
+    merge!(F_ALIGN_ITEMS, align_items);
+    merge!(F_ALIGN_SELF, align_self);
+    merge!(F_JUSTIFY_ITEMS, justify_items);
+    merge!(F_JUSTIFY_SELF, justify_self);
+    merge!(F_ALIGN_CONTENT, align_content);
+    merge!(F_JUSTIFY_CONTENT, justify_content);
+
+    // Gap
+    merge!(F_GAP_WIDTH, gap.width);
+    merge!(F_GAP_HEIGHT, gap.height);
+
+    // Block
+    merge!(F_TEXT_ALIGN, text_align);
+
+    // Flexbox
+    merge!(F_FLEX_DIRECTION, flex_direction);
+    merge!(F_FLEX_WRAP, flex_wrap);
+    merge!(F_FLEX_BASIS, flex_basis);
+    merge!(F_FLEX_GROW, flex_grow);
+    merge!(F_FLEX_SHRINK, flex_shrink);
+
+    // Grid
+    merge!(clone F_GRID_TEMPLATE_ROWS, grid_template_rows);
+    merge!(clone F_GRID_TEMPLATE_COLUMNS, grid_template_columns);
+    merge!(clone F_GRID_AUTO_ROWS, grid_auto_rows);
+    merge!(clone F_GRID_AUTO_COLUMNS, grid_auto_columns);
+    merge!(F_GRID_AUTO_FLOW, grid_auto_flow);
+    merge!(clone F_GRID_ROW, grid_row);
+    merge!(clone F_GRID_COLUMN, grid_column);
+}
+
+/// The mask of fields whose values differ between `a` and `b`.
+fn differing_fields(a: &taffy::Style, b: &taffy::Style) -> u64 {
+    let mut mask = 0;
+    macro_rules! differ {
+        ($flag:expr, $($path:ident).+) => {
+            if a.$($path).+ != b.$($path).+ {
+                mask |= $flag;
+            }
+        };
+    }
+
+    differ!(F_DISPLAY, display);
+    differ!(F_BOX_SIZING, box_sizing);
+    differ!(F_OVERFLOW_X, overflow.x);
+    differ!(F_OVERFLOW_Y, overflow.y);
+    differ!(F_SCROLLBAR_WIDTH, scrollbar_width);
+    differ!(F_POSITION, position);
+
+    // Inset
+    differ!(F_INSET_LEFT, inset.left);
+    differ!(F_INSET_RIGHT, inset.right);
+    differ!(F_INSET_TOP, inset.top);
+    differ!(F_INSET_BOTTOM, inset.bottom);
+
+    // Size
+    differ!(F_SIZE_WIDTH, size.width);
+    differ!(F_SIZE_HEIGHT, size.height);
+    differ!(F_MIN_SIZE_WIDTH, min_size.width);
+    differ!(F_MIN_SIZE_HEIGHT, min_size.height);
+    differ!(F_MAX_SIZE_WIDTH, max_size.width);
+    differ!(F_MAX_SIZE_HEIGHT, max_size.height);
+    differ!(F_ASPECT_RATIO, aspect_ratio);
+
+    // Margin
+    differ!(F_MARGIN_LEFT, margin.left);
+    differ!(F_MARGIN_RIGHT, margin.right);
+    differ!(F_MARGIN_TOP, margin.top);
+    differ!(F_MARGIN_BOTTOM, margin.bottom);
+
+    // Padding
+    differ!(F_PADDING_LEFT, padding.left);
+    differ!(F_PADDING_RIGHT, padding.right);
+    differ!(F_PADDING_TOP, padding.top);
+    differ!(F_PADDING_BOTTOM, padding.bottom);
+
+    // Border
+    differ!(F_BORDER_LEFT, border.left);
+    differ!(F_BORDER_RIGHT, border.right);
+    differ!(F_BORDER_TOP, border.top);
+    differ!(F_BORDER_BOTTOM, border.bottom);
+
+    // Alignment
+    differ!(F_ALIGN_ITEMS, align_items);
+    differ!(F_ALIGN_SELF, align_self);
+    differ!(F_JUSTIFY_ITEMS, justify_items);
+    differ!(F_JUSTIFY_SELF, justify_self);
+    differ!(F_ALIGN_CONTENT, align_content);
+    differ!(F_JUSTIFY_CONTENT, justify_content);
+
+    // Gap
+    differ!(F_GAP_WIDTH, gap.width);
+    differ!(F_GAP_HEIGHT, gap.height);
+
+    // Block
+    differ!(F_TEXT_ALIGN, text_align);
+
+    // Flexbox
+    differ!(F_FLEX_DIRECTION, flex_direction);
+    differ!(F_FLEX_WRAP, flex_wrap);
+    differ!(F_FLEX_BASIS, flex_basis);
+    differ!(F_FLEX_GROW, flex_grow);
+    differ!(F_FLEX_SHRINK, flex_shrink);
+
+    // Grid
+    differ!(F_GRID_TEMPLATE_ROWS, grid_template_rows);
+    differ!(F_GRID_TEMPLATE_COLUMNS, grid_template_columns);
+    differ!(F_GRID_AUTO_ROWS, grid_auto_rows);
+    differ!(F_GRID_AUTO_COLUMNS, grid_auto_columns);
+    differ!(F_GRID_AUTO_FLOW, grid_auto_flow);
+    differ!(F_GRID_ROW, grid_row);
+    differ!(F_GRID_COLUMN, grid_column);
+
+    mask
+}
+
+/// 64-bit FNV-1a. Unlike `DefaultHasher`, whose algorithm may change between Rust
+/// releases, its output is fixed; integers are written little-endian and `usize`/`isize`
+/// as 64 bits so the result is also the same on every platform.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl std::hash::Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+/// How many distinct styles `intern_style` remembers per thread before evicting the
+/// least recently used one.
+const INTERN_CAPACITY: usize = 4096;
+
+/// An LRU table of canonical styles.
+#[derive(Default)]
+struct InternTable {
+    /// Advanced on every lookup; each entry records the tick it was last used at.
+    tick: u64,
+    /// Canonical instances bucketed by `Style.__hash__`, with their last-used ticks.
+    buckets: std::collections::HashMap<u64, Vec<(Py<Style>, u64)>>,
+    /// The bucket of each entry, keyed by its last-used tick, so the oldest comes first.
+    recency: std::collections::BTreeMap<u64, u64>,
+}
+
+impl InternTable {
+    /// Drop the least recently used entry.
+    fn evict_oldest(&mut self) {
+        let Some((tick, key)) = self.recency.pop_first() else {
+            return;
+        };
+        if let Some(bucket) = self.buckets.get_mut(&key) {
+            bucket.retain(|(_, used)| *used != tick);
+            if bucket.is_empty() {
+                self.buckets.remove(&key);
+            }
+        }
+    }
+}
+
+thread_local! {
+    static INTERNED_STYLES: std::cell::RefCell<InternTable> = std::cell::RefCell::default();
+}
+
+/// Return a canonical instance for `style`: equal styles interned on the same thread
+/// return the same object.
+#[pyfunction]
+fn intern_style(py: Python<'_>, style: Bound<'_, Style>) -> Py<Style> {
+    let candidate = style.borrow();
+    let key = candidate.__hash__();
+    INTERNED_STYLES.with_borrow_mut(|table| {
+        table.tick += 1;
+        let tick = table.tick;
+        if let Some((existing, used)) = table.buckets.get_mut(&key).and_then(|bucket| {
+            bucket
+                .iter_mut()
+                .find(|(s, _)| s.bind(py).borrow().__eq__(&candidate))
+        }) {
+            table.recency.remove(used);
+            *used = tick;
+            table.recency.insert(tick, key);
+            return existing.clone_ref(py);
+        }
+        if table.recency.len() >= INTERN_CAPACITY {
+            table.evict_oldest();
+        }
+        table
+            .buckets
+            .entry(key)
+            .or_default()
+            .push((style.clone().unbind(), tick));
+        table.recency.insert(tick, key);
+        style.unbind()
+    })
 }
 
 pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Style>()?;
+    m.add_function(wrap_pyfunction!(intern_style, m)?)?;
     Ok(())
 }
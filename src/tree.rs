@@ -1,17 +1,508 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use taffy::prelude as tp;
 use taffy::TraversePartialTree;
 
-use crate::errors::{catch_node_panic, catch_panic, taffy_error_to_py};
-use crate::geometry::{AvailableSize, KnownSize};
+use crate::errors::{
+    catch_node_panic, catch_panic, taffy_error_to_py, CycleDetected, InvalidNodeId, WrongTree,
+};
+use crate::geometry::{
+    AvailableSize, AvailableSizeInput, KnownSize, MeasureResult, Point, Rect, Size,
+};
 use crate::layout::Layout;
 use crate::node::NodeId;
-use crate::style::Style;
+use crate::style::{style_issues, Style};
 
 /// A tree of layout nodes.
 #[pyclass(unsendable, module = "waxy")]
 pub struct TaffyTree {
-    inner: tp::TaffyTree<Py<PyAny>>,
+    inner: tp::TaffyTree<NodeContext>,
+    /// Unique per tree; stamped on every `NodeId` this tree hands out.
+    id: u64,
+    /// The id of the tree this one was cloned from, and the nodes it had at the time,
+    /// so `translate` can accept that tree's `NodeId`s.
+    cloned_from: Option<(u64, HashSet<taffy::NodeId>)>,
+    /// Called with a `NodeId` to produce contexts for leaves that lack one.
+    context_factory: Option<Py<PyAny>>,
+    /// Contexts produced by `context_factory`. They're kept out of taffy because attaching
+    /// a context there marks the node dirty, discarding the layout it was just measured for.
+    factory_contexts: HashMap<taffy::NodeId, Py<PyAny>>,
+    /// Counters from the most recent layout run.
+    last_stats: LayoutStats,
+    /// Mirrors taffy's rounding config, which it doesn't expose.
+    rounding: bool,
+    /// Every node in the tree; taffy has no way to enumerate them.
+    nodes: HashSet<taffy::NodeId>,
+    /// Debugging labels, kept separately from node contexts.
+    labels: HashMap<taffy::NodeId, String>,
+    /// Each node's `Style` set-field mask, which taffy's style has no room for.
+    set_fields: HashMap<taffy::NodeId, u64>,
+}
+
+/// A node's Python context. Cloning needs the interpreter to copy the reference, so it
+/// attaches for just that, letting `TaffyTree.clone` copy the taffy tree without enabling
+/// pyo3's crate-wide `py-clone` feature.
+struct NodeContext(Py<PyAny>);
+
+impl Clone for NodeContext {
+    fn clone(&self) -> Self {
+        Python::attach(|py| NodeContext(self.0.clone_ref(py)))
+    }
+}
+
+/// Diagnostics for a single layout run, reported by `last_layout_stats`.
+#[derive(Clone, Copy, Default)]
+struct LayoutStats {
+    /// Nodes without a cached layout when the run started.
+    nodes_laid_out: usize,
+    /// Calls into the Python measure function.
+    measure_calls: usize,
+}
+
+/// Source of unique `TaffyTree` ids.
+static NEXT_TREE_ID: AtomicU64 = AtomicU64::new(0);
+
+impl TaffyTree {
+    fn from_inner(inner: tp::TaffyTree<NodeContext>) -> Self {
+        Self {
+            inner,
+            id: NEXT_TREE_ID.fetch_add(1, Ordering::Relaxed),
+            cloned_from: None,
+            context_factory: None,
+            factory_contexts: HashMap::new(),
+            last_stats: LayoutStats::default(),
+            rounding: true,
+            nodes: HashSet::new(),
+            labels: HashMap::new(),
+            set_fields: HashMap::new(),
+        }
+    }
+
+    /// Record a newly created node with its style and wrap its id as a `NodeId`.
+    fn add_node(&mut self, id: taffy::NodeId, style: &Style) -> NodeId {
+        self.nodes.insert(id);
+        self.set_fields.insert(id, style.set_fields());
+        self.node_id(id)
+    }
+
+    /// Wrap a taffy node id as a `NodeId` belonging to this tree.
+    fn node_id(&self, id: taffy::NodeId) -> NodeId {
+        NodeId {
+            inner: id,
+            tree: self.id,
+        }
+    }
+
+    /// Raise `WrongTree` if `node` was created by a different tree.
+    fn check(&self, node: &NodeId) -> PyResult<()> {
+        if node.tree == self.id {
+            Ok(())
+        } else {
+            Err(WrongTree::new_err(format!(
+                "{} belongs to a different TaffyTree",
+                node.__repr__()
+            )))
+        }
+    }
+
+    /// Like `check`, but also raise `InvalidNodeId` if `node` has been removed, for
+    /// methods that only touch waxy-side state and so can't rely on taffy to notice.
+    fn check_present(&self, node: &NodeId) -> PyResult<()> {
+        self.check(node)?;
+        if self.nodes.contains(&node.inner) {
+            Ok(())
+        } else {
+            Err(InvalidNodeId::new_err(format!(
+                "node {} is not present in the tree (was it removed?)",
+                node.__repr__()
+            )))
+        }
+    }
+
+    /// Check every node in `nodes`, returning their taffy ids.
+    fn check_all(&self, nodes: &[NodeId]) -> PyResult<Vec<taffy::NodeId>> {
+        nodes
+            .iter()
+            .map(|n| self.check(n).map(|()| n.inner))
+            .collect()
+    }
+
+    /// Raise `CycleDetected` if `child` is `parent` or one of its ancestors.
+    fn check_acyclic(&self, parent: &NodeId, child: &NodeId) -> PyResult<()> {
+        let creates_cycle = catch_node_panic(parent, || {
+            std::iter::successors(Some(parent.inner), |&id| self.inner.parent(id))
+                .any(|id| id == child.inner)
+        })?;
+        if creates_cycle {
+            return Err(CycleDetected::new_err(format!(
+                "cannot add {} as a child of {}: it is that node or one of its ancestors",
+                child.__repr__(),
+                parent.__repr__()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Raise `ValueError` if `root` is dirty. Marking a node dirty also dirties its
+    /// ancestors, so this guarantees every layout under `root` is up to date too.
+    fn check_laid_out(&self, root: &NodeId) -> PyResult<()> {
+        if self.dirty(root)? {
+            return Err(PyValueError::new_err(format!(
+                "the layout of {} has not been computed; call compute_layout first",
+                root.__repr__()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Count the nodes under `root` (inclusive) with no cached layout. Marking a node dirty
+    /// also dirties its ancestors, so clean subtrees are skipped without being walked.
+    fn count_dirty(&self, root: &NodeId) -> PyResult<usize> {
+        let mut count = 0;
+        let mut stack = vec![root.inner];
+        while let Some(id) = stack.pop() {
+            if !catch_node_panic(root, || self.inner.dirty(id))?.map_err(taffy_error_to_py)? {
+                continue;
+            }
+            count += 1;
+            stack.extend(
+                catch_node_panic(root, || self.inner.children(id))?.map_err(taffy_error_to_py)?,
+            );
+        }
+        Ok(count)
+    }
+
+    /// Compute the layout of the tree rooted at `node`, calling `measure` for leaves with a context.
+    fn run_layout(
+        &mut self,
+        py: Python<'_>,
+        node: &NodeId,
+        available: Option<&AvailableSizeInput>,
+        measure: Option<Py<PyAny>>,
+    ) -> PyResult<()> {
+        let avail: taffy::Size<taffy::AvailableSpace> =
+            available.map(|a| a.into()).unwrap_or(taffy::Size {
+                width: taffy::AvailableSpace::MaxContent,
+                height: taffy::AvailableSpace::MaxContent,
+            });
+
+        self.last_stats = LayoutStats {
+            nodes_laid_out: self.count_dirty(node)?,
+            measure_calls: 0,
+        };
+
+        match measure {
+            None => catch_panic(|| self.inner.compute_layout(node.inner, avail))?
+                .map_err(taffy_error_to_py),
+            Some(measure_fn) => {
+                // py_err lives outside catch_unwind so it survives a panic unwind.
+                let py_err: std::cell::RefCell<Option<PyErr>> = std::cell::RefCell::new(None);
+                let measure_calls = std::cell::Cell::new(0);
+                let tree_id = self.id;
+                // Factory results from this run (including None), so each leaf is asked once.
+                let produced: std::cell::RefCell<HashMap<taffy::NodeId, Option<Py<PyAny>>>> =
+                    std::cell::RefCell::default();
+                let factory = self.context_factory.as_ref();
+                let factory_contexts = &self.factory_contexts;
+
+                let result = catch_panic(|| {
+                    self.inner.compute_layout_with_measure(
+                        node.inner,
+                        avail,
+                        |known,
+                         available,
+                         node_id,
+                         node_context: Option<&mut NodeContext>,
+                         style| {
+                            // If we already have a Python error, short-circuit.
+                            if py_err.borrow().is_some() {
+                                return taffy::Size::ZERO;
+                            }
+
+                            // If both dimensions are already known, return them directly.
+                            if let taffy::Size {
+                                width: Some(w),
+                                height: Some(h),
+                            } = known
+                            {
+                                return taffy::Size {
+                                    width: w,
+                                    height: h,
+                                };
+                            }
+
+                            let context = match node_context {
+                                Some(context) => context.0.clone_ref(py),
+                                None => {
+                                    let context = match (factory_contexts.get(&node_id), factory) {
+                                        (Some(context), _) => Ok(Some(context.clone_ref(py))),
+                                        (None, Some(factory)) if !has_fixed_size(style) => {
+                                            produce_context(
+                                                py,
+                                                factory,
+                                                &produced,
+                                                NodeId {
+                                                    inner: node_id,
+                                                    tree: tree_id,
+                                                },
+                                            )
+                                        }
+                                        // No context to measure, so don't bother calling Python.
+                                        (None, _) => Ok(None),
+                                    };
+                                    match context {
+                                        Ok(Some(context)) => context,
+                                        Ok(None) => return taffy::Size::ZERO,
+                                        Err(e) => {
+                                            *py_err.borrow_mut() = Some(e);
+                                            return taffy::Size::ZERO;
+                                        }
+                                    }
+                                }
+                            };
+
+                            // Convert to Python types and call the measure function.
+                            let py_known = KnownSize::from(known);
+                            let py_avail = AvailableSize::from(available);
+
+                            measure_calls.set(measure_calls.get() + 1);
+                            let call_result = measure_fn.call1(py, (py_known, py_avail, context));
+
+                            match call_result {
+                                Err(e) => {
+                                    *py_err.borrow_mut() = Some(e);
+                                    taffy::Size::ZERO
+                                }
+                                Ok(result) => match measured_size(
+                                    result.bind(py),
+                                    &NodeId {
+                                        inner: node_id,
+                                        tree: tree_id,
+                                    },
+                                ) {
+                                    Ok(size) => size,
+                                    Err(e) => {
+                                        *py_err.borrow_mut() = Some(e);
+                                        taffy::Size::ZERO
+                                    }
+                                },
+                            }
+                        },
+                    )
+                });
+
+                self.last_stats.measure_calls = measure_calls.get();
+                self.factory_contexts.extend(
+                    produced
+                        .into_inner()
+                        .into_iter()
+                        .filter_map(|(id, context)| Some((id, context?))),
+                );
+
+                // Priority: Python errors first, then panics, then taffy errors.
+                if let Some(e) = py_err.into_inner() {
+                    return Err(e);
+                }
+                result?.map_err(taffy_error_to_py)
+            }
+        }
+    }
+}
+
+/// Whether `style` fixes a node's size in both axes, so its content never decides it.
+fn has_fixed_size(style: &taffy::Style) -> bool {
+    style.size.width.into_option().is_some() && style.size.height.into_option().is_some()
+}
+
+/// The context `factory` produces for `node`, asking it at most once per layout run.
+fn produce_context(
+    py: Python<'_>,
+    factory: &Py<PyAny>,
+    produced: &std::cell::RefCell<HashMap<taffy::NodeId, Option<Py<PyAny>>>>,
+    node: NodeId,
+) -> PyResult<Option<Py<PyAny>>> {
+    if let Some(context) = produced.borrow().get(&node.inner) {
+        return Ok(context.as_ref().map(|c| c.clone_ref(py)));
+    }
+    let id = node.inner;
+    let context = factory.call1(py, (node,))?;
+    let context = (!context.is_none(py)).then_some(context);
+    produced
+        .borrow_mut()
+        .insert(id, context.as_ref().map(|c| c.clone_ref(py)));
+    Ok(context)
+}
+
+/// Append one line per node to `out`, mirroring taffy's `print_tree` output.
+fn format_node(
+    tree: &tp::TaffyTree<NodeContext>,
+    labels: &HashMap<taffy::NodeId, String>,
+    node: taffy::NodeId,
+    has_sibling: bool,
+    lines: &str,
+    out: &mut String,
+) {
+    use std::fmt::Write;
+    use taffy::PrintTree;
+
+    let layout = tree.get_final_layout(node);
+    let fork = if has_sibling {
+        "├── "
+    } else {
+        "└── "
+    };
+    // Writing to a String can't fail.
+    let _ = write!(
+        out,
+        "\n{lines}{fork} {display}{label} [x: {x:<4} y: {y:<4} w: {width:<4} h: {height:<4} \
+         content_w: {content_width:<4} content_h: {content_height:<4} \
+         border: l:{bl} r:{br} t:{bt} b:{bb}, padding: l:{pl} r:{pr} t:{pt} b:{pb}] ({node:?})",
+        display = tree.get_debug_label(node),
+        label = labels
+            .get(&node)
+            .map(|label| format!(" {label:?}"))
+            .unwrap_or_default(),
+        x = layout.location.x,
+        y = layout.location.y,
+        width = layout.size.width,
+        height = layout.size.height,
+        content_width = layout.content_size.width,
+        content_height = layout.content_size.height,
+        bl = layout.border.left,
+        br = layout.border.right,
+        bt = layout.border.top,
+        bb = layout.border.bottom,
+        pl = layout.padding.left,
+        pr = layout.padding.right,
+        pt = layout.padding.top,
+        pb = layout.padding.bottom,
+    );
+
+    let child_lines = format!("{lines}{}", if has_sibling { "│   " } else { "    " });
+    let num_children = tree.child_count(node);
+    for (index, child) in tree.child_ids(node).enumerate() {
+        format_node(
+            tree,
+            labels,
+            child,
+            index < num_children - 1,
+            &child_lines,
+            out,
+        );
+    }
+}
+
+/// Append the SVG elements for one node (whose `layout.location` is absolute) to `out`.
+fn svg_node(out: &mut String, id: u64, layout: &Layout) {
+    use std::fmt::Write;
+
+    let border_box = layout.border_box();
+    let padding_box = layout.padding_box();
+    for (class, rect, stroke) in [
+        ("border-box", &border_box, "stroke=\"black\""),
+        (
+            "padding-box",
+            &padding_box,
+            "stroke=\"steelblue\" stroke-dasharray=\"4 2\"",
+        ),
+    ] {
+        // Writing to a String can't fail.
+        let _ = writeln!(
+            out,
+            "<rect class=\"{class}\" x=\"{x}\" y=\"{y}\" width=\"{w}\" height=\"{h}\" fill=\"none\" {stroke}/>",
+            x = rect.left,
+            y = rect.top,
+            w = (rect.right - rect.left).max(0.0),
+            h = (rect.bottom - rect.top).max(0.0),
+        );
+    }
+    let _ = writeln!(
+        out,
+        "<text x=\"{x}\" y=\"{y}\" dominant-baseline=\"hanging\">{id}</text>",
+        x = padding_box.left + 1.0,
+        y = padding_box.top + 1.0,
+    );
+}
+
+/// Problems with how a child's style fits its parent's layout mode.
+fn placement_issues(style: &taffy::Style, parent: &taffy::Style) -> Vec<String> {
+    let mut issues = Vec::new();
+    let placements = [
+        (
+            "grid_row",
+            &style.grid_row,
+            "grid_template_rows",
+            &parent.grid_template_rows,
+        ),
+        (
+            "grid_column",
+            &style.grid_column,
+            "grid_template_columns",
+            &parent.grid_template_columns,
+        ),
+    ];
+    for (name, line, template_name, template) in placements {
+        let placed = [&line.start, &line.end];
+        if parent.display != taffy::Display::Grid {
+            if placed
+                .iter()
+                .any(|p| !matches!(p, taffy::GridPlacement::Auto))
+            {
+                issues.push(format!(
+                    "{name} has no effect because the parent is not a grid container"
+                ));
+            }
+        } else if template.is_empty()
+            && placed
+                .iter()
+                .any(|p| matches!(p, taffy::GridPlacement::Line(_)))
+        {
+            issues.push(format!(
+                "{name} is placed on numbered lines, but the parent has no {template_name}, \
+                 so the item lands in implicit tracks"
+            ));
+        }
+    }
+
+    if parent.display != taffy::Display::Flex {
+        if style.flex_grow != 0.0 {
+            issues
+                .push("flex_grow has no effect because the parent is not a flex container".into());
+        }
+        if style.flex_basis != taffy::Dimension::auto() {
+            issues
+                .push("flex_basis has no effect because the parent is not a flex container".into());
+        }
+    }
+
+    issues
+}
+
+/// Convert a measure function's return value (a `Size` or `MeasureResult`) into a taffy size.
+///
+/// taffy's measure functions can't report baselines, so `MeasureResult.first_baseline`
+/// is not forwarded.
+fn measured_size(result: &Bound<'_, PyAny>, node: &NodeId) -> PyResult<taffy::Size<f32>> {
+    let size = if let Ok(size) = result.cast::<Size>() {
+        size.get().clone()
+    } else if let Ok(measured) = result.cast::<MeasureResult>() {
+        measured.get().size.clone()
+    } else {
+        let got = if result.is_none() {
+            "None".to_string()
+        } else {
+            result.get_type().name()?.to_string()
+        };
+        return Err(PyTypeError::new_err(format!(
+            "measure function must return Size or MeasureResult, not {got} (measuring {})",
+            node.__repr__()
+        )));
+    };
+    Ok((&size).into())
 }
 
 #[pymethods]
@@ -19,58 +510,116 @@ impl TaffyTree {
     /// Create a new empty layout tree.
     #[new]
     fn new() -> Self {
-        Self {
-            inner: tp::TaffyTree::new(),
-        }
+        Self::from_inner(tp::TaffyTree::new())
     }
 
     /// Create a new layout tree with pre-allocated capacity.
     #[staticmethod]
     fn with_capacity(capacity: usize) -> Self {
-        Self {
-            inner: tp::TaffyTree::with_capacity(capacity),
-        }
+        Self::from_inner(tp::TaffyTree::with_capacity(capacity))
     }
 
     /// Create a new leaf node with the given style.
     fn new_leaf(&mut self, style: &Style) -> PyResult<NodeId> {
         self.inner
             .new_leaf(style.to_taffy())
-            .map(NodeId::from)
+            .map(|id| self.add_node(id, style))
             .map_err(taffy_error_to_py)
     }
 
     /// Create a new leaf node with the given style and context.
     fn new_leaf_with_context(&mut self, style: &Style, context: Py<PyAny>) -> PyResult<NodeId> {
         self.inner
-            .new_leaf_with_context(style.to_taffy(), context)
-            .map(NodeId::from)
+            .new_leaf_with_context(style.to_taffy(), NodeContext(context))
+            .map(|id| self.add_node(id, style))
             .map_err(taffy_error_to_py)
     }
 
+    /// Create a leaf node for each style, returning their ids in order.
+    fn new_leaves(&mut self, styles: Vec<PyRef<'_, Style>>) -> PyResult<Vec<NodeId>> {
+        styles.iter().map(|style| self.new_leaf(style)).collect()
+    }
+
+    /// Create a leaf node for each `(style, context)` pair, returning their ids in order.
+    fn new_leaves_with_context(
+        &mut self,
+        pairs: Vec<(PyRef<'_, Style>, Py<PyAny>)>,
+    ) -> PyResult<Vec<NodeId>> {
+        pairs
+            .into_iter()
+            .map(|(style, context)| self.new_leaf_with_context(&style, context))
+            .collect()
+    }
+
     /// Get the context attached to a node, if any.
-    fn get_node_context(&self, py: Python<'_>, node: &NodeId) -> Option<Py<PyAny>> {
-        self.inner
+    fn get_node_context(&self, py: Python<'_>, node: &NodeId) -> PyResult<Option<Py<PyAny>>> {
+        self.check(node)?;
+        Ok(self
+            .inner
             .get_node_context(node.inner)
-            .map(|ctx| ctx.clone_ref(py))
+            .map(|ctx| &ctx.0)
+            .or_else(|| self.factory_contexts.get(&node.inner))
+            .map(|ctx| ctx.clone_ref(py)))
     }
 
     /// Set or clear the context attached to a node.
     fn set_node_context(&mut self, node: &NodeId, context: Option<Py<PyAny>>) -> PyResult<()> {
-        catch_node_panic(node, || self.inner.set_node_context(node.inner, context))?
-            .map_err(taffy_error_to_py)
+        self.check(node)?;
+        catch_node_panic(node, || {
+            self.inner
+                .set_node_context(node.inner, context.map(NodeContext))
+        })?
+        .map_err(taffy_error_to_py)?;
+        self.factory_contexts.remove(&node.inner);
+        Ok(())
+    }
+
+    /// Get the debugging label attached to a node, if any.
+    fn node_label(&self, node: &NodeId) -> PyResult<Option<String>> {
+        self.check_present(node)?;
+        Ok(self.labels.get(&node.inner).cloned())
+    }
+
+    /// Set or clear the debugging label attached to a node.
+    fn set_node_label(&mut self, node: &NodeId, label: Option<String>) -> PyResult<()> {
+        self.check_present(node)?;
+        match label {
+            Some(label) => self.labels.insert(node.inner, label),
+            None => self.labels.remove(&node.inner),
+        };
+        Ok(())
+    }
+
+    /// Set or clear the factory used to produce contexts for leaves that lack one.
+    fn set_context_factory(&mut self, factory: Option<Py<PyAny>>) {
+        self.context_factory = factory;
     }
 
     /// Create a new node with children.
     fn new_with_children(&mut self, style: &Style, children: Vec<NodeId>) -> PyResult<NodeId> {
-        let child_ids: Vec<taffy::NodeId> = children.iter().map(|c| c.inner).collect();
+        let child_ids = self.check_all(&children)?;
         catch_panic(|| self.inner.new_with_children(style.to_taffy(), &child_ids))?
-            .map(NodeId::from)
+            .map(|id| self.add_node(id, style))
             .map_err(taffy_error_to_py)
     }
 
+    /// Create a container node and call `builder(tree, node)` to populate its children.
+    fn component(
+        slf: &Bound<'_, Self>,
+        style: &Style,
+        builder: &Bound<'_, PyAny>,
+    ) -> PyResult<NodeId> {
+        // Release the borrow before calling back into Python, which will mutate the tree.
+        let node = slf.borrow_mut().new_leaf(style)?;
+        builder.call1((slf, node.clone()))?;
+        Ok(node)
+    }
+
     /// Add a child to a parent node.
     fn add_child(&mut self, parent: &NodeId, child: &NodeId) -> PyResult<()> {
+        self.check(parent)?;
+        self.check(child)?;
+        self.check_acyclic(parent, child)?;
         catch_panic(|| self.inner.add_child(parent.inner, child.inner))?.map_err(taffy_error_to_py)
     }
 
@@ -81,6 +630,9 @@ impl TaffyTree {
         child_index: usize,
         child: &NodeId,
     ) -> PyResult<()> {
+        self.check(parent)?;
+        self.check(child)?;
+        self.check_acyclic(parent, child)?;
         catch_panic(|| {
             self.inner
                 .insert_child_at_index(parent.inner, child_index, child.inner)
@@ -90,24 +642,31 @@ impl TaffyTree {
 
     /// Set the children of a node, replacing any existing children.
     fn set_children(&mut self, parent: &NodeId, children: Vec<NodeId>) -> PyResult<()> {
-        let child_ids: Vec<taffy::NodeId> = children.iter().map(|c| c.inner).collect();
+        self.check(parent)?;
+        let child_ids = self.check_all(&children)?;
+        for child in &children {
+            self.check_acyclic(parent, child)?;
+        }
         catch_panic(|| self.inner.set_children(parent.inner, &child_ids))?
             .map_err(taffy_error_to_py)
     }
 
     /// Remove a specific child from a parent.
     fn remove_child(&mut self, parent: &NodeId, child: &NodeId) -> PyResult<NodeId> {
+        self.check(parent)?;
+        self.check(child)?;
         catch_panic(|| self.inner.remove_child(parent.inner, child.inner))?
-            .map(NodeId::from)
+            .map(|id| self.node_id(id))
             .map_err(taffy_error_to_py)
     }
 
     /// Remove a child at a specific index.
     fn remove_child_at_index(&mut self, parent: &NodeId, child_index: usize) -> PyResult<NodeId> {
+        self.check(parent)?;
         catch_node_panic(parent, || {
             self.inner.remove_child_at_index(parent.inner, child_index)
         })?
-        .map(NodeId::from)
+        .map(|id| self.node_id(id))
         .map_err(taffy_error_to_py)
     }
 
@@ -118,38 +677,125 @@ impl TaffyTree {
         child_index: usize,
         new_child: &NodeId,
     ) -> PyResult<NodeId> {
+        self.check(parent)?;
+        self.check(new_child)?;
+        self.check_acyclic(parent, new_child)?;
         catch_panic(|| {
             self.inner
                 .replace_child_at_index(parent.inner, child_index, new_child.inner)
         })?
-        .map(NodeId::from)
+        .map(|id| self.node_id(id))
         .map_err(taffy_error_to_py)
     }
 
     /// Get the child at a specific index.
     fn child_at_index(&self, parent: &NodeId, child_index: usize) -> PyResult<NodeId> {
+        self.check(parent)?;
         catch_node_panic(parent, || {
             self.inner.child_at_index(parent.inner, child_index)
         })?
-        .map(NodeId::from)
+        .map(|id| self.node_id(id))
         .map_err(taffy_error_to_py)
     }
 
     /// Get all children of a node.
     fn children(&self, parent: &NodeId) -> PyResult<Vec<NodeId>> {
+        self.check(parent)?;
         catch_node_panic(parent, || self.inner.children(parent.inner))?
-            .map(|ids: Vec<taffy::NodeId>| ids.into_iter().map(NodeId::from).collect())
+            .map(|ids: Vec<taffy::NodeId>| ids.into_iter().map(|id| self.node_id(id)).collect())
             .map_err(taffy_error_to_py)
     }
 
+    /// Iterate over the children of a node without building a list.
+    fn children_iter(slf: &Bound<'_, Self>, parent: &NodeId) -> PyResult<ChildIter> {
+        slf.borrow().check(parent)?;
+        Ok(ChildIter {
+            tree: slf.clone().unbind(),
+            parent: parent.clone(),
+            index: 0,
+        })
+    }
+
+    /// Iterate over `root` and its descendants, depth-first (pre-order) or breadth-first.
+    #[pyo3(signature = (root, order="dfs"))]
+    fn descendants(slf: &Bound<'_, Self>, root: &NodeId, order: &str) -> PyResult<NodeIter> {
+        slf.borrow().check(root)?;
+        let breadth_first = match order {
+            "dfs" => false,
+            "bfs" => true,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "order must be \"dfs\" or \"bfs\", got {order:?}"
+                )))
+            }
+        };
+        Ok(NodeIter {
+            tree: slf.clone().unbind(),
+            pending: VecDeque::from([root.inner]),
+            breadth_first,
+        })
+    }
+
+    /// The first node under `root`, in depth-first pre-order, for which `predicate` is truthy.
+    fn find(
+        slf: &Bound<'_, Self>,
+        root: &NodeId,
+        predicate: &Bound<'_, PyAny>,
+    ) -> PyResult<Option<NodeId>> {
+        let mut nodes = Self::descendants(slf, root, "dfs")?;
+        while let Some(node) = nodes.__next__(slf.py())? {
+            if predicate.call1((node.clone(),))?.is_truthy()? {
+                return Ok(Some(node));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Every node under `root`, in depth-first pre-order, for which `predicate` is truthy.
+    fn find_all(
+        slf: &Bound<'_, Self>,
+        root: &NodeId,
+        predicate: &Bound<'_, PyAny>,
+    ) -> PyResult<Vec<NodeId>> {
+        let mut nodes = Self::descendants(slf, root, "dfs")?;
+        let mut found = Vec::new();
+        while let Some(node) = nodes.__next__(slf.py())? {
+            if predicate.call1((node.clone(),))?.is_truthy()? {
+                found.push(node);
+            }
+        }
+        Ok(found)
+    }
+
     /// Get the number of children of a node.
     fn child_count(&self, parent: &NodeId) -> PyResult<usize> {
+        self.check(parent)?;
         catch_node_panic(parent, || self.inner.child_count(parent.inner))
     }
 
     /// Get the parent of a node, if any.
     fn parent(&self, child: &NodeId) -> PyResult<Option<NodeId>> {
-        catch_node_panic(child, || self.inner.parent(child.inner).map(NodeId::from))
+        self.check(child)?;
+        Ok(catch_node_panic(child, || self.inner.parent(child.inner))?.map(|id| self.node_id(id)))
+    }
+
+    /// The chain of ancestors from the immediate parent up to the root.
+    fn ancestors(&self, node: &NodeId) -> PyResult<Vec<NodeId>> {
+        self.check(node)?;
+        catch_node_panic(node, || {
+            std::iter::successors(self.inner.parent(node.inner), |&id| self.inner.parent(id))
+                .map(|id| self.node_id(id))
+                .collect()
+        })
+    }
+
+    /// The number of edges between a node and its root (0 for a root).
+    fn depth(&self, node: &NodeId) -> PyResult<usize> {
+        self.check(node)?;
+        catch_node_panic(node, || {
+            std::iter::successors(self.inner.parent(node.inner), |&id| self.inner.parent(id))
+                .count()
+        })
     }
 
     /// Get the total number of nodes in the tree.
@@ -157,38 +803,186 @@ impl TaffyTree {
         self.inner.total_node_count()
     }
 
+    /// Get the number of nodes in the subtree rooted at `node`, including `node` itself.
+    fn subtree_node_count(&self, node: &NodeId) -> PyResult<usize> {
+        self.check(node)?;
+        catch_node_panic(node, || {
+            let mut count = 0;
+            let mut pending = vec![node.inner];
+            while let Some(id) = pending.pop() {
+                count += 1;
+                pending.extend(self.inner.child_ids(id));
+            }
+            count
+        })
+    }
+
     /// Remove a node from the tree.
     fn remove(&mut self, node: &NodeId) -> PyResult<NodeId> {
-        catch_node_panic(node, || self.inner.remove(node.inner))?
-            .map(NodeId::from)
-            .map_err(taffy_error_to_py)
+        self.check(node)?;
+        let removed =
+            catch_node_panic(node, || self.inner.remove(node.inner))?.map_err(taffy_error_to_py)?;
+        self.nodes.remove(&removed);
+        self.labels.remove(&removed);
+        self.factory_contexts.remove(&removed);
+        self.set_fields.remove(&removed);
+        Ok(self.node_id(removed))
     }
 
     /// Clear all nodes from the tree.
     fn clear(&mut self) {
+        // taffy's `clear` leaves node contexts behind, so drop them first. Clearing in place
+        // (rather than swapping in a fresh tree) keeps the slotmap versions, so pre-clear
+        // `NodeId`s can't alias nodes created afterwards.
+        for &id in &self.nodes {
+            let _ = self.inner.set_node_context(id, None);
+        }
         self.inner.clear();
+        self.nodes.clear();
+        self.labels.clear();
+        self.set_fields.clear();
+        self.factory_contexts.clear();
+    }
+
+    /// Whether `node` belongs to this tree and has not been removed.
+    fn contains(&self, node: &NodeId) -> bool {
+        node.tree == self.id && self.nodes.contains(&node.inner)
+    }
+
+    /// Every node without a parent, including detached leaves, sorted by id.
+    fn roots(&self) -> Vec<NodeId> {
+        let mut roots: Vec<taffy::NodeId> = self
+            .nodes
+            .iter()
+            .copied()
+            .filter(|&id| self.inner.parent(id).is_none())
+            .collect();
+        roots.sort_unstable_by_key(|&id| u64::from(id));
+        roots.into_iter().map(|id| self.node_id(id)).collect()
+    }
+
+    /// An independent copy of this tree: same structure, styles, layouts and contexts.
+    /// The copy is a different tree with its own id; `translate` maps this tree's
+    /// `NodeId`s onto it.
+    fn clone(&self, py: Python<'_>) -> Self {
+        Self {
+            // Taffy node ids survive the clone; only the contexts' references are copied.
+            inner: self.inner.clone(),
+            id: NEXT_TREE_ID.fetch_add(1, Ordering::Relaxed),
+            cloned_from: Some((self.id, self.nodes.clone())),
+            context_factory: self.context_factory.as_ref().map(|f| f.clone_ref(py)),
+            factory_contexts: self
+                .factory_contexts
+                .iter()
+                .map(|(&id, ctx)| (id, ctx.clone_ref(py)))
+                .collect(),
+            last_stats: self.last_stats,
+            rounding: self.rounding,
+            nodes: self.nodes.clone(),
+            labels: self.labels.clone(),
+            set_fields: self.set_fields.clone(),
+        }
+    }
+
+    fn __copy__(&self, py: Python<'_>) -> Self {
+        self.clone(py)
+    }
+
+    /// The node in this tree corresponding to `node`, which may belong to this tree or
+    /// to the tree this one was cloned from (if it existed when the clone was made).
+    fn translate(&self, node: &NodeId) -> PyResult<NodeId> {
+        match &self.cloned_from {
+            Some((source, nodes)) if node.tree == *source => {
+                if nodes.contains(&node.inner) {
+                    Ok(self.node_id(node.inner))
+                } else {
+                    Err(InvalidNodeId::new_err(format!(
+                        "{} was created after this tree was cloned",
+                        node.__repr__()
+                    )))
+                }
+            }
+            _ => self.check(node).map(|()| node.clone()),
+        }
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    /// Clear the tree on leaving a `with` block, releasing every node's context.
+    fn __exit__(
+        &mut self,
+        _exc_type: &Bound<'_, PyAny>,
+        _exc_value: &Bound<'_, PyAny>,
+        _traceback: &Bound<'_, PyAny>,
+    ) -> bool {
+        self.clear();
+        false
     }
 
     /// Set the style of a node.
     fn set_style(&mut self, node: &NodeId, style: &Style) -> PyResult<()> {
+        self.check(node)?;
         catch_node_panic(node, || self.inner.set_style(node.inner, style.to_taffy()))?
-            .map_err(taffy_error_to_py)
+            .map_err(taffy_error_to_py)?;
+        self.set_fields.insert(node.inner, style.set_fields());
+        Ok(())
+    }
+
+    /// Set the styles of many nodes in one call, in order.
+    /// Every node is checked against this tree before any style is applied; after that,
+    /// the first failing node raises and the remaining updates are not applied.
+    fn set_styles(&mut self, updates: Vec<(NodeId, PyRef<'_, Style>)>) -> PyResult<()> {
+        for (node, _) in &updates {
+            self.check(node)?;
+        }
+        for (node, style) in &updates {
+            catch_node_panic(node, || self.inner.set_style(node.inner, style.to_taffy()))?
+                .map_err(taffy_error_to_py)?;
+            self.set_fields.insert(node.inner, style.set_fields());
+        }
+        Ok(())
     }
 
-    /// Get the style of a node.
+    /// Get the style of a node, with the same fields marked as set as the style it was given.
     fn style(&self, node: &NodeId) -> PyResult<Style> {
+        self.check(node)?;
+        let set_fields = self
+            .set_fields
+            .get(&node.inner)
+            .copied()
+            .unwrap_or_default();
         catch_node_panic(node, || self.inner.style(node.inner))?
-            .map(Style::from)
+            .map(|style| Style::from_taffy(style, set_fields))
             .map_err(taffy_error_to_py)
     }
 
+    /// Set the style of the child at `child_index` of `parent`.
+    fn set_child_style(
+        &mut self,
+        parent: &NodeId,
+        child_index: usize,
+        style: &Style,
+    ) -> PyResult<()> {
+        let child = self.child_at_index(parent, child_index)?;
+        self.set_style(&child, style)
+    }
+
+    /// Get the style of the child at `child_index` of `parent`.
+    fn child_style(&self, parent: &NodeId, child_index: usize) -> PyResult<Style> {
+        self.style(&self.child_at_index(parent, child_index)?)
+    }
+
     /// Mark a node as dirty (needing re-layout).
     fn mark_dirty(&mut self, node: &NodeId) -> PyResult<()> {
+        self.check(node)?;
         catch_node_panic(node, || self.inner.mark_dirty(node.inner))?.map_err(taffy_error_to_py)
     }
 
     /// Check if a node is dirty (needs re-layout).
     fn dirty(&self, node: &NodeId) -> PyResult<bool> {
+        self.check(node)?;
         catch_node_panic(node, || self.inner.dirty(node.inner))?.map_err(taffy_error_to_py)
     }
 
@@ -198,98 +992,239 @@ impl TaffyTree {
         &mut self,
         py: Python<'_>,
         node: &NodeId,
-        available: Option<&AvailableSize>,
+        available: Option<AvailableSizeInput>,
         measure: Option<Py<PyAny>>,
     ) -> PyResult<()> {
-        let avail: taffy::Size<taffy::AvailableSpace> =
-            available.map(|a| a.into()).unwrap_or(taffy::Size {
-                width: taffy::AvailableSpace::MaxContent,
-                height: taffy::AvailableSpace::MaxContent,
-            });
+        self.check(node)?;
+        self.run_layout(py, node, available.as_ref(), measure)
+    }
 
-        match measure {
-            None => catch_panic(|| self.inner.compute_layout(node.inner, avail))?
-                .map_err(taffy_error_to_py),
-            Some(measure_fn) => {
-                // py_err lives outside catch_unwind so it survives a panic unwind.
-                let py_err: std::cell::RefCell<Option<PyErr>> = std::cell::RefCell::new(None);
+    /// Compute the layout of each of several independent trees, in order.
+    #[pyo3(signature = (roots, available=None, measure=None))]
+    fn compute_layout_all(
+        &mut self,
+        py: Python<'_>,
+        roots: Vec<NodeId>,
+        available: Option<AvailableSizeInput>,
+        measure: Option<Py<PyAny>>,
+    ) -> PyResult<()> {
+        self.check_all(&roots)?;
+        let mut stats = LayoutStats::default();
+        for root in &roots {
+            let measure = measure.as_ref().map(|m| m.clone_ref(py));
+            let result = self.run_layout(py, root, available.as_ref(), measure);
+            stats.nodes_laid_out += self.last_stats.nodes_laid_out;
+            stats.measure_calls += self.last_stats.measure_calls;
+            self.last_stats = stats;
+            result?;
+        }
+        Ok(())
+    }
 
-                let result = catch_panic(|| {
-                    self.inner.compute_layout_with_measure(
-                        node.inner,
-                        avail,
-                        |known,
-                         available,
-                         _node_id,
-                         node_context: Option<&mut Py<PyAny>>,
-                         _style| {
-                            // If we already have a Python error, short-circuit.
-                            if py_err.borrow().is_some() {
-                                return taffy::Size::ZERO;
-                            }
+    /// Compute the layout of a tree rooted at the given node and return the root's layout.
+    #[pyo3(signature = (node, available=None, measure=None))]
+    fn compute_and_get_layout(
+        &mut self,
+        py: Python<'_>,
+        node: &NodeId,
+        available: Option<AvailableSizeInput>,
+        measure: Option<Py<PyAny>>,
+    ) -> PyResult<Layout> {
+        self.compute_layout(py, node, available, measure)?;
+        self.layout(node)
+    }
 
-                            // If both dimensions are already known, return them directly.
-                            if let taffy::Size {
-                                width: Some(w),
-                                height: Some(h),
-                            } = known
-                            {
-                                return taffy::Size {
-                                    width: w,
-                                    height: h,
-                                };
-                            }
+    /// Measure a single node by laying it out as a root, returning its size.
+    #[pyo3(signature = (node, available=None, measure=None))]
+    fn measure_node(
+        &mut self,
+        py: Python<'_>,
+        node: &NodeId,
+        available: Option<AvailableSizeInput>,
+        measure: Option<Py<PyAny>>,
+    ) -> PyResult<Size> {
+        self.check(node)?;
+        self.run_layout(py, node, available.as_ref(), measure)?;
+        let size = catch_node_panic(node, || self.inner.layout(node.inner))?
+            .map(|layout| Size::from(layout.size))
+            .map_err(taffy_error_to_py)?;
+        // Laying the node out as a root overwrote its in-tree layout; dirty it so the
+        // next compute_layout of its real root lays it out in place again.
+        catch_node_panic(node, || self.inner.mark_dirty(node.inner))?.map_err(taffy_error_to_py)?;
+        Ok(size)
+    }
 
-                            // If no context, return zero — don't bother calling Python.
-                            let Some(context) = node_context else {
-                                return taffy::Size::ZERO;
-                            };
+    /// Counters from the most recent layout run (`compute_layout`, `measure_node`, ...).
+    fn last_layout_stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("nodes_laid_out", self.last_stats.nodes_laid_out)?;
+        dict.set_item("measure_calls", self.last_stats.measure_calls)?;
+        Ok(dict)
+    }
 
-                            // Convert to Python types and call the measure function.
-                            let py_known = KnownSize::from(known);
-                            let py_avail = AvailableSize::from(available);
+    /// Get the computed layout of a node.
+    fn layout(&self, node: &NodeId) -> PyResult<Layout> {
+        self.check(node)?;
+        catch_node_panic(node, || self.inner.layout(node.inner))?
+            .map(Layout::from)
+            .map_err(taffy_error_to_py)
+    }
 
-                            let call_result =
-                                measure_fn.call1(py, (py_known, py_avail, context.clone_ref(py)));
+    /// Serialize the subtree rooted at `root` to nested dicts. `layout` is omitted
+    /// for nodes whose layout is stale (dirty).
+    fn to_dict<'py>(&self, py: Python<'py>, root: &NodeId) -> PyResult<Bound<'py, PyDict>> {
+        self.check(root)?;
+        let dict = PyDict::new(py);
+        dict.set_item("node_id", u64::from(root.inner))?;
+        dict.set_item("style", self.style(root)?.to_dict(py)?)?;
+        if !self.dirty(root)? {
+            dict.set_item("layout", self.layout(root)?.to_dict(py)?)?;
+        }
+        let children = self
+            .children(root)?
+            .iter()
+            .map(|child| self.to_dict(py, child))
+            .collect::<PyResult<Vec<_>>>()?;
+        dict.set_item("children", children)?;
+        Ok(dict)
+    }
 
-                            match call_result {
-                                Err(e) => {
-                                    *py_err.borrow_mut() = Some(e);
-                                    taffy::Size::ZERO
-                                }
-                                Ok(result) => match result.extract::<crate::geometry::Size>(py) {
-                                    Ok(size) => taffy::Size {
-                                        width: size.width,
-                                        height: size.height,
-                                    },
-                                    Err(e) => {
-                                        *py_err.borrow_mut() = Some(e.into());
-                                        taffy::Size::ZERO
-                                    }
-                                },
-                            }
-                        },
-                    )
-                });
+    /// Render the computed layout of the subtree rooted at `root` as a standalone SVG document.
+    /// Each node with an up-to-date layout is drawn as its border box and padding box
+    /// (two `<rect>`s in distinct strokes) labelled with its node id.
+    fn to_svg(&self, root: &NodeId) -> PyResult<String> {
+        self.check_laid_out(root)?;
+        let root_box = self.layout(root)?.border_box();
+        let mut body = String::new();
+        catch_node_panic(root, || {
+            let mut stack = vec![(root.inner, 0.0f32, 0.0f32)];
+            while let Some((node, dx, dy)) = stack.pop() {
+                let mut layout = Layout::from(self.inner.layout(node)?);
+                layout.location.x += dx;
+                layout.location.y += dy;
+                svg_node(&mut body, u64::from(node), &layout);
+                // Push children in reverse so the first child is drawn next.
+                let (x, y) = (layout.location.x, layout.location.y);
+                let start = stack.len();
+                stack.extend(self.inner.child_ids(node).map(|child| (child, x, y)));
+                stack[start..].reverse();
+            }
+            Ok(())
+        })?
+        .map_err(taffy_error_to_py)?;
+        Ok(format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+             viewBox=\"{x} {y} {w} {h}\" font-family=\"monospace\" font-size=\"10\">\n{body}</svg>\n",
+            x = root_box.left,
+            y = root_box.top,
+            w = root_box.right - root_box.left,
+            h = root_box.bottom - root_box.top,
+        ))
+    }
 
-                // Priority: Python errors first, then panics, then taffy errors.
-                if let Some(e) = py_err.into_inner() {
-                    return Err(e);
+    /// `(node, layout)` pairs in depth-first pre-order, skipping `display: none` subtrees.
+    fn visible_layouts(&self, root: &NodeId) -> PyResult<Vec<(NodeId, Layout)>> {
+        self.check(root)?;
+        catch_node_panic(root, || {
+            let mut visible = Vec::new();
+            let mut stack = vec![root.inner];
+            while let Some(node) = stack.pop() {
+                if self.inner.style(node)?.display == taffy::Display::None {
+                    continue;
                 }
-                result?.map_err(taffy_error_to_py)
+                let layout = Layout::from(self.inner.layout(node)?);
+                visible.push((self.node_id(node), layout));
+                // Push children in reverse so the first child is visited next.
+                let start = stack.len();
+                stack.extend(self.inner.child_ids(node));
+                stack[start..].reverse();
+            }
+            Ok(visible)
+        })?
+        .map_err(taffy_error_to_py)
+    }
+
+    /// Style problems in the subtree rooted at `root`, as `(node, message)` pairs in
+    /// depth-first pre-order.
+    fn lint(&self, root: &NodeId) -> PyResult<Vec<(NodeId, String)>> {
+        self.check(root)?;
+        catch_node_panic(root, || {
+            let mut issues = Vec::new();
+            let mut stack = vec![(root.inner, None)];
+            while let Some((node, parent)) = stack.pop() {
+                let style = self.inner.style(node)?;
+                let mut messages = style_issues(style);
+                if let Some(parent) = parent {
+                    messages.extend(placement_issues(style, self.inner.style(parent)?));
+                }
+                issues.extend(messages.into_iter().map(|m| (self.node_id(node), m)));
+                // Push children in reverse so the first child is visited next.
+                let start = stack.len();
+                stack.extend(self.inner.child_ids(node).map(|child| (child, Some(node))));
+                stack[start..].reverse();
             }
+            Ok(issues)
+        })?
+        .map_err(taffy_error_to_py)
+    }
+
+    /// Get the computed layout of a node, with `location` relative to the tree root.
+    fn absolute_layout(&self, node: &NodeId) -> PyResult<Layout> {
+        let mut layout = self.layout(node)?;
+        let mut current = node.inner;
+        while let Some(parent) = self.inner.parent(current) {
+            let offset = self
+                .inner
+                .layout(parent)
+                .map_err(taffy_error_to_py)?
+                .location;
+            layout.location.x += offset.x;
+            layout.location.y += offset.y;
+            current = parent;
         }
+        Ok(layout)
     }
 
-    /// Get the computed layout of a node.
-    fn layout(&self, node: &NodeId) -> PyResult<Layout> {
-        catch_node_panic(node, || self.inner.layout(node.inner))?
-            .map(Layout::from)
-            .map_err(taffy_error_to_py)
+    /// Iterate over `(node, layout, absolute_location)` for `root` and its descendants
+    /// in depth-first pre-order. `root` must have an up-to-date layout.
+    fn iter_layout(slf: &Bound<'_, Self>, root: &NodeId) -> PyResult<LayoutIter> {
+        let tree = slf.borrow();
+        tree.check_laid_out(root)?;
+        // The absolute location of root's parent, so the first yield is root's absolute location.
+        let absolute = tree.absolute_layout(root)?.location;
+        let relative = tree.layout(root)?.location;
+        Ok(LayoutIter {
+            tree: slf.clone().unbind(),
+            pending: vec![(root.inner, absolute.x - relative.x, absolute.y - relative.y)],
+        })
+    }
+
+    /// The nodes under `root` (inclusive), in depth-first pre-order, whose absolute
+    /// border box overlaps `viewport`. `root` must have an up-to-date layout.
+    fn visible_nodes(
+        slf: &Bound<'_, Self>,
+        root: &NodeId,
+        viewport: &Rect,
+    ) -> PyResult<Vec<NodeId>> {
+        let mut layouts = Self::iter_layout(slf, root)?;
+        let mut visible = Vec::new();
+        while let Some((node, layout, absolute)) = layouts.__next__(slf.py())? {
+            let right = absolute.x + layout.size.width;
+            let bottom = absolute.y + layout.size.height;
+            if absolute.x < viewport.right
+                && right > viewport.left
+                && absolute.y < viewport.bottom
+                && bottom > viewport.top
+            {
+                visible.push(node);
+            }
+        }
+        Ok(visible)
     }
 
     /// Get the unrounded layout of a node.
     fn unrounded_layout(&self, node: &NodeId) -> PyResult<Layout> {
+        self.check(node)?;
         catch_node_panic(node, || {
             Layout::from(self.inner.unrounded_layout(node.inner))
         })
@@ -298,16 +1233,44 @@ impl TaffyTree {
     /// Enable rounding of layout values.
     fn enable_rounding(&mut self) {
         self.inner.enable_rounding();
+        self.rounding = true;
     }
 
     /// Disable rounding of layout values.
     fn disable_rounding(&mut self) {
         self.inner.disable_rounding();
+        self.rounding = false;
+    }
+
+    /// Whether layout values are rounded (the default).
+    fn is_rounding_enabled(&self) -> bool {
+        self.rounding
+    }
+
+    /// A context manager that disables rounding on entry and restores the prior
+    /// setting on exit.
+    fn rounding_disabled(slf: &Bound<'_, Self>) -> RoundingDisabled {
+        RoundingDisabled {
+            tree: slf.clone().unbind(),
+            previous: None,
+        }
     }
 
     /// Print the layout tree for debugging.
-    fn print_tree(&mut self, root: &NodeId) -> PyResult<()> {
-        catch_node_panic(root, || self.inner.print_tree(root.inner))
+    fn print_tree(&self, py: Python<'_>, root: &NodeId) -> PyResult<()> {
+        let text = self.format_tree(root)?;
+        py.import("builtins")?.getattr("print")?.call1((text,))?;
+        Ok(())
+    }
+
+    /// Render the layout tree for debugging, in the same format as `print_tree`.
+    fn format_tree(&self, root: &NodeId) -> PyResult<String> {
+        self.check(root)?;
+        let mut out = String::from("TREE");
+        catch_node_panic(root, || {
+            format_node(&self.inner, &self.labels, root.inner, false, "", &mut out)
+        })?;
+        Ok(out)
     }
 
     fn __repr__(&self) -> String {
@@ -323,7 +1286,170 @@ impl TaffyTree {
     }
 }
 
+/// Iterator over a node's children, returned by `TaffyTree.children_iter`.
+#[pyclass(module = "waxy")]
+struct ChildIter {
+    tree: Py<TaffyTree>,
+    parent: NodeId,
+    index: usize,
+}
+
+#[pymethods]
+impl ChildIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<NodeId>> {
+        let tree = self.tree.borrow(py);
+        let parent = self.parent.inner;
+        let child = catch_node_panic(&self.parent, || {
+            (self.index < tree.inner.child_count(parent))
+                .then(|| tree.inner.get_child_id(parent, self.index))
+        })?;
+        self.index += 1;
+        Ok(child.map(|id| tree.node_id(id)))
+    }
+}
+
+/// Iterator over a subtree's nodes, returned by `TaffyTree.descendants`.
+#[pyclass(module = "waxy")]
+struct NodeIter {
+    tree: Py<TaffyTree>,
+    /// Nodes yet to be yielded: a stack for depth-first, a queue for breadth-first.
+    pending: VecDeque<taffy::NodeId>,
+    breadth_first: bool,
+}
+
+#[pymethods]
+impl NodeIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<NodeId>> {
+        let next = if self.breadth_first {
+            self.pending.pop_front()
+        } else {
+            self.pending.pop_back()
+        };
+        let Some(node) = next else {
+            return Ok(None);
+        };
+        let tree = self.tree.borrow(py);
+        let node = tree.node_id(node);
+        let children = catch_node_panic(&node, || tree.inner.children(node.inner))?
+            .map_err(taffy_error_to_py)?;
+        if self.breadth_first {
+            self.pending.extend(children);
+        } else {
+            // Push children in reverse so the first child is visited next.
+            self.pending.extend(children.into_iter().rev());
+        }
+        Ok(Some(node))
+    }
+}
+
+/// Iterator over a subtree's layouts, returned by `TaffyTree.iter_layout`.
+#[pyclass(module = "waxy")]
+struct LayoutIter {
+    tree: Py<TaffyTree>,
+    /// Nodes yet to be yielded, each with its parent's absolute `(x, y)`.
+    pending: Vec<(taffy::NodeId, f32, f32)>,
+}
+
+#[pymethods]
+impl LayoutIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<(NodeId, Layout, Point)>> {
+        let Some((node, dx, dy)) = self.pending.pop() else {
+            return Ok(None);
+        };
+        let tree = self.tree.borrow(py);
+        let node = tree.node_id(node);
+        let (layout, children) = catch_node_panic(&node, || {
+            Ok::<_, taffy::TaffyError>((
+                Layout::from(tree.inner.layout(node.inner)?),
+                tree.inner.children(node.inner)?,
+            ))
+        })?
+        .map_err(taffy_error_to_py)?;
+        let absolute = Point {
+            x: dx + layout.location.x,
+            y: dy + layout.location.y,
+        };
+        // Push children in reverse so the first child is visited next.
+        self.pending.extend(
+            children
+                .into_iter()
+                .rev()
+                .map(|child| (child, absolute.x, absolute.y)),
+        );
+        Ok(Some((node, layout, absolute)))
+    }
+}
+
+/// Context manager returned by `TaffyTree.rounding_disabled`.
+#[pyclass(module = "waxy")]
+struct RoundingDisabled {
+    tree: Py<TaffyTree>,
+    /// The rounding setting to restore on exit, captured on entry.
+    previous: Option<bool>,
+}
+
+#[pymethods]
+impl RoundingDisabled {
+    fn __enter__(&mut self, py: Python<'_>) {
+        let mut tree = self.tree.borrow_mut(py);
+        self.previous = Some(tree.rounding);
+        tree.disable_rounding();
+    }
+
+    fn __exit__(
+        &mut self,
+        py: Python<'_>,
+        _exc_type: &Bound<'_, PyAny>,
+        _exc_value: &Bound<'_, PyAny>,
+        _traceback: &Bound<'_, PyAny>,
+    ) -> bool {
+        if let Some(true) = self.previous.take() {
+            self.tree.borrow_mut(py).enable_rounding();
+        }
+        false
+    }
+}
+
+/// A measure function that ignores the node context, returned by `simple_measure`.
+#[pyclass(frozen, module = "waxy")]
+struct SimpleMeasure {
+    func: Py<PyAny>,
+}
+
+#[pymethods]
+impl SimpleMeasure {
+    fn __call__(
+        &self,
+        py: Python<'_>,
+        known: &Bound<'_, PyAny>,
+        available: &Bound<'_, PyAny>,
+        _context: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<PyAny>> {
+        self.func.call1(py, (known, available))
+    }
+}
+
+/// Adapt a two-argument `func(known, available)` into a measure function that
+/// ignores the node context.
+#[pyfunction]
+fn simple_measure(func: Py<PyAny>) -> SimpleMeasure {
+    SimpleMeasure { func }
+}
+
 pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<TaffyTree>()?;
+    m.add_function(wrap_pyfunction!(simple_measure, m)?)?;
     Ok(())
 }
@@ -1,10 +1,11 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyTuple;
+use pyo3::types::{PyTuple, PyType};
 use taffy::geometry::MinMax;
 use taffy::prelude::TaffyGridLine;
 use taffy::style::{
     CompactLength, GridPlacement as TaffyGridPlacement, MaxTrackSizingFunction,
-    MinTrackSizingFunction, TrackSizingFunction,
+    MinTrackSizingFunction, RepetitionCount, TrackSizingFunction,
 };
 
 use crate::errors::{InvalidGridLine, InvalidGridSpan, InvalidLength, InvalidPercent};
@@ -40,6 +41,10 @@ impl Length {
         PyTuple::new(py, ["value"]).unwrap().unbind()
     }
 
+    fn __getnewargs__(&self) -> (f32,) {
+        (self.value,)
+    }
+
     fn __repr__(&self) -> String {
         format!("Length({})", self.value)
     }
@@ -51,6 +56,28 @@ impl Length {
     fn __hash__(&self) -> isize {
         self.value.to_bits() as isize
     }
+
+    fn __lt__(&self, other: &Length) -> bool {
+        self.value < other.value
+    }
+
+    fn __le__(&self, other: &Length) -> bool {
+        self.value <= other.value
+    }
+
+    fn __gt__(&self, other: &Length) -> bool {
+        self.value > other.value
+    }
+
+    fn __ge__(&self, other: &Length) -> bool {
+        self.value >= other.value
+    }
+
+    fn __abs__(&self) -> Self {
+        Self {
+            value: self.value.abs(),
+        }
+    }
 }
 
 /// A percentage value (0.0 to 1.0).
@@ -87,6 +114,10 @@ impl Percent {
         PyTuple::new(py, ["value"]).unwrap().unbind()
     }
 
+    fn __getnewargs__(&self) -> (f32,) {
+        (self.value,)
+    }
+
     fn __repr__(&self) -> String {
         format!("Percent({})", self.value)
     }
@@ -98,6 +129,44 @@ impl Percent {
     fn __hash__(&self) -> isize {
         self.value.to_bits() as isize
     }
+
+    fn __lt__(&self, other: &Percent) -> bool {
+        self.value < other.value
+    }
+
+    fn __le__(&self, other: &Percent) -> bool {
+        self.value <= other.value
+    }
+
+    fn __gt__(&self, other: &Percent) -> bool {
+        self.value > other.value
+    }
+
+    fn __ge__(&self, other: &Percent) -> bool {
+        self.value >= other.value
+    }
+
+    fn __abs__(&self) -> Self {
+        Self {
+            value: self.value.abs(),
+        }
+    }
+
+    /// Create a Percent from a value on the 0-100 scale, e.g. `50` for 50%.
+    #[classmethod]
+    fn from_percentage(_cls: &Bound<'_, PyType>, value: f32) -> PyResult<Self> {
+        if !(0.0..=100.0).contains(&value) {
+            return Err(InvalidPercent::new_err(format!(
+                "percentage must be in [0.0, 100.0], got {value}"
+            )));
+        }
+        Self::new(value / 100.0)
+    }
+
+    /// The value on the 0-100 scale, e.g. `50` for `Percent(0.5)`.
+    fn as_percentage(&self) -> f32 {
+        self.value * 100.0
+    }
 }
 
 /// Automatic sizing or placement.
@@ -117,6 +186,10 @@ impl Auto {
         Self {}
     }
 
+    fn __getnewargs__<'py>(&self, py: Python<'py>) -> Bound<'py, PyTuple> {
+        PyTuple::empty(py)
+    }
+
     fn __repr__(&self) -> String {
         "Auto()".to_owned()
     }
@@ -150,6 +223,10 @@ impl MinContent {
         Self {}
     }
 
+    fn __getnewargs__<'py>(&self, py: Python<'py>) -> Bound<'py, PyTuple> {
+        PyTuple::empty(py)
+    }
+
     fn __repr__(&self) -> String {
         "MinContent()".to_owned()
     }
@@ -183,6 +260,10 @@ impl MaxContent {
         Self {}
     }
 
+    fn __getnewargs__<'py>(&self, py: Python<'py>) -> Bound<'py, PyTuple> {
+        PyTuple::empty(py)
+    }
+
     fn __repr__(&self) -> String {
         "MaxContent()".to_owned()
     }
@@ -223,6 +304,10 @@ impl Definite {
         PyTuple::new(py, ["value"]).unwrap().unbind()
     }
 
+    fn __getnewargs__(&self) -> (f32,) {
+        (self.value,)
+    }
+
     fn __repr__(&self) -> String {
         format!("Definite({})", self.value)
     }
@@ -234,6 +319,28 @@ impl Definite {
     fn __hash__(&self) -> isize {
         self.value.to_bits() as isize
     }
+
+    fn __lt__(&self, other: &Definite) -> bool {
+        self.value < other.value
+    }
+
+    fn __le__(&self, other: &Definite) -> bool {
+        self.value <= other.value
+    }
+
+    fn __gt__(&self, other: &Definite) -> bool {
+        self.value > other.value
+    }
+
+    fn __ge__(&self, other: &Definite) -> bool {
+        self.value >= other.value
+    }
+
+    fn __abs__(&self) -> Self {
+        Self {
+            value: self.value.abs(),
+        }
+    }
 }
 
 // ─── Grid track sizing only ────────────────────────────────────────────────
@@ -267,6 +374,10 @@ impl Fraction {
         PyTuple::new(py, ["value"]).unwrap().unbind()
     }
 
+    fn __getnewargs__(&self) -> (f32,) {
+        (self.value,)
+    }
+
     fn __repr__(&self) -> String {
         format!("Fraction({})", self.value)
     }
@@ -278,6 +389,28 @@ impl Fraction {
     fn __hash__(&self) -> isize {
         self.value.to_bits() as isize
     }
+
+    fn __lt__(&self, other: &Fraction) -> bool {
+        self.value < other.value
+    }
+
+    fn __le__(&self, other: &Fraction) -> bool {
+        self.value <= other.value
+    }
+
+    fn __gt__(&self, other: &Fraction) -> bool {
+        self.value > other.value
+    }
+
+    fn __ge__(&self, other: &Fraction) -> bool {
+        self.value >= other.value
+    }
+
+    fn __abs__(&self) -> Self {
+        Self {
+            value: self.value.abs(),
+        }
+    }
 }
 
 /// CSS `fit-content()` grid track sizing function.
@@ -318,6 +451,10 @@ impl FitContent {
         self.limit.clone_ref(py)
     }
 
+    fn __getnewargs__(&self, py: Python<'_>) -> (Py<PyAny>,) {
+        (self.limit.clone_ref(py),)
+    }
+
     fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
         let limit_repr = self.limit.bind(py).repr()?.to_str()?.to_owned();
         Ok(format!("FitContent({limit_repr})"))
@@ -393,6 +530,10 @@ impl Minmax {
         self.max.clone_ref(py)
     }
 
+    fn __getnewargs__(&self, py: Python<'_>) -> (Py<PyAny>, Py<PyAny>) {
+        (self.min.clone_ref(py), self.max.clone_ref(py))
+    }
+
     fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
         let min_repr = self.min.bind(py).repr()?.to_str()?.to_owned();
         let max_repr = self.max.bind(py).repr()?.to_str()?.to_owned();
@@ -423,6 +564,367 @@ impl Minmax {
     }
 }
 
+/// CSS `auto-fill` repetition count for `Repeat`.
+///
+/// Repeats the tracks as many times as fit in the container, keeping empty repetitions.
+///
+/// See: [taffy `RepetitionCount::AutoFill`](https://docs.rs/taffy/0.9.2/taffy/style/enum.RepetitionCount.html),
+/// [MDN `auto-fill`](https://developer.mozilla.org/en-US/docs/Web/CSS/repeat#auto-fill)
+#[pyclass(frozen, from_py_object, module = "waxy")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AutoFill {}
+
+#[pymethods]
+impl AutoFill {
+    #[new]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn __getnewargs__<'py>(&self, py: Python<'py>) -> Bound<'py, PyTuple> {
+        PyTuple::empty(py)
+    }
+
+    fn __repr__(&self) -> String {
+        "AutoFill()".to_owned()
+    }
+
+    fn __eq__(&self, _other: &AutoFill) -> bool {
+        true
+    }
+
+    fn __hash__(&self) -> isize {
+        3
+    }
+}
+
+/// CSS `auto-fit` repetition count for `Repeat`.
+///
+/// Like `auto-fill`, but empty repetitions collapse to zero size.
+///
+/// See: [taffy `RepetitionCount::AutoFit`](https://docs.rs/taffy/0.9.2/taffy/style/enum.RepetitionCount.html),
+/// [MDN `auto-fit`](https://developer.mozilla.org/en-US/docs/Web/CSS/repeat#auto-fit)
+#[pyclass(frozen, from_py_object, module = "waxy")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AutoFit {}
+
+#[pymethods]
+impl AutoFit {
+    #[new]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn __getnewargs__<'py>(&self, py: Python<'py>) -> Bound<'py, PyTuple> {
+        PyTuple::empty(py)
+    }
+
+    fn __repr__(&self) -> String {
+        "AutoFit()".to_owned()
+    }
+
+    fn __eq__(&self, _other: &AutoFit) -> bool {
+        true
+    }
+
+    fn __hash__(&self) -> isize {
+        4
+    }
+}
+
+/// Accepts `int | AutoFill | AutoFit` as a `Repeat` count.
+#[derive(FromPyObject)]
+enum RepetitionCountInput {
+    AutoFill(AutoFill),
+    AutoFit(AutoFit),
+    Count(i64),
+}
+
+impl RepetitionCountInput {
+    fn to_taffy(&self) -> PyResult<RepetitionCount> {
+        match self {
+            RepetitionCountInput::AutoFill(_) => Ok(RepetitionCount::AutoFill),
+            RepetitionCountInput::AutoFit(_) => Ok(RepetitionCount::AutoFit),
+            RepetitionCountInput::Count(n) => u16::try_from(*n)
+                .ok()
+                .filter(|&n| n > 0)
+                .map(RepetitionCount::Count)
+                .ok_or_else(|| {
+                    PyValueError::new_err(format!(
+                        "Repeat count must be between 1 and {}, got {n}",
+                        u16::MAX
+                    ))
+                }),
+        }
+    }
+}
+
+/// CSS `repeat()` in a grid template: a list of tracks repeated `count` times.
+///
+/// `count` is a positive `int`, `AUTO_FILL`, or `AUTO_FIT`. Used only in
+/// `grid_template_rows` and `grid_template_columns`.
+///
+/// The repeat is forwarded to taffy as-is. Like CSS, taffy ignores the *whole*
+/// template (leaving no explicit tracks) if it has more than one auto-repeat,
+/// or if it has an auto-repeat and any track anywhere in the template lacks a fixed
+/// (length or percentage) min or max sizing function. So `Repeat(AUTO_FILL, [Fraction(1)])`
+/// is ignored, while `Repeat(AUTO_FILL, [Minmax(Length(100), Fraction(1))])` works.
+///
+/// See: [taffy `GridTemplateRepetition`](https://docs.rs/taffy/0.9.2/taffy/style/struct.GridTemplateRepetition.html),
+/// [MDN `repeat()`](https://developer.mozilla.org/en-US/docs/Web/CSS/repeat)
+#[pyclass(frozen, module = "waxy")]
+#[derive(Debug)]
+pub struct Repeat {
+    pub(crate) count: RepetitionCount,
+    /// Length | Percent | Auto | MinContent | MaxContent | Fraction | Minmax | FitContent
+    pub(crate) tracks: Vec<Py<PyAny>>,
+}
+
+#[pymethods]
+impl Repeat {
+    #[new]
+    fn new(count: RepetitionCountInput, tracks: Vec<Bound<'_, PyAny>>) -> PyResult<Self> {
+        if tracks.is_empty() {
+            return Err(PyValueError::new_err("Repeat must have at least one track"));
+        }
+        // Validate types
+        for track in &tracks {
+            let _: GridTrackInput = track.extract()?;
+        }
+        Ok(Self {
+            count: count.to_taffy()?,
+            tracks: tracks.into_iter().map(Bound::unbind).collect(),
+        })
+    }
+
+    #[classattr]
+    fn __match_args__(py: Python<'_>) -> Py<PyTuple> {
+        PyTuple::new(py, ["count", "tracks"]).unwrap().unbind()
+    }
+
+    /// The repetition count: an `int`, `AutoFill()`, or `AutoFit()`.
+    #[getter]
+    fn count(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        match self.count {
+            RepetitionCount::AutoFill => Ok(Py::new(py, AutoFill {})?.into_any()),
+            RepetitionCount::AutoFit => Ok(Py::new(py, AutoFit {})?.into_any()),
+            RepetitionCount::Count(n) => Ok(n.into_pyobject(py)?.into_any().unbind()),
+        }
+    }
+
+    #[getter]
+    fn tracks(&self, py: Python<'_>) -> Vec<Py<PyAny>> {
+        self.tracks.iter().map(|t| t.clone_ref(py)).collect()
+    }
+
+    fn __getnewargs__(&self, py: Python<'_>) -> PyResult<(Py<PyAny>, Vec<Py<PyAny>>)> {
+        Ok((self.count(py)?, self.tracks(py)))
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let count = self.count(py)?.bind(py).repr()?.to_str()?.to_owned();
+        let tracks = self
+            .tracks
+            .iter()
+            .map(|t| Ok(t.bind(py).repr()?.to_str()?.to_owned()))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(format!("Repeat({count}, [{}])", tracks.join(", ")))
+    }
+
+    fn __eq__(&self, py: Python<'_>, other: &Repeat) -> PyResult<bool> {
+        if self.count != other.count || self.tracks.len() != other.tracks.len() {
+            return Ok(false);
+        }
+        for (a, b) in self.tracks.iter().zip(&other.tracks) {
+            if !a.bind(py).eq(b.bind(py))? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn __hash__(&self, py: Python<'_>) -> PyResult<u64> {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        match self.count {
+            RepetitionCount::AutoFill => 0u8.hash(&mut hasher),
+            RepetitionCount::AutoFit => 1u8.hash(&mut hasher),
+            RepetitionCount::Count(n) => {
+                2u8.hash(&mut hasher);
+                n.hash(&mut hasher);
+            }
+        }
+        for track in &self.tracks {
+            track.bind(py).hash()?.hash(&mut hasher);
+        }
+        Ok(hasher.finish())
+    }
+}
+
+impl Repeat {
+    pub(crate) fn to_taffy(&self, py: Python<'_>) -> taffy::GridTemplateComponent<String> {
+        taffy::GridTemplateComponent::Repeat(taffy::GridTemplateRepetition {
+            count: self.count,
+            tracks: self
+                .tracks
+                .iter()
+                .map(|t| {
+                    let track: GridTrackInput = t.bind(py).extract().unwrap();
+                    track.to_taffy(py)
+                })
+                .collect(),
+            line_names: Vec::new(),
+        })
+    }
+}
+
+// ─── Grid track parsing ────────────────────────────────────────────────────
+
+/// Parse a CSS grid track list such as `"100px 1fr minmax(100px, 1fr)"` into track values.
+///
+/// Supports `<n>px`, `<n>%`, `<n>fr`, `auto`, `min-content`, `max-content`,
+/// `minmax(<min>, <max>)`, `fit-content(<length-percentage>)`, and
+/// `repeat(<count> | auto-fill | auto-fit, <tracks>)`.
+/// Raises `ValueError` naming the first token that cannot be parsed.
+#[pyfunction]
+pub(crate) fn tracks(py: Python<'_>, spec: &str) -> PyResult<Vec<Py<PyAny>>> {
+    split_track_list(spec)?
+        .into_iter()
+        .map(|token| parse_track(py, spec, token))
+        .collect()
+}
+
+/// Split a track list on whitespace, keeping parenthesized arguments together.
+fn split_track_list(spec: &str) -> PyResult<Vec<&str>> {
+    let unbalanced =
+        || PyValueError::new_err(format!("unbalanced parentheses in track list {spec:?}"));
+    let mut tokens = Vec::new();
+    let mut depth = 0usize;
+    let mut start = None;
+    for (i, c) in spec.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1).ok_or_else(unbalanced)?,
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(s) = start.take() {
+                    tokens.push(&spec[s..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+    if depth != 0 {
+        return Err(unbalanced());
+    }
+    if let Some(s) = start {
+        tokens.push(&spec[s..]);
+    }
+    Ok(tokens)
+}
+
+/// Parse a single track token into a grid track value.
+fn parse_track(py: Python<'_>, spec: &str, token: &str) -> PyResult<Py<PyAny>> {
+    let invalid = |reason: &str| {
+        PyValueError::new_err(format!("invalid track {token:?} in {spec:?}: {reason}"))
+    };
+    if let Some(args) = token
+        .strip_prefix("minmax(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let (min, max) = args
+            .split_once(',')
+            .ok_or_else(|| invalid("expected minmax(<min>, <max>)"))?;
+        let min = parse_track(py, spec, min.trim())?;
+        let max = parse_track(py, spec, max.trim())?;
+        if min.bind(py).extract::<GridTrackMinInput>().is_err() {
+            return Err(invalid(
+                "minmax() minimum must be a length, percentage, auto, min-content, or max-content",
+            ));
+        }
+        if max.bind(py).extract::<GridTrackMaxInput>().is_err() {
+            return Err(invalid("minmax() maximum must not be another minmax()"));
+        }
+        return Ok(Py::new(py, Minmax { min, max })?.into_any());
+    }
+    if let Some(args) = token
+        .strip_prefix("repeat(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let (count, tracks) = args
+            .split_once(',')
+            .ok_or_else(|| invalid("expected repeat(<count>, <tracks>)"))?;
+        let count = match count.trim() {
+            "auto-fill" => RepetitionCount::AutoFill,
+            "auto-fit" => RepetitionCount::AutoFit,
+            count => count
+                .parse::<u16>()
+                .ok()
+                .filter(|&n| n > 0)
+                .map(RepetitionCount::Count)
+                .ok_or_else(|| {
+                    invalid("repeat() count must be a positive integer, auto-fill, or auto-fit")
+                })?,
+        };
+        let tracks = split_track_list(tracks)?
+            .into_iter()
+            .map(|track| parse_track(py, spec, track))
+            .collect::<PyResult<Vec<_>>>()?;
+        if tracks
+            .iter()
+            .any(|track| track.bind(py).extract::<GridTrackInput>().is_err())
+        {
+            return Err(invalid("repeat() tracks must not contain another repeat()"));
+        }
+        if tracks.is_empty() {
+            return Err(invalid("repeat() must have at least one track"));
+        }
+        return Ok(Py::new(py, Repeat { count, tracks })?.into_any());
+    }
+    if let Some(arg) = token
+        .strip_prefix("fit-content(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let limit = parse_track(py, spec, arg.trim())?;
+        if limit.bind(py).extract::<LengthPercentageInput>().is_err() {
+            return Err(invalid(
+                "fit-content() limit must be a length or percentage",
+            ));
+        }
+        return Ok(Py::new(py, FitContent { limit })?.into_any());
+    }
+    match token {
+        "auto" => return Ok(Py::new(py, Auto {})?.into_any()),
+        "min-content" => return Ok(Py::new(py, MinContent {})?.into_any()),
+        "max-content" => return Ok(Py::new(py, MaxContent {})?.into_any()),
+        _ => {}
+    }
+    let (number, unit) = ["px", "fr", "%"]
+        .iter()
+        .find_map(|unit| token.strip_suffix(unit).map(|number| (number, *unit)))
+        .ok_or_else(|| {
+            invalid("expected <n>px, <n>%, <n>fr, auto, min-content, max-content, minmax(), or fit-content()")
+        })?;
+    let value = number
+        .parse::<f32>()
+        .ok()
+        .filter(|v| v.is_finite() && *v >= 0.0)
+        .ok_or_else(|| invalid("expected a non-negative number before the unit"))?;
+    match unit {
+        "px" => Ok(Py::new(py, Length { value })?.into_any()),
+        "fr" => Ok(Py::new(py, Fraction { value })?.into_any()),
+        _ if value <= 100.0 => Ok(Py::new(
+            py,
+            Percent {
+                value: value / 100.0,
+            },
+        )?
+        .into_any()),
+        _ => Err(invalid("percentages must be between 0% and 100%")),
+    }
+}
+
 // ─── Grid placement only ──────────────────────────────────────────────────
 
 /// A 1-based grid line index (negative indices count from the end).
@@ -455,6 +957,10 @@ impl GridLine {
         PyTuple::new(py, ["index"]).unwrap().unbind()
     }
 
+    fn __getnewargs__(&self) -> (i16,) {
+        (self.index,)
+    }
+
     fn __repr__(&self) -> String {
         format!("GridLine({})", self.index)
     }
@@ -498,6 +1004,10 @@ impl GridSpan {
         PyTuple::new(py, ["count"]).unwrap().unbind()
     }
 
+    fn __getnewargs__(&self) -> (u16,) {
+        (self.count,)
+    }
+
     fn __repr__(&self) -> String {
         format!("GridSpan({})", self.count)
     }
@@ -511,11 +1021,132 @@ impl GridSpan {
     }
 }
 
+/// A named grid line, optionally the `index`-th line with that name (negative indices count from the end).
+///
+/// Used in grid placement (`GridPlacement.start`, `GridPlacement.end`).
+///
+/// See: [taffy `GridPlacement::NamedLine`](https://docs.rs/taffy/0.9.2/taffy/style/enum.GridPlacement.html),
+/// [MDN Named lines](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_grid_layout/Grid_layout_using_named_grid_lines)
+#[pyclass(frozen, from_py_object, module = "waxy")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridNamedLine {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub index: Option<i16>,
+}
+
+#[pymethods]
+impl GridNamedLine {
+    #[new]
+    #[pyo3(signature = (name, index=None))]
+    pub fn new(name: String, index: Option<i16>) -> PyResult<Self> {
+        if name.is_empty() {
+            return Err(PyValueError::new_err(
+                "GridNamedLine name must not be empty",
+            ));
+        }
+        if index == Some(0) {
+            return Err(InvalidGridLine::new_err(
+                "GridNamedLine index must not be 0 (omit it to use the first line with that name)",
+            ));
+        }
+        Ok(Self { name, index })
+    }
+
+    #[classattr]
+    fn __match_args__(py: Python<'_>) -> Py<PyTuple> {
+        PyTuple::new(py, ["name", "index"]).unwrap().unbind()
+    }
+
+    fn __getnewargs__(&self) -> (String, Option<i16>) {
+        (self.name.clone(), self.index)
+    }
+
+    fn __repr__(&self) -> String {
+        match self.index {
+            Some(index) => format!("GridNamedLine({:?}, {index})", self.name),
+            None => format!("GridNamedLine({:?})", self.name),
+        }
+    }
+
+    fn __eq__(&self, other: &GridNamedLine) -> bool {
+        self == other
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.name.hash(&mut hasher);
+        self.index.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Span until the `count`-th grid line with the given name.
+///
+/// Used in grid placement (`GridPlacement.start`, `GridPlacement.end`).
+///
+/// See: [taffy `GridPlacement::NamedSpan`](https://docs.rs/taffy/0.9.2/taffy/style/enum.GridPlacement.html),
+/// [MDN `grid-column-start` (span)](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-column-start)
+#[pyclass(frozen, from_py_object, module = "waxy")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridNamedSpan {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub count: u16,
+}
+
+#[pymethods]
+impl GridNamedSpan {
+    #[new]
+    #[pyo3(signature = (name, count=1))]
+    pub fn new(name: String, count: u16) -> PyResult<Self> {
+        if name.is_empty() {
+            return Err(PyValueError::new_err(
+                "GridNamedSpan name must not be empty",
+            ));
+        }
+        if count == 0 {
+            return Err(InvalidGridSpan::new_err(
+                "GridNamedSpan count must be at least 1",
+            ));
+        }
+        Ok(Self { name, count })
+    }
+
+    #[classattr]
+    fn __match_args__(py: Python<'_>) -> Py<PyTuple> {
+        PyTuple::new(py, ["name", "count"]).unwrap().unbind()
+    }
+
+    fn __getnewargs__(&self) -> (String, u16) {
+        (self.name.clone(), self.count)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GridNamedSpan({:?}, {})", self.name, self.count)
+    }
+
+    fn __eq__(&self, other: &GridNamedSpan) -> bool {
+        self == other
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.name.hash(&mut hasher);
+        self.count.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
 // ─── Grid placement ────────────────────────────────────────────────────────
 
 /// A start/end pair of grid placements for a child item.
 ///
-/// Each of `start` and `end` is a `GridLine | GridSpan | Auto` value.
+/// Each of `start` and `end` is a `GridLine | GridSpan | GridNamedLine | GridNamedSpan | Auto` value.
 /// Defaults both to `Auto` (the CSS default for unplaced items).
 ///
 pub(crate) fn hash_taffy_grid_placement<H: std::hash::Hasher>(
@@ -622,6 +1253,26 @@ impl GridPlacement {
         }
     }
 
+    /// Parse the CSS `grid-row` / `grid-column` shorthand, e.g. `"1 / span 2"`, `"span 3"`, `"auto"`.
+    #[staticmethod]
+    pub(crate) fn parse(s: &str) -> PyResult<Self> {
+        let (start, end) = match s.split_once('/') {
+            Some((start, end)) => (start, Some(end)),
+            None => (s, None),
+        };
+        Ok(Self {
+            start: parse_grid_placement_side(s, start)?,
+            end: match end {
+                Some(end) => parse_grid_placement_side(s, end)?,
+                None => TaffyGridPlacement::Auto,
+            },
+        })
+    }
+
+    fn __getnewargs__(&self, py: Python<'_>) -> PyResult<(Py<PyAny>, Py<PyAny>)> {
+        Ok((self.start(py)?, self.end(py)?))
+    }
+
     fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
         let start = grid_placement_to_py(py, self.start.clone())?;
         let end = grid_placement_to_py(py, self.end.clone())?;
@@ -653,6 +1304,30 @@ impl GridPlacement {
     }
 }
 
+/// Parse one side of a grid placement shorthand: `auto`, `<line>`, or `span <count>`.
+fn parse_grid_placement_side(input: &str, side: &str) -> PyResult<TaffyGridPlacement> {
+    let malformed = || {
+        PyValueError::new_err(format!(
+            "invalid grid placement {input:?}: expected \"auto\", a line number, or \"span N\" on each side of \"/\""
+        ))
+    };
+    let tokens: Vec<&str> = side.split_whitespace().collect();
+    match tokens.as_slice() {
+        ["auto"] => Ok(TaffyGridPlacement::Auto),
+        ["span", count] => {
+            let count = count.parse::<u16>().map_err(|_| malformed())?;
+            Ok(TaffyGridPlacement::Span(GridSpan::new(count)?.count))
+        }
+        [index] => {
+            let index = index.parse::<i16>().map_err(|_| malformed())?;
+            Ok(TaffyGridPlacement::from_line_index(
+                GridLine::new(index)?.index,
+            ))
+        }
+        _ => Err(malformed()),
+    }
+}
+
 impl From<taffy::Line<TaffyGridPlacement>> for GridPlacement {
     fn from(line: taffy::Line<TaffyGridPlacement>) -> Self {
         Self {
@@ -791,6 +1466,22 @@ impl GridTrackMaxInput {
     }
 }
 
+/// Accepts a grid track or a `Repeat`, for `grid_template_*`.
+#[derive(FromPyObject)]
+pub enum GridTemplateInput {
+    Repeat(Py<Repeat>),
+    Track(GridTrackInput),
+}
+
+impl GridTemplateInput {
+    pub fn to_taffy(&self, py: Python<'_>) -> taffy::GridTemplateComponent<String> {
+        match self {
+            GridTemplateInput::Repeat(r) => r.bind(py).borrow().to_taffy(py),
+            GridTemplateInput::Track(t) => taffy::GridTemplateComponent::Single(t.to_taffy(py)),
+        }
+    }
+}
+
 /// Accepts the full set of grid track values from Python.
 #[derive(FromPyObject)]
 pub enum GridTrackInput {
@@ -840,11 +1531,13 @@ impl GridTrackInput {
     }
 }
 
-/// Accepts `GridLine | GridSpan | Auto` for grid placement.
+/// Accepts `GridLine | GridSpan | GridNamedLine | GridNamedSpan | Auto` for grid placement.
 #[derive(FromPyObject)]
 pub enum GridPlacementInput {
     GridLine(GridLine),
     GridSpan(GridSpan),
+    GridNamedLine(GridNamedLine),
+    GridNamedSpan(GridNamedSpan),
     Auto(Auto),
 }
 
@@ -853,6 +1546,13 @@ impl GridPlacementInput {
         match self {
             GridPlacementInput::GridLine(gl) => TaffyGridPlacement::from_line_index(gl.index),
             GridPlacementInput::GridSpan(gs) => TaffyGridPlacement::Span(gs.count),
+            // taffy uses index 0 to mean "no index specified"
+            GridPlacementInput::GridNamedLine(nl) => {
+                TaffyGridPlacement::NamedLine(nl.name.clone(), nl.index.unwrap_or(0))
+            }
+            GridPlacementInput::GridNamedSpan(ns) => {
+                TaffyGridPlacement::NamedSpan(ns.name.clone(), ns.count)
+            }
             GridPlacementInput::Auto(_) => TaffyGridPlacement::Auto,
         }
     }
@@ -1035,8 +1735,88 @@ pub fn grid_placement_to_py(py: Python<'_>, gp: TaffyGridPlacement) -> PyResult<
             Ok(Py::new(py, GridLine { index: gl.as_i16() })?.into_any())
         }
         TaffyGridPlacement::Span(count) => Ok(Py::new(py, GridSpan { count })?.into_any()),
-        // NamedLine and NamedSpan are not supported; map to Auto
-        _ => Ok(Py::new(py, Auto {})?.into_any()),
+        TaffyGridPlacement::NamedLine(name, index) => Ok(Py::new(
+            py,
+            GridNamedLine {
+                name,
+                index: (index != 0).then_some(index),
+            },
+        )?
+        .into_any()),
+        TaffyGridPlacement::NamedSpan(name, count) => {
+            Ok(Py::new(py, GridNamedSpan { name, count })?.into_any())
+        }
+    }
+}
+
+// ─── Taffy → CSS text helpers ─────────────────────────────────────────────
+// Used by `Style.to_css`. These mirror the Python conversion helpers above,
+// including the track shorthand recognition, but produce CSS value syntax.
+
+pub(crate) fn compact_length_to_css(raw: CompactLength) -> String {
+    match raw.tag() {
+        CompactLength::LENGTH_TAG => format!("{}px", raw.value()),
+        CompactLength::PERCENT_TAG => format!("{}%", raw.value() * 100.0),
+        CompactLength::FR_TAG => format!("{}fr", raw.value()),
+        CompactLength::MIN_CONTENT_TAG => "min-content".to_owned(),
+        CompactLength::MAX_CONTENT_TAG => "max-content".to_owned(),
+        CompactLength::FIT_CONTENT_PX_TAG => format!("fit-content({}px)", raw.value()),
+        CompactLength::FIT_CONTENT_PERCENT_TAG => {
+            format!("fit-content({}%)", raw.value() * 100.0)
+        }
+        _ => "auto".to_owned(),
+    }
+}
+
+pub(crate) fn grid_track_to_css(tsf: TrackSizingFunction) -> String {
+    let min = tsf.min_sizing_function().into_raw();
+    let max = tsf.max_sizing_function().into_raw();
+    let same = min.tag() == max.tag() && min.value().to_bits() == max.value().to_bits();
+    match (min.tag(), max.tag()) {
+        (CompactLength::AUTO_TAG, CompactLength::FR_TAG)
+        | (CompactLength::AUTO_TAG, CompactLength::FIT_CONTENT_PX_TAG)
+        | (CompactLength::AUTO_TAG, CompactLength::FIT_CONTENT_PERCENT_TAG) => {
+            compact_length_to_css(max)
+        }
+        _ if same => compact_length_to_css(min),
+        _ => format!(
+            "minmax({}, {})",
+            compact_length_to_css(min),
+            compact_length_to_css(max)
+        ),
+    }
+}
+
+pub(crate) fn grid_template_to_css(tracks: &[taffy::GridTemplateComponent<String>]) -> String {
+    if tracks.is_empty() {
+        return "none".to_owned();
+    }
+    tracks
+        .iter()
+        .map(|component| match component {
+            taffy::GridTemplateComponent::Single(tsf) => grid_track_to_css(*tsf),
+            taffy::GridTemplateComponent::Repeat(rep) => {
+                let count = match rep.count {
+                    taffy::RepetitionCount::AutoFill => "auto-fill".to_owned(),
+                    taffy::RepetitionCount::AutoFit => "auto-fit".to_owned(),
+                    taffy::RepetitionCount::Count(n) => n.to_string(),
+                };
+                let tracks: Vec<_> = rep.tracks.iter().map(|t| grid_track_to_css(*t)).collect();
+                format!("repeat({count}, {})", tracks.join(" "))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub(crate) fn grid_placement_to_css(gp: &TaffyGridPlacement) -> String {
+    match gp {
+        TaffyGridPlacement::Auto => "auto".to_owned(),
+        TaffyGridPlacement::Line(gl) => gl.as_i16().to_string(),
+        TaffyGridPlacement::Span(count) => format!("span {count}"),
+        TaffyGridPlacement::NamedLine(name, 0) => name.to_string(),
+        TaffyGridPlacement::NamedLine(name, index) => format!("{name} {index}"),
+        TaffyGridPlacement::NamedSpan(name, count) => format!("span {name} {count}"),
     }
 }
 
@@ -1052,14 +1832,22 @@ pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Fraction>()?;
     m.add_class::<FitContent>()?;
     m.add_class::<Minmax>()?;
+    m.add_class::<AutoFill>()?;
+    m.add_class::<AutoFit>()?;
+    m.add_class::<Repeat>()?;
     m.add_class::<GridLine>()?;
     m.add_class::<GridSpan>()?;
+    m.add_class::<GridNamedLine>()?;
+    m.add_class::<GridNamedSpan>()?;
     m.add_class::<GridPlacement>()?;
+    m.add_function(wrap_pyfunction!(tracks, m)?)?;
 
     // Module-level singletons for common zero-field types
     m.add("AUTO", Py::new(m.py(), Auto {})?.into_any())?;
     m.add("MIN_CONTENT", Py::new(m.py(), MinContent {})?.into_any())?;
     m.add("MAX_CONTENT", Py::new(m.py(), MaxContent {})?.into_any())?;
+    m.add("AUTO_FILL", Py::new(m.py(), AutoFill {})?.into_any())?;
+    m.add("AUTO_FIT", Py::new(m.py(), AutoFit {})?.into_any())?;
 
     Ok(())
 }
@@ -13,7 +13,17 @@ ENUM_VARIANTS: list[tuple[type, list[str]]] = [
     ),
     (
         waxy.AlignContent,
-        ["Start", "End", "Center", "Stretch", "SpaceBetween", "SpaceEvenly", "SpaceAround"],
+        [
+            "Start",
+            "End",
+            "FlexStart",
+            "FlexEnd",
+            "Center",
+            "Stretch",
+            "SpaceBetween",
+            "SpaceEvenly",
+            "SpaceAround",
+        ],
     ),
     (waxy.Overflow, ["Visible", "Clip", "Hidden", "Scroll"]),
     (waxy.GridAutoFlow, ["Row", "Column", "RowDense", "ColumnDense"]),
@@ -47,3 +57,72 @@ def test_definite_is_not_min_or_max_content() -> None:
 def test_enum_equality() -> None:
     assert waxy.Display.Flex == waxy.Display.Flex
     assert waxy.Display.Flex != waxy.Display.Grid
+
+
+ALL_VARIANTS: list[tuple[type, str]] = [(cls, name) for cls, names in ENUM_VARIANTS for name in names]
+
+
+@pytest.mark.parametrize(
+    ("enum_class", "name"),
+    ALL_VARIANTS,
+    ids=[f"{cls.__name__}.{name}" for cls, name in ALL_VARIANTS],
+)
+def test_enum_from_str_name_round_trip(enum_class: type, name: str) -> None:
+    member = getattr(enum_class, name)
+    assert member.name == name
+    assert enum_class.from_str(name) == member
+    assert enum_class.from_str(name.lower()) == member
+    assert enum_class.from_str(name.upper()) == member
+
+
+def test_display_from_str_accepts_none_alias() -> None:
+    assert waxy.Display.from_str("none") == waxy.Display.Nil
+    assert waxy.Display.from_str("nil") == waxy.Display.Nil
+    assert waxy.Display.Nil.name == "Nil"
+
+
+def test_enum_from_str_unknown_lists_valid_options() -> None:
+    with pytest.raises(ValueError, match="expected one of: Relative, Absolute"):
+        waxy.Position.from_str("sticky")
+
+
+@pytest.mark.parametrize(
+    ("enum_class", "variants"),
+    ENUM_VARIANTS,
+    ids=[cls.__name__ for cls, _ in ENUM_VARIANTS],
+)
+def test_enum_members_in_declaration_order(enum_class: type, variants: list[str]) -> None:
+    assert enum_class.members() == [getattr(enum_class, name) for name in variants]
+
+
+def test_enum_members() -> None:
+    assert len(waxy.Display.members()) == 4
+    assert waxy.AlignContent.SpaceEvenly in waxy.AlignContent.members()
+
+
+@pytest.mark.parametrize(
+    ("member", "css_name"),
+    [
+        (waxy.Display.Nil, "none"),
+        (waxy.Display.Flex, "flex"),
+        (waxy.FlexDirection.RowReverse, "row-reverse"),
+        (waxy.FlexWrap.NoWrap, "nowrap"),
+        (waxy.AlignItems.FlexStart, "flex-start"),
+        (waxy.AlignContent.SpaceBetween, "space-between"),
+        (waxy.GridAutoFlow.ColumnDense, "column dense"),
+        (waxy.BoxSizing.ContentBox, "content-box"),
+    ],
+)
+def test_enum_css_name(member: object, css_name: str) -> None:
+    assert member.css_name == css_name  # type: ignore[attr-defined]
+
+
+@pytest.mark.parametrize(
+    ("enum_class", "name"),
+    ALL_VARIANTS,
+    ids=[f"{cls.__name__}.{name}" for cls, name in ALL_VARIANTS],
+)
+def test_enum_css_name_is_lowercase_keyword(enum_class: type, name: str) -> None:
+    css_name = getattr(enum_class, name).css_name
+    assert css_name == css_name.lower()
+    assert "_" not in css_name
@@ -8,6 +8,7 @@ TAFFY_EXCEPTION_SUBCLASSES = [
     waxy.InvalidChildNode,
     waxy.InvalidInputNode,
     waxy.InvalidNodeId,
+    waxy.WrongTree,
 ]
 
 
@@ -203,6 +204,24 @@ def test_invalid_node_id_parent() -> None:
         tree.parent(node)
 
 
+def test_invalid_node_id_ancestors() -> None:
+    tree, node = _removed_node()
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.ancestors(node)
+
+
+def test_invalid_node_id_depth() -> None:
+    tree, node = _removed_node()
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.depth(node)
+
+
+def test_invalid_node_id_children_iter() -> None:
+    tree, node = _removed_node()
+    with pytest.raises(waxy.InvalidNodeId):
+        list(tree.children_iter(node))
+
+
 def test_invalid_node_id_style() -> None:
     tree, node = _removed_node()
     with pytest.raises(waxy.InvalidNodeId):
@@ -215,12 +234,36 @@ def test_invalid_node_id_set_style() -> None:
         tree.set_style(node, waxy.Style())
 
 
+def test_invalid_node_id_compute_and_get_layout() -> None:
+    tree, node = _removed_node()
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.compute_and_get_layout(node)
+
+
 def test_invalid_node_id_layout() -> None:
     tree, node = _removed_node()
     with pytest.raises(waxy.InvalidNodeId):
         tree.layout(node)
 
 
+def test_invalid_node_id_absolute_layout() -> None:
+    tree, node = _removed_node()
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.absolute_layout(node)
+
+
+def test_invalid_node_id_lint() -> None:
+    tree, node = _removed_node()
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.lint(node)
+
+
+def test_invalid_node_id_visible_layouts() -> None:
+    tree, node = _removed_node()
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.visible_layouts(node)
+
+
 def test_invalid_node_id_unrounded_layout() -> None:
     tree, node = _removed_node()
     with pytest.raises(waxy.InvalidNodeId):
@@ -316,6 +359,12 @@ def test_invalid_node_id_print_tree() -> None:
         tree.print_tree(node)
 
 
+def test_invalid_node_id_format_tree() -> None:
+    tree, node = _removed_node()
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.format_tree(node)
+
+
 def test_invalid_node_id_compute_layout() -> None:
     tree, node = _removed_node()
     with pytest.raises(waxy.InvalidNodeId):
@@ -358,3 +407,86 @@ def test_measure_error_preserved_over_invalid_node_id() -> None:
     # not be swallowed and converted to InvalidNodeId.
     with pytest.raises(TypeError, match="measure callback failed"):
         tree.compute_layout(root, measure=bad_measure)
+
+
+# --- WrongTree ---
+
+
+def test_wrong_tree_is_invalid_node_id() -> None:
+    assert issubclass(waxy.WrongTree, waxy.InvalidNodeId)
+    assert issubclass(waxy.WrongTree, KeyError)
+
+
+def test_wrong_tree_single_node() -> None:
+    tree_a = waxy.TaffyTree()
+    tree_b = waxy.TaffyTree()
+    node = tree_a.new_leaf(waxy.Style())
+    tree_b.new_leaf(waxy.Style())  # same slot index in tree B
+
+    with pytest.raises(waxy.WrongTree, match="different TaffyTree"):
+        tree_b.layout(node)
+
+
+def test_wrong_tree_children_list() -> None:
+    tree_a = waxy.TaffyTree()
+    tree_b = waxy.TaffyTree()
+    foreign = tree_a.new_leaf(waxy.Style())
+    local = tree_b.new_leaf(waxy.Style())
+
+    with pytest.raises(waxy.WrongTree):
+        tree_b.new_with_children(waxy.Style(), [local, foreign])
+    with pytest.raises(waxy.WrongTree):
+        tree_b.add_child(local, foreign)
+    assert tree_b.total_node_count() == 1
+
+
+def test_wrong_tree_node_ids_not_equal() -> None:
+    tree_a = waxy.TaffyTree()
+    tree_b = waxy.TaffyTree()
+    assert tree_a.new_leaf(waxy.Style()) != tree_b.new_leaf(waxy.Style())
+
+
+# --- CycleDetected ---
+
+
+def test_cycle_detected_is_taffy_exception() -> None:
+    assert issubclass(waxy.CycleDetected, waxy.TaffyException)
+
+
+def _chain(depth: int) -> tuple[waxy.TaffyTree, list[waxy.NodeId]]:
+    """A tree whose nodes form a single root-to-leaf path, returned root first."""
+    tree = waxy.TaffyTree()
+    nodes = [tree.new_leaf(waxy.Style())]
+    for _ in range(depth - 1):
+        nodes.insert(0, tree.new_with_children(waxy.Style(), [nodes[0]]))
+    return tree, nodes
+
+
+def test_add_ancestor_to_descendant_raises() -> None:
+    tree, (root, middle, leaf) = _chain(3)
+    with pytest.raises(waxy.CycleDetected, match="ancestors"):
+        tree.add_child(leaf, root)
+    with pytest.raises(waxy.CycleDetected):
+        tree.insert_child_at_index(leaf, 0, middle)
+    with pytest.raises(waxy.CycleDetected):
+        tree.set_children(leaf, [root])
+    with pytest.raises(waxy.CycleDetected):
+        tree.replace_child_at_index(middle, 0, root)
+    assert tree.children(leaf) == []
+    assert tree.children(middle) == [leaf]
+
+
+def test_add_node_to_itself_raises() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(waxy.Style())
+    with pytest.raises(waxy.CycleDetected):
+        tree.add_child(node, node)
+
+
+def test_deep_tree_builds_without_cycle_errors() -> None:
+    tree, nodes = _chain(200)
+    assert tree.depth(nodes[-1]) == 199
+    extra = tree.new_leaf(waxy.Style())
+    tree.add_child(nodes[-1], extra)
+    tree.set_children(nodes[0], [nodes[1], tree.new_leaf(waxy.Style())])
+    assert tree.depth(extra) == 200
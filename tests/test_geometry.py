@@ -233,6 +233,28 @@ def test_rect_columns() -> None:
     ]
 
 
+def test_rect_pixels_orders() -> None:
+    r = waxy.Rect(0.0, 1.0, 0.0, 1.0)
+    assert list(r.pixels()) == list(r)
+    assert list(r.pixels("row")) == [
+        waxy.Point(0.0, 0.0),
+        waxy.Point(1.0, 0.0),
+        waxy.Point(0.0, 1.0),
+        waxy.Point(1.0, 1.0),
+    ]
+    assert list(r.pixels("column")) == [
+        waxy.Point(0.0, 0.0),
+        waxy.Point(0.0, 1.0),
+        waxy.Point(1.0, 0.0),
+        waxy.Point(1.0, 1.0),
+    ]
+
+
+def test_rect_pixels_invalid_order() -> None:
+    with pytest.raises(ValueError, match="order must be"):
+        waxy.Rect(0.0, 1.0, 0.0, 1.0).pixels("diagonal")  # type: ignore[arg-type]
+
+
 def test_rect_rows_empty() -> None:
     r = waxy.Rect(0.5, 0.6, 0.0, 1.0)
     assert [list(row) for row in r.rows()] == []
@@ -277,6 +299,62 @@ def test_line_len() -> None:
     assert len(waxy.Line(0.5, 0.6)) == 0
 
 
+@pytest.mark.parametrize(
+    ("value", "expected"),
+    [
+        (waxy.Size(0.0, 0.0), False),
+        (waxy.Size(10.0, 0.0), False),
+        (waxy.Size(10.0, 5.0), True),
+        (waxy.Rect(0.0, 0.0, 0.0, 0.0), False),
+        (waxy.Rect(left=0.0, right=10.0, top=5.0, bottom=5.0), False),
+        (waxy.Rect(left=10.0, right=0.0, top=0.0, bottom=5.0), False),
+        (waxy.Rect(left=0.5, right=0.6, top=0.0, bottom=1.0), True),
+        (waxy.Line(0.0, 0.0), False),
+        (waxy.Line(3.0, 1.0), False),
+        (waxy.Line(0.5, 0.6), True),
+        (waxy.Point(0.0, 0.0), True),
+        (waxy.Point(1.0, 2.0), True),
+    ],
+)
+def test_truthiness(value: object, expected: bool) -> None:
+    assert bool(value) is expected
+
+
+def test_line_intersection_disjoint() -> None:
+    a = waxy.Line(0.0, 2.0)
+    b = waxy.Line(3.0, 5.0)
+    assert a.intersection(b) is None
+    assert not a.overlaps(b)
+
+
+def test_line_intersection_partial() -> None:
+    assert waxy.Line(0.0, 4.0).intersection(waxy.Line(2.0, 6.0)) == waxy.Line(2.0, 4.0)
+
+
+def test_line_intersection_nested() -> None:
+    outer = waxy.Line(0.0, 10.0)
+    inner = waxy.Line(2.0, 3.0)
+    assert outer.intersection(inner) == inner
+    assert inner.intersection(outer) == inner
+    assert outer.overlaps(inner)
+
+
+def test_line_intersection_touching_is_zero_length() -> None:
+    a = waxy.Line(0.0, 5.0)
+    b = waxy.Line(5.0, 9.0)
+    assert a.intersection(b) == waxy.Line(5.0, 5.0)
+    assert b.intersection(a) == waxy.Line(5.0, 5.0)
+    assert a.overlaps(b)
+
+
+@pytest.mark.parametrize(
+    ("value", "expected"),
+    [(-1.0, 0.0), (0.0, 0.0), (2.5, 2.5), (5.0, 5.0), (7.0, 5.0)],
+)
+def test_line_clamp(value: float, expected: float) -> None:
+    assert waxy.Line(0.0, 5.0).clamp(value) == expected
+
+
 def test_size_area() -> None:
     assert waxy.Size(3.0, 4.0).area == 12.0
     assert waxy.Size(0.0, 5.0).area == 0.0
@@ -311,6 +389,210 @@ def test_rect_intersection_touching_edge() -> None:
     assert a.intersection(b) == waxy.Rect(2.0, 2.0, 0.0, 2.0)
 
 
+def test_rect_grid_cell() -> None:
+    r = waxy.Rect(left=10.0, right=110.0, top=0.0, bottom=50.0)
+    assert r.grid_cell(0, 0, 4, 2) == waxy.Rect(left=10.0, right=35.0, top=0.0, bottom=25.0)
+    assert r.grid_cell(3, 1, 4, 2) == waxy.Rect(left=85.0, right=110.0, top=25.0, bottom=50.0)
+
+
+def test_rect_grid_cell_with_gap() -> None:
+    r = waxy.Rect(left=0.0, right=100.0, top=0.0, bottom=100.0)
+    # Three 30px columns separated by 5px gaps.
+    assert r.grid_cell(1, 0, 3, 1, gap=5.0) == waxy.Rect(left=35.0, right=65.0, top=0.0, bottom=100.0)
+    assert r.grid_cell(2, 0, 3, 1, gap=5.0).right == 100.0
+
+
+@pytest.mark.parametrize(("col", "row"), [(4, 0), (0, 2), (10, 10)])
+def test_rect_grid_cell_out_of_range(col: int, row: int) -> None:
+    with pytest.raises(IndexError, match="outside a 4x2 grid"):
+        waxy.Rect(0.0, 100.0, 0.0, 100.0).grid_cell(col, row, 4, 2)
+
+
+def test_rect_grid_cell_empty_grid() -> None:
+    with pytest.raises(ValueError, match="at least one column"):
+        waxy.Rect(0.0, 100.0, 0.0, 100.0).grid_cell(0, 0, 0, 1)
+
+
+@pytest.mark.parametrize(
+    ("method", "expected"),
+    [
+        ("floor", waxy.Size(1.0, -3.0)),
+        ("ceil", waxy.Size(2.0, -2.0)),
+        ("round", waxy.Size(2.0, -3.0)),
+    ],
+)
+def test_size_rounding(method: str, expected: waxy.Size) -> None:
+    assert getattr(waxy.Size(1.5, -2.5), method)() == expected
+
+
+@pytest.mark.parametrize(
+    ("method", "expected"),
+    [
+        ("floor", waxy.Rect(left=0.0, right=10.0, top=1.0, bottom=20.0)),
+        ("ceil", waxy.Rect(left=1.0, right=11.0, top=2.0, bottom=21.0)),
+        ("round", waxy.Rect(left=0.0, right=11.0, top=2.0, bottom=20.0)),
+        ("round_outward", waxy.Rect(left=0.0, right=11.0, top=1.0, bottom=21.0)),
+        ("round_to_pixels", waxy.Rect(left=0.0, right=11.0, top=1.0, bottom=21.0)),
+    ],
+)
+def test_rect_rounding(method: str, expected: waxy.Rect) -> None:
+    r = waxy.Rect(left=0.4, right=10.5, top=1.5, bottom=20.2)
+    assert getattr(r, method)() == expected
+
+
+def test_rect_round_outward_covers_original() -> None:
+    r = waxy.Rect(left=0.6, right=0.9, top=3.1, bottom=3.4)
+    rounded = r.round_outward()
+    assert rounded == waxy.Rect(left=0.0, right=1.0, top=3.0, bottom=4.0)
+    assert rounded.contains(r.top_left)
+    assert rounded.contains(r.bottom_right)
+
+
+def test_rect_scale() -> None:
+    r = waxy.Rect(left=1.0, right=10.5, top=-2.0, bottom=4.25)
+    assert r.scale(2.0) == waxy.Rect(left=2.0, right=21.0, top=-4.0, bottom=8.5)
+
+
+def test_rect_scale_then_round_to_pixels() -> None:
+    r = waxy.Rect(left=0.3, right=10.3, top=0.7, bottom=5.2)
+    snapped = r.scale(1.5).round_to_pixels()
+    assert snapped == waxy.Rect(left=0.0, right=16.0, top=1.0, bottom=8.0)
+
+
+def test_point_unpacking() -> None:
+    x, y = waxy.Point(3.0, 4.0)
+    assert (x, y) == (3.0, 4.0)
+
+
+def test_point_length_distance_dot() -> None:
+    assert waxy.Point(3.0, 4.0).length() == 5.0
+    assert waxy.Point(0.0, 0.0).distance(waxy.Point(3.0, 4.0)) == 5.0
+    assert waxy.Point(1.0, 2.0).dot(waxy.Point(3.0, -4.0)) == -5.0
+
+
+def test_point_manhattan_distance() -> None:
+    assert waxy.Point(1.0, 2.0).manhattan_distance(waxy.Point(4.0, -2.0)) == 7.0
+    assert waxy.Point(3.0, 3.0).manhattan_distance(waxy.Point(3.0, 3.0)) == 0.0
+
+
+@pytest.mark.parametrize(
+    ("point", "expected"),
+    [
+        (waxy.Point(5.0, 5.0), waxy.Point(5.0, 5.0)),
+        (waxy.Point(-3.0, 5.0), waxy.Point(0.0, 5.0)),
+        (waxy.Point(15.0, 25.0), waxy.Point(10.0, 20.0)),
+        (waxy.Point(4.0, -1.0), waxy.Point(4.0, 0.0)),
+    ],
+)
+def test_rect_closest_point(point: waxy.Point, expected: waxy.Point) -> None:
+    r = waxy.Rect(left=0.0, right=10.0, top=0.0, bottom=20.0)
+    assert r.closest_point(point) == expected
+
+
+def test_rect_center() -> None:
+    r = waxy.Rect(left=10.0, right=20.0, top=0.0, bottom=5.0)
+    assert r.center == waxy.Point(15.0, 2.5)
+
+
+def test_rect_from_origin_size() -> None:
+    r = waxy.Rect.from_origin_size(waxy.Point(3.0, 4.0), waxy.Size(10.0, 20.0))
+    assert r == waxy.Rect(left=3.0, right=13.0, top=4.0, bottom=24.0)
+
+
+def test_rect_from_origin_size_round_trip() -> None:
+    r = waxy.Rect(left=-5.0, right=7.5, top=2.0, bottom=9.0)
+    assert waxy.Rect.from_origin_size(r.top_left, r.size) == r
+
+
+def test_rect_inflate() -> None:
+    r = waxy.Rect(left=10.0, right=20.0, top=10.0, bottom=30.0)
+    assert r.inflate(2.0, 3.0) == waxy.Rect(left=8.0, right=22.0, top=7.0, bottom=33.0)
+    assert r.deflate(2.0, 3.0) == waxy.Rect(left=12.0, right=18.0, top=13.0, bottom=27.0)
+    assert r.inflate(2.0, 3.0).deflate(2.0, 3.0) == r
+
+
+def test_rect_deflate_past_center_inverts() -> None:
+    r = waxy.Rect(left=0.0, right=10.0, top=0.0, bottom=10.0).deflate(6.0, 0.0)
+    assert r.width == -2.0
+
+
+def test_rect_translate() -> None:
+    r = waxy.Rect(left=0.0, right=10.0, top=0.0, bottom=5.0).translate(3.0, -1.0)
+    assert r == waxy.Rect(left=3.0, right=13.0, top=-1.0, bottom=4.0)
+
+
+def test_size_checked() -> None:
+    assert waxy.Size.checked(10.0, 0.0) == waxy.Size(10.0, 0.0)
+
+
+@pytest.mark.parametrize(("width", "height"), [(-1.0, 5.0), (5.0, -0.5)])
+def test_size_checked_rejects_negative(width: float, height: float) -> None:
+    with pytest.raises(ValueError, match="must not be negative"):
+        waxy.Size.checked(width, height)
+
+
+def test_rect_checked() -> None:
+    r = waxy.Rect.checked(1.0, 1.0, 2.0, 5.0)
+    assert r == waxy.Rect(left=1.0, right=1.0, top=2.0, bottom=5.0)
+
+
+def test_rect_checked_rejects_negative_width() -> None:
+    with pytest.raises(ValueError, match=r"right \(0\) must not be less than left \(10\)"):
+        waxy.Rect.checked(10.0, 0.0, 0.0, 10.0)
+
+
+def test_rect_checked_rejects_negative_height() -> None:
+    with pytest.raises(ValueError, match=r"bottom \(1\) must not be less than top \(2\)"):
+        waxy.Rect.checked(0.0, 10.0, 2.0, 1.0)
+
+
+def test_size_add_sub() -> None:
+    assert waxy.Size(1.0, 2.0) + waxy.Size(3.0, 4.0) == waxy.Size(4.0, 6.0)
+    assert waxy.Size(5.0, 5.0) - waxy.Size(1.0, 2.0) == waxy.Size(4.0, 3.0)
+
+
+def test_size_mul_div() -> None:
+    assert waxy.Size(2.0, 3.0) * 2 == waxy.Size(4.0, 6.0)
+    assert 2 * waxy.Size(2.0, 3.0) == waxy.Size(4.0, 6.0)
+    assert waxy.Size(4.0, 6.0) / 2 == waxy.Size(2.0, 3.0)
+
+
+def test_size_scale_to_fit() -> None:
+    image = waxy.Size(4.0, 3.0)
+    assert image.scale_to_fit(waxy.Size(6.0, 6.0)) == waxy.Size(6.0, 4.5)
+    assert image.scale_to_fit(waxy.Size(2.0, 2.0)) == waxy.Size(2.0, 1.5)
+
+
+def test_size_scale_to_cover() -> None:
+    image = waxy.Size(4.0, 3.0)
+    assert image.scale_to_cover(waxy.Size(6.0, 6.0)) == waxy.Size(8.0, 6.0)
+    assert image.scale_to_cover(waxy.Size(1.5, 1.5)) == waxy.Size(2.0, 1.5)
+
+
+@pytest.mark.parametrize(
+    ("size", "bounds"),
+    [
+        (waxy.Size(400.0, 300.0), waxy.Size(0.0, 100.0)),
+        (waxy.Size(400.0, 300.0), waxy.Size(0.0, 0.0)),
+        (waxy.Size(0.0, 300.0), waxy.Size(100.0, 100.0)),
+        (waxy.Size(400.0, 0.0), waxy.Size(100.0, 100.0)),
+    ],
+)
+def test_size_scale_to_fit_degenerate(size: waxy.Size, bounds: waxy.Size) -> None:
+    assert size.scale_to_fit(bounds) == waxy.Size(0.0, 0.0)
+
+
+def test_size_scale_to_cover_zero_size() -> None:
+    assert waxy.Size(0.0, 3.0).scale_to_cover(waxy.Size(10.0, 10.0)) == waxy.Size(0.0, 0.0)
+    assert waxy.Size(4.0, 3.0).scale_to_cover(waxy.Size(0.0, 0.0)) == waxy.Size(0.0, 0.0)
+
+
+def test_size_unpacking() -> None:
+    width, height = waxy.Size(2.0, 3.0)
+    assert (width, height) == (2.0, 3.0)
+    assert list(waxy.Size(5.0, 7.0)) == [5.0, 7.0]
+
+
 def test_point_mul() -> None:
     p = waxy.Point(2.0, 3.0) * 4.0
     assert p == waxy.Point(8.0, 12.0)
@@ -345,5 +627,23 @@ def test_line_contains() -> None:
     assert not line.contains(5.5)
 
 
+@pytest.mark.parametrize(
+    ("obj", "expected"),
+    [
+        (waxy.Size(1.0, 2.0), True),
+        (waxy.Size(float("nan"), 2.0), False),
+        (waxy.Size(1.0, float("inf")), False),
+        (waxy.Point(1.0, 2.0), True),
+        (waxy.Point(float("-inf"), 2.0), False),
+        (waxy.Point(1.0, float("nan")), False),
+        (waxy.Rect(0.0, 10.0, 0.0, 10.0), True),
+        (waxy.Rect(0.0, float("inf"), 0.0, 10.0), False),
+        (waxy.Rect(0.0, 10.0, float("nan"), 10.0), False),
+    ],
+)
+def test_is_finite(obj: waxy.Size | waxy.Point | waxy.Rect, expected: bool) -> None:
+    assert obj.is_finite() is expected
+
+
 def test_size_repr() -> None:
     assert "Size" in repr(waxy.Size(1.0, 2.0))
@@ -5,6 +5,8 @@ Layout.location is relative to the parent's border box origin (not content box).
 Layout.size is the border box size (excludes margin, includes border + padding + content).
 """
 
+import pytest
+
 import waxy
 
 
@@ -308,3 +310,230 @@ def test_layout_size_is_border_box_regardless_of_box_sizing() -> None:
     # ContentBox: style size 100 is content → layout.size = 120 (border box), content = 100
     assert cb_layout.size.width == 120.0
     assert cb_layout.content_box_width() == 100.0
+
+
+def test_absolute_layout_sums_ancestor_locations() -> None:
+    tree = waxy.TaffyTree()
+    leaf = tree.new_leaf(waxy.Style(size=(waxy.Length(20.0), waxy.Length(20.0))))
+    parent = tree.new_with_children(
+        waxy.Style(padding_left=waxy.Length(12.0), padding_top=waxy.Length(8.0)), [leaf]
+    )
+    root = tree.new_with_children(
+        waxy.Style(padding_left=waxy.Length(10.0), padding_top=waxy.Length(5.0)), [parent]
+    )
+
+    tree.compute_layout(root)
+
+    absolute = tree.absolute_layout(leaf)
+    assert absolute.location == waxy.Point(22.0, 13.0)
+    assert absolute.size == tree.layout(leaf).size
+    assert tree.absolute_layout(parent).location == waxy.Point(10.0, 5.0)
+
+
+def test_absolute_layout_of_root_is_unchanged() -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_leaf(waxy.Style(size=(waxy.Length(10.0), waxy.Length(10.0))))
+
+    tree.compute_layout(root)
+
+    assert tree.absolute_layout(root) == tree.layout(root)
+
+
+def test_iter_layout_accumulates_parent_offsets() -> None:
+    tree = waxy.TaffyTree()
+    leaf = tree.new_leaf(waxy.Style(size=(waxy.Length(20.0), waxy.Length(20.0))))
+    sibling = tree.new_leaf(waxy.Style(size=(waxy.Length(5.0), waxy.Length(5.0))))
+    parent = tree.new_with_children(
+        waxy.Style(padding_left=waxy.Length(12.0), padding_top=waxy.Length(8.0)), [leaf]
+    )
+    root = tree.new_with_children(
+        waxy.Style(padding_left=waxy.Length(10.0), padding_top=waxy.Length(5.0)),
+        [parent, sibling],
+    )
+
+    tree.compute_layout(root)
+
+    items = list(tree.iter_layout(root))
+    assert [node for node, _, _ in items] == [root, parent, leaf, sibling]
+    for node, layout, absolute in items:
+        assert layout == tree.layout(node)
+        assert absolute == tree.absolute_layout(node).location
+
+    _, root_layout, root_absolute = items[0]
+    assert root_absolute == root_layout.location
+    _, _, leaf_absolute = items[2]
+    assert leaf_absolute == waxy.Point(22.0, 13.0)
+
+
+def test_iter_layout_of_subtree_uses_absolute_locations() -> None:
+    tree = waxy.TaffyTree()
+    leaf = tree.new_leaf(waxy.Style(size=(waxy.Length(20.0), waxy.Length(20.0))))
+    parent = tree.new_with_children(waxy.Style(padding_left=waxy.Length(3.0)), [leaf])
+    root = tree.new_with_children(waxy.Style(padding_left=waxy.Length(10.0)), [parent])
+
+    tree.compute_layout(root)
+
+    assert [absolute for _, _, absolute in tree.iter_layout(parent)] == [
+        waxy.Point(10.0, 0.0),
+        waxy.Point(13.0, 0.0),
+    ]
+
+
+def test_iter_layout_requires_computed_layout() -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_leaf(waxy.Style())
+
+    with pytest.raises(ValueError, match="compute_layout"):
+        tree.iter_layout(root)
+
+
+def test_visible_nodes_culls_children_outside_viewport() -> None:
+    tree = waxy.TaffyTree()
+    rows = [
+        tree.new_leaf(waxy.Style(size=(waxy.Length(100.0), waxy.Length(50.0)))) for _ in range(10)
+    ]
+    root = tree.new_with_children(waxy.Style.flex_column(), rows)
+
+    tree.compute_layout(root)
+
+    # Scrolled down by 120: rows span y 100-150, 150-200 and 200-250 are in view.
+    viewport = waxy.Rect(left=0.0, right=100.0, top=120.0, bottom=220.0)
+    assert tree.visible_nodes(root, viewport) == [root, rows[2], rows[3], rows[4]]
+
+
+def test_visible_nodes_excludes_boxes_touching_viewport_edge() -> None:
+    tree = waxy.TaffyTree()
+    rows = [
+        tree.new_leaf(waxy.Style(size=(waxy.Length(100.0), waxy.Length(50.0)))) for _ in range(3)
+    ]
+    root = tree.new_with_children(waxy.Style.flex_column(), rows)
+
+    tree.compute_layout(root)
+
+    viewport = waxy.Rect(left=0.0, right=100.0, top=50.0, bottom=100.0)
+    assert tree.visible_nodes(root, viewport) == [root, rows[1]]
+
+
+def test_visible_nodes_requires_computed_layout() -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_leaf(waxy.Style())
+
+    with pytest.raises(ValueError, match="compute_layout"):
+        tree.visible_nodes(root, waxy.Rect(right=10.0, bottom=10.0))
+
+
+def test_layout_box_rects() -> None:
+    """border_box, padding_box, and content_box are nested rects positioned at location."""
+    tree = waxy.TaffyTree()
+
+    node = tree.new_leaf(
+        waxy.Style(
+            size=(waxy.Length(100.0), waxy.Length(100.0)),
+            border=(waxy.Length(3.0), waxy.Length(2.0), waxy.Length(4.0), waxy.Length(1.0)),
+            padding=(waxy.Length(7.0), waxy.Length(6.0), waxy.Length(8.0), waxy.Length(5.0)),
+        )
+    )
+    root = tree.new_with_children(
+        waxy.Style(padding_left=waxy.Length(10.0), padding_top=waxy.Length(20.0)), [node]
+    )
+
+    tree.compute_layout(root)
+    layout = tree.layout(node)
+
+    assert layout.border_box() == waxy.Rect(left=10.0, right=110.0, top=20.0, bottom=120.0)
+    assert layout.padding_box() == waxy.Rect(left=11.0, right=108.0, top=23.0, bottom=116.0)
+    assert layout.content_box() == waxy.Rect(left=16.0, right=102.0, top=30.0, bottom=108.0)
+    assert layout.content_box().width == layout.content_box_width()
+    assert layout.content_box().height == layout.content_box_height()
+
+
+def test_layout_box_rects_equal_without_padding_or_border() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(waxy.Style(size=(waxy.Length(30.0), waxy.Length(40.0))))
+
+    tree.compute_layout(node)
+    layout = tree.layout(node)
+
+    expected = waxy.Rect(left=0.0, right=30.0, top=0.0, bottom=40.0)
+    assert layout.border_box() == layout.padding_box() == layout.content_box() == expected
+
+
+@pytest.mark.parametrize(
+    ("point", "expected"),
+    [
+        (waxy.Point(20.0, 15.0), True),
+        (waxy.Point(10.0, 5.0), True),  # top-left corner
+        (waxy.Point(10.0, 20.0), True),  # left edge
+        (waxy.Point(25.0, 5.0), True),  # top edge
+        (waxy.Point(40.0, 20.0), False),  # right edge
+        (waxy.Point(25.0, 45.0), False),  # bottom edge
+        (waxy.Point(5.0, 20.0), False),
+        (waxy.Point(20.0, 50.0), False),
+    ],
+)
+def test_layout_contains(point: waxy.Point, expected: bool) -> None:
+    tree = waxy.TaffyTree()
+    child = tree.new_leaf(waxy.Style(size=(waxy.Length(30.0), waxy.Length(40.0))))
+    root = tree.new_with_children(
+        waxy.Style(padding_left=waxy.Length(10.0), padding_top=waxy.Length(5.0)), [child]
+    )
+
+    tree.compute_layout(root)
+
+    assert tree.layout(child).contains(point) is expected
+
+
+def overflow_layout(child_size: tuple[float, float]) -> waxy.Layout:
+    tree = waxy.TaffyTree()
+    child = tree.new_leaf(
+        waxy.Style(size=(waxy.Length(child_size[0]), waxy.Length(child_size[1])), flex_shrink=0.0)
+    )
+    root = tree.new_with_children(
+        waxy.Style(
+            size=(waxy.Length(50.0), waxy.Length(60.0)),
+            padding=waxy.Length(5.0),
+            border=waxy.Length(3.0),
+        ),
+        [child],
+    )
+    tree.compute_layout(root)
+    return tree.layout(root)
+
+
+def test_layout_overflows_when_content_exceeds_fixed_size() -> None:
+    layout = overflow_layout((80.0, 30.0))
+
+    assert layout.overflows() == (True, False)
+    # 80 wide content plus 5 padding each side, in a 50 - 2 * 3 = 44 wide padding box.
+    assert layout.scrollable_size() == waxy.Size(46.0, 0.0)
+
+
+def test_layout_does_not_overflow_when_content_fits_exactly() -> None:
+    # The content box is 50 - 2 * (5 + 3) = 34 by 60 - 2 * (5 + 3) = 44.
+    layout = overflow_layout((34.0, 44.0))
+
+    assert layout.overflows() == (False, False)
+    assert layout.scrollable_size() == waxy.Size(0.0, 0.0)
+
+
+def test_layout_to_dict() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(
+        waxy.Style(
+            size=(waxy.Length(30.0), waxy.Length(40.0)),
+            padding_left=waxy.Length(2.0),
+            margin_top=waxy.Length(5.0),
+        )
+    )
+    tree.compute_layout(node)
+
+    assert tree.layout(node).to_dict() == {
+        "order": 0,
+        "location": {"x": 0.0, "y": 0.0},
+        "size": {"width": 30.0, "height": 40.0},
+        "content_size": {"width": 2.0, "height": 0.0},
+        "scrollbar_size": {"width": 0.0, "height": 0.0},
+        "border": {"left": 0.0, "right": 0.0, "top": 0.0, "bottom": 0.0},
+        "padding": {"left": 2.0, "right": 0.0, "top": 0.0, "bottom": 0.0},
+        "margin": {"left": 0.0, "right": 0.0, "top": 5.0, "bottom": 0.0},
+    }
@@ -1,3 +1,4 @@
+import re
 import textwrap
 from dataclasses import dataclass
 from unittest.mock import MagicMock
@@ -39,6 +40,19 @@ def test_known_size_eq() -> None:
     assert waxy.KnownSize(width=1.0) != waxy.KnownSize(width=2.0)
 
 
+def test_known_size_fully_known_unwraps_to_itself() -> None:
+    kd = waxy.KnownSize(width=10.0, height=20.0)
+    assert kd.is_fully_known()
+    assert kd.unwrap_or(waxy.Size(1.0, 2.0)) == waxy.Size(10.0, 20.0)
+
+
+def test_known_size_partially_known_falls_back_per_axis() -> None:
+    assert not waxy.KnownSize(width=10.0).is_fully_known()
+    assert not waxy.KnownSize().is_fully_known()
+    assert waxy.KnownSize(width=10.0).unwrap_or(waxy.Size(1.0, 2.0)) == waxy.Size(10.0, 2.0)
+    assert waxy.KnownSize(height=20.0).unwrap_or(waxy.Size(1.0, 2.0)) == waxy.Size(1.0, 20.0)
+
+
 # --- AvailableSize ---
 
 
@@ -85,6 +99,54 @@ def test_available_size_eq() -> None:
     assert a == b
 
 
+def test_available_size_axis_predicates() -> None:
+    ad = waxy.AvailableSize(width=waxy.Definite(100.0), height=waxy.MinContent())
+    assert ad.width_is_definite()
+    assert not ad.width_is_min_content()
+    assert not ad.width_is_max_content()
+    assert not ad.height_is_definite()
+    assert ad.height_is_min_content()
+    assert not ad.height_is_max_content()
+    assert waxy.AvailableSize(waxy.MaxContent(), waxy.MaxContent()).height_is_max_content()
+
+
+def test_available_size_is_definite_per_axis() -> None:
+    ad = waxy.AvailableSize(waxy.Definite(100.0), waxy.MaxContent())
+    assert ad.is_definite() == (True, False)
+    assert waxy.AvailableSize(waxy.MinContent(), waxy.Definite(5.0)).is_definite() == (False, True)
+
+
+def test_available_size_definite_or_fills_content_axes() -> None:
+    ad = waxy.AvailableSize(waxy.Definite(100.0), waxy.MaxContent())
+    assert ad.definite_or(1.0, 2.0) == waxy.Size(100.0, 2.0)
+
+
+def test_available_size_map_definite() -> None:
+    ad = waxy.AvailableSize(waxy.Definite(100.0), waxy.MaxContent())
+    assert ad.map_definite(lambda v: v / 2) == waxy.AvailableSize(
+        waxy.Definite(50.0), waxy.MaxContent()
+    )
+
+
+@pytest.mark.parametrize(
+    ("space", "mode"),
+    [
+        (waxy.Definite(10.0), "definite"),
+        (waxy.MinContent(), "min-content"),
+        (waxy.MaxContent(), "max-content"),
+    ],
+)
+def test_available_size_axis_mode(space: waxy.AvailableSpaceValue, mode: str) -> None:
+    assert waxy.AvailableSize(space, waxy.Definite(0.0)).axis_mode(0) == mode
+    assert waxy.AvailableSize(waxy.Definite(0.0), space).axis_mode(1) == mode
+
+
+def test_available_size_axis_mode_invalid_axis() -> None:
+    ad = waxy.AvailableSize(waxy.MinContent(), waxy.MaxContent())
+    with pytest.raises(ValueError, match="axis must be 0"):
+        ad.axis_mode(2)
+
+
 # --- Definite / MinContent / MaxContent ---
 
 
@@ -177,6 +239,35 @@ def test_compute_layout_with_fixed_measure() -> None:
     assert layout.size.height == 100.0
 
 
+def test_simple_measure_ignores_context() -> None:
+    tree = waxy.TaffyTree[str]()
+    node = tree.new_leaf_with_context(waxy.Style(), "ignored")
+    root = tree.new_with_children(waxy.Style(display=waxy.Display.Flex), [node])
+    seen: list[tuple[waxy.KnownSize, waxy.AvailableSize]] = []
+
+    @waxy.simple_measure
+    def measure(known: waxy.KnownSize, available: waxy.AvailableSize) -> waxy.Size:
+        seen.append((known, available))
+        return waxy.Size(40.0, 12.0)
+
+    tree.compute_layout(root, measure=measure)
+
+    assert tree.layout(node).size == waxy.Size(40.0, 12.0)
+    assert seen
+    assert all(isinstance(known, waxy.KnownSize) for known, _ in seen)
+
+
+def test_simple_measure_propagates_errors() -> None:
+    tree = waxy.TaffyTree[str]()
+    node = tree.new_leaf_with_context(waxy.Style(), "text")
+
+    def measure(known: waxy.KnownSize, available: waxy.AvailableSize) -> waxy.Size:
+        raise RuntimeError("boom")
+
+    with pytest.raises(RuntimeError, match="boom"):
+        tree.compute_layout(node, measure=waxy.simple_measure(measure))
+
+
 def test_compute_layout_text_wrapping() -> None:
     tree = waxy.TaffyTree[TextContent]()
     # Give the text node an explicit width so it wraps
@@ -297,6 +388,55 @@ def test_measure_error_propagation() -> None:
         tree.compute_layout(root, measure=bad_measure)
 
 
+def test_measure_returns_measure_result() -> None:
+    tree = waxy.TaffyTree[str]()
+    node = tree.new_leaf_with_context(waxy.Style(), "text")
+    root = tree.new_with_children(waxy.Style(display=waxy.Display.Flex), [node])
+
+    tree.compute_layout(
+        root,
+        measure=lambda kd, avail, ctx: waxy.MeasureResult(waxy.Size(40.0, 12.0), first_baseline=9.0),
+    )
+
+    assert tree.layout(node).size == waxy.Size(40.0, 12.0)
+
+
+def test_measure_bad_return_type() -> None:
+    tree = waxy.TaffyTree[str]()
+    node = tree.new_leaf_with_context(waxy.Style(), "text")
+
+    with pytest.raises(TypeError, match="must return Size or MeasureResult, not tuple"):
+        tree.compute_layout(node, measure=lambda kd, avail, ctx: (40.0, 12.0))
+
+
+def test_measure_returning_string_names_the_node() -> None:
+    tree = waxy.TaffyTree[str]()
+    node = tree.new_leaf_with_context(waxy.Style(), "text")
+
+    with pytest.raises(TypeError, match=rf"not str \(measuring {re.escape(repr(node))}\)"):
+        tree.compute_layout(node, measure=lambda kd, avail, ctx: "40x12")
+
+
+def test_measure_returning_none() -> None:
+    tree = waxy.TaffyTree[str]()
+    node = tree.new_leaf_with_context(waxy.Style(), "text")
+
+    with pytest.raises(TypeError, match=rf"not None \(measuring {re.escape(repr(node))}\)"):
+        tree.compute_layout(node, measure=lambda kd, avail, ctx: None)
+
+
+def test_measure_result_fields() -> None:
+    result = waxy.MeasureResult(waxy.Size(1.0, 2.0))
+    assert result.size == waxy.Size(1.0, 2.0)
+    assert result.first_baseline is None
+    assert result == waxy.MeasureResult(waxy.Size(1.0, 2.0), None)
+    assert result != waxy.MeasureResult(waxy.Size(1.0, 2.0), 1.5)
+    assert hash(result) == hash(waxy.MeasureResult(waxy.Size(1.0, 2.0)))
+    assert repr(waxy.MeasureResult(waxy.Size(1.0, 2.0), 1.5)) == (
+        "MeasureResult(size=Size(width=1, height=2), first_baseline=1.5)"
+    )
+
+
 def test_compute_layout_with_available_space_param() -> None:
     tree = waxy.TaffyTree[TextContent]()
     node = tree.new_leaf_with_context(
@@ -424,3 +564,292 @@ def test_compute_layout_without_measure_still_works() -> None:
     layout = tree.layout(node)
     assert layout.size.width == 100.0
     assert layout.size.height == 50.0
+
+
+# --- Context factory ---
+
+
+def test_context_factory_populates_leaves_without_context() -> None:
+    tree = waxy.TaffyTree[FixedContent]()
+    a = tree.new_leaf(waxy.Style())
+    b = tree.new_leaf(waxy.Style())
+    root = tree.new_with_children(waxy.Style(display=waxy.Display.Flex), [a, b])
+
+    sizes = {a: FixedContent(width=30.0, height=10.0), b: FixedContent(width=50.0, height=20.0)}
+    tree.set_context_factory(lambda node: sizes[node])
+
+    def measure(
+        known: waxy.KnownSize,
+        available: waxy.AvailableSize,
+        context: FixedContent,
+    ) -> waxy.Size:
+        return waxy.Size(context.width, context.height)
+
+    tree.compute_layout(root, measure=measure)
+
+    assert tree.get_node_context(a) == sizes[a]
+    assert tree.layout(a).size.width == 30.0
+    assert tree.layout(b).size.width == 50.0
+
+
+def test_context_factory_skips_nodes_with_context() -> None:
+    tree = waxy.TaffyTree[FixedContent]()
+    existing = FixedContent(width=10.0, height=10.0)
+    leaf = tree.new_leaf_with_context(waxy.Style(), existing)
+    root = tree.new_with_children(waxy.Style(), [leaf])
+
+    factory = MagicMock(return_value=FixedContent(width=99.0, height=99.0))
+    tree.set_context_factory(factory)
+    tree.compute_layout(root, measure=lambda kd, avail, ctx: waxy.Size(ctx.width, ctx.height))
+
+    factory.assert_not_called()
+    assert tree.get_node_context(leaf) == existing
+
+
+def test_context_factory_only_called_for_measured_leaves() -> None:
+    tree = waxy.TaffyTree[FixedContent]()
+    hidden = tree.new_leaf(waxy.Style(display=waxy.Display.Nil))
+    fixed = tree.new_leaf(waxy.Style(size_width=waxy.Length(10.0), size_height=waxy.Length(10.0)))
+    measured = tree.new_leaf(waxy.Style())
+    root = tree.new_with_children(waxy.Style(display=waxy.Display.Flex), [hidden, fixed, measured])
+
+    factory = MagicMock(return_value=FixedContent(width=30.0, height=10.0))
+    tree.set_context_factory(factory)
+
+    def measure(
+        known: waxy.KnownSize,
+        available: waxy.AvailableSize,
+        context: FixedContent,
+    ) -> waxy.Size:
+        return waxy.Size(context.width, context.height)
+
+    tree.compute_layout(root, measure=measure)
+
+    factory.assert_called_once_with(measured)
+    assert tree.get_node_context(hidden) is None
+    assert tree.get_node_context(fixed) is None
+    assert tree.get_node_context(measured) == FixedContent(width=30.0, height=10.0)
+    assert tree.layout(measured).size.width == 30.0
+
+
+def test_context_factory_keeps_layout_cached() -> None:
+    tree = waxy.TaffyTree[FixedContent]()
+    leaf = tree.new_leaf(waxy.Style())
+    root = tree.new_with_children(waxy.Style(), [leaf])
+
+    factory = MagicMock(return_value=FixedContent(width=30.0, height=10.0))
+    tree.set_context_factory(factory)
+    measure = MagicMock(return_value=waxy.Size(30.0, 10.0))
+
+    tree.compute_layout(root, measure=measure)
+    assert not tree.dirty(root)
+
+    tree.compute_layout(root, measure=measure)
+    assert factory.call_count == 1
+    assert tree.last_layout_stats()["nodes_laid_out"] == 0
+
+
+def test_context_factory_returning_none_leaves_node_without_context() -> None:
+    tree = waxy.TaffyTree[FixedContent]()
+    leaf = tree.new_leaf(waxy.Style())
+    root = tree.new_with_children(waxy.Style(), [leaf])
+
+    tree.set_context_factory(lambda node: None)
+    tree.compute_layout(root, measure=lambda kd, avail, ctx: waxy.Size(1.0, 1.0))
+
+    assert tree.get_node_context(leaf) is None
+
+
+def test_context_factory_not_called_without_measure() -> None:
+    tree = waxy.TaffyTree[FixedContent]()
+    leaf = tree.new_leaf(waxy.Style())
+
+    factory = MagicMock(return_value=None)
+    tree.set_context_factory(factory)
+    tree.compute_layout(leaf)
+
+    factory.assert_not_called()
+
+
+def test_context_factory_error_propagation() -> None:
+    tree = waxy.TaffyTree[FixedContent]()
+    leaf = tree.new_leaf(waxy.Style())
+
+    def factory(node: waxy.NodeId) -> FixedContent:
+        msg = "factory failed!"
+        raise ValueError(msg)
+
+    tree.set_context_factory(factory)
+    with pytest.raises(ValueError, match="factory failed!"):
+        tree.compute_layout(leaf, measure=lambda kd, avail, ctx: waxy.Size(1.0, 1.0))
+
+
+def test_context_factory_clear() -> None:
+    tree = waxy.TaffyTree[FixedContent]()
+    leaf = tree.new_leaf(waxy.Style())
+
+    factory = MagicMock(return_value=None)
+    tree.set_context_factory(factory)
+    tree.set_context_factory(None)
+    tree.compute_layout(leaf, measure=lambda kd, avail, ctx: waxy.Size(1.0, 1.0))
+
+    factory.assert_not_called()
+
+
+# --- measure_node ---
+
+
+def test_measure_node_returns_intrinsic_size() -> None:
+    tree = waxy.TaffyTree[FixedContent]()
+    tooltip = tree.new_leaf_with_context(waxy.Style(), FixedContent(width=80.0, height=20.0))
+
+    size = tree.measure_node(
+        tooltip, measure=lambda kd, avail, ctx: waxy.Size(ctx.width, ctx.height)
+    )
+
+    assert size == waxy.Size(80.0, 20.0)
+
+
+def test_measure_node_respects_available_space() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(waxy.Style(size_width=waxy.Percent(0.5), size_height=waxy.Length(10.0)))
+
+    size = tree.measure_node(
+        node,
+        waxy.AvailableSize(width=waxy.Definite(200.0), height=waxy.MaxContent()),
+    )
+
+    assert size == waxy.Size(100.0, 10.0)
+
+
+def test_measure_node_min_vs_max_content_of_wrapping_flex_container() -> None:
+    tree = waxy.TaffyTree()
+    items = [
+        tree.new_leaf(waxy.Style(size_width=waxy.Length(30.0), size_height=waxy.Length(10.0)))
+        for _ in range(3)
+    ]
+    row = tree.new_with_children(
+        waxy.Style(display=waxy.Display.Flex, flex_wrap=waxy.FlexWrap.Wrap), items
+    )
+
+    max_content = tree.measure_node(
+        row, waxy.AvailableSize(width=waxy.MaxContent(), height=waxy.MaxContent())
+    )
+    min_content = tree.measure_node(
+        row, waxy.AvailableSize(width=waxy.MinContent(), height=waxy.MaxContent())
+    )
+
+    # With unlimited width every item fits on one line; at min-content each wraps onto its own.
+    assert max_content == waxy.Size(90.0, 10.0)
+    assert min_content == waxy.Size(30.0, 30.0)
+
+
+def test_measure_node_subtree_then_compute_root_restores_layout() -> None:
+    tree = waxy.TaffyTree()
+    spacer = tree.new_leaf(waxy.Style(size_width=waxy.Length(30.0), size_height=waxy.Length(5.0)))
+    popover = tree.new_leaf(waxy.Style(size_width=waxy.Length(40.0), size_height=waxy.Length(5.0)))
+    root = tree.new_with_children(waxy.Style(), [spacer, popover])
+
+    tree.compute_layout(root)
+    assert tree.layout(popover).location == waxy.Point(30.0, 0.0)
+
+    assert tree.measure_node(popover) == waxy.Size(40.0, 5.0)
+    assert tree.dirty(popover)
+
+    tree.compute_layout(root)
+    assert tree.layout(popover).location == waxy.Point(30.0, 0.0)
+
+
+def test_measure_node_error_propagation() -> None:
+    tree = waxy.TaffyTree[FixedContent]()
+    leaf = tree.new_leaf_with_context(waxy.Style(), FixedContent(width=1.0, height=1.0))
+
+    def measure(
+        known: waxy.KnownSize, available: waxy.AvailableSize, context: FixedContent
+    ) -> waxy.Size:
+        msg = "measure failed!"
+        raise RuntimeError(msg)
+
+    with pytest.raises(RuntimeError, match="measure failed!"):
+        tree.measure_node(leaf, measure=measure)
+
+
+# --- resizing ---
+
+
+def test_compute_layout_resizes_against_new_available_space() -> None:
+    tree = waxy.TaffyTree()
+    sidebar = tree.new_leaf(waxy.Style(size_width=waxy.Length(50.0)))
+    content = tree.new_leaf(waxy.Style(flex_grow=1.0))
+    root = tree.new_with_children(waxy.Style(size_width=waxy.Percent(1.0)), [sidebar, content])
+
+    tree.compute_layout(root, waxy.AvailableSize(waxy.Definite(200.0), waxy.Definite(100.0)))
+    assert tree.layout(content).size.width == 150.0
+
+    tree.compute_layout(root, waxy.AvailableSize(waxy.Definite(300.0), waxy.Definite(100.0)))
+
+    assert tree.layout(root).size.width == 300.0
+    assert tree.layout(content).size.width == 250.0
+
+
+def test_resize_does_not_remeasure_fixed_subtrees() -> None:
+    tree = waxy.TaffyTree[str]()
+    fixed = tree.new_leaf_with_context(waxy.Style(), "fixed")
+    fluid = tree.new_leaf_with_context(waxy.Style(flex_grow=1.0), "fluid")
+    panel = tree.new_with_children(
+        waxy.Style(size=(waxy.Length(50.0), waxy.Length(50.0))), [fixed]
+    )
+    root = tree.new_with_children(waxy.Style(size_width=waxy.Percent(1.0)), [panel, fluid])
+
+    calls: list[str] = []
+
+    def measure(known: waxy.KnownSize, available: waxy.AvailableSize, context: str) -> waxy.Size:
+        calls.append(context)
+        return waxy.Size(10.0, 10.0)
+
+    tree.compute_layout(
+        root, waxy.AvailableSize(waxy.Definite(200.0), waxy.Definite(100.0)), measure=measure
+    )
+    calls.clear()
+
+    tree.compute_layout(
+        root, waxy.AvailableSize(waxy.Definite(300.0), waxy.Definite(100.0)), measure=measure
+    )
+
+    assert "fixed" not in calls
+    assert "fluid" in calls
+    assert tree.last_layout_stats()["nodes_laid_out"] == 0
+
+
+def test_last_layout_stats_cold_vs_warm() -> None:
+    tree = waxy.TaffyTree[str]()
+    leaves = [tree.new_leaf_with_context(waxy.Style(), f"leaf{i}") for i in range(3)]
+    root = tree.new_with_children(waxy.Style(display=waxy.Display.Flex), leaves)
+    calls: list[str] = []
+
+    def measure(known: waxy.KnownSize, available: waxy.AvailableSize, context: str) -> waxy.Size:
+        calls.append(context)
+        return waxy.Size(10.0, 10.0)
+
+    assert tree.last_layout_stats() == {"nodes_laid_out": 0, "measure_calls": 0}
+
+    tree.compute_layout(root, measure=measure)
+    cold = tree.last_layout_stats()
+    assert cold["nodes_laid_out"] == 4
+    assert cold["measure_calls"] == len(calls) > 0
+
+    tree.compute_layout(root, measure=measure)
+    warm = tree.last_layout_stats()
+    assert warm["nodes_laid_out"] < cold["nodes_laid_out"]
+    assert warm == {"nodes_laid_out": 0, "measure_calls": 0}
+
+
+def test_last_layout_stats_counts_only_dirty_nodes() -> None:
+    tree = waxy.TaffyTree()
+    leaves = [tree.new_leaf(waxy.Style()) for _ in range(3)]
+    root = tree.new_with_children(waxy.Style(), leaves)
+    tree.compute_layout(root)
+
+    tree.mark_dirty(leaves[1])
+    tree.compute_layout(root)
+    assert tree.last_layout_stats() == {"nodes_laid_out": 2, "measure_calls": 0}
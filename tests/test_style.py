@@ -1,3 +1,6 @@
+import copy
+import pickle
+
 import pytest
 
 import waxy
@@ -150,6 +153,41 @@ def test_style_or_size_fields() -> None:
     assert result.size_height == waxy.Length(200.0)
 
 
+def _fully_set_style(**overrides: object) -> waxy.Style:
+    """A style with every field explicitly set, to its default unless overridden."""
+    default = waxy.Style()
+    fields: dict[str, object] = {}
+    for name in dir(default):
+        try:
+            default.is_set(name)
+        except ValueError:
+            continue
+        fields[name] = getattr(default, name)
+    return waxy.Style(**(fields | overrides))
+
+
+def test_style_or_fully_set_rhs_replaces_lhs() -> None:
+    full = _fully_set_style(display=waxy.Display.Grid)
+    lhs = waxy.Style(display=waxy.Display.Block, flex_grow=3.0)
+    assert lhs | full == full
+    assert (lhs | full).flex_grow == 0.0
+
+
+def test_style_merge_all_matches_or_fold() -> None:
+    layers = [
+        waxy.Style(display=waxy.Display.Block, flex_grow=1.0),
+        waxy.Style(flex_grow=2.0, padding_top=waxy.Length(4.0)),
+        waxy.Style(),
+        waxy.Style(display=waxy.Display.Grid, align_items=None),
+    ]
+    folded = layers[0] | layers[1] | layers[2] | layers[3]
+    assert waxy.Style.merge_all(*layers) == folded
+
+
+def test_style_merge_all_empty() -> None:
+    assert waxy.Style.merge_all() == waxy.Style()
+
+
 def test_style_or_does_not_mutate_operands() -> None:
     a = waxy.Style(display=waxy.Display.Flex)
     b = waxy.Style(display=waxy.Display.Grid)
@@ -182,6 +220,29 @@ def test_style_or_explicit_none_aspect_ratio() -> None:
     assert result.aspect_ratio is None
 
 
+@pytest.mark.parametrize("ratio", [0.0, -1.5, float("inf"), float("nan")])
+def test_style_rejects_invalid_aspect_ratio(ratio: float) -> None:
+    with pytest.raises(ValueError, match="aspect_ratio must be positive and finite"):
+        waxy.Style(aspect_ratio=ratio)
+
+
+def test_style_from_dict_rejects_invalid_aspect_ratio() -> None:
+    with pytest.raises(ValueError, match="aspect_ratio"):
+        waxy.Style.from_dict({"aspect_ratio": 0.0})
+
+
+def test_style_aspect_ratio_from() -> None:
+    ratio = waxy.Style.aspect_ratio_from(16, 9)
+    assert ratio == pytest.approx(16 / 9)
+    assert waxy.Style(aspect_ratio=ratio).aspect_ratio == pytest.approx(16 / 9)
+
+
+@pytest.mark.parametrize(("width", "height"), [(16.0, 0.0), (0.0, 9.0), (-16.0, 9.0)])
+def test_style_aspect_ratio_from_rejects_degenerate_boxes(width: float, height: float) -> None:
+    with pytest.raises(ValueError):
+        waxy.Style.aspect_ratio_from(width, height)
+
+
 def test_style_or_grid_tracks() -> None:
     a = waxy.Style(
         grid_template_columns=[waxy.Length(100.0)],
@@ -194,9 +255,82 @@ def test_style_or_grid_tracks() -> None:
     assert len(result.grid_template_rows) == 1
 
 
+def test_style_and_keeps_commonly_set_fields() -> None:
+    a = waxy.Style(display=waxy.Display.Flex, flex_grow=1.0, padding_top=waxy.Length(4.0))
+    b = waxy.Style(display=waxy.Display.Flex, flex_grow=1.0, margin_top=waxy.Length(2.0))
+    common = a & b
+    assert common.set_field_names() == ["display", "flex_grow"]
+    assert common == waxy.Style(display=waxy.Display.Flex, flex_grow=1.0)
+
+
+def test_style_and_drops_fields_set_to_different_values() -> None:
+    a = waxy.Style(display=waxy.Display.Flex, flex_grow=1.0)
+    b = waxy.Style(display=waxy.Display.Flex, flex_grow=2.0)
+    assert (a & b).set_field_names() == ["display"]
+
+
+def test_style_and_factors_out_shared_sibling_properties() -> None:
+    siblings = [
+        waxy.Style(flex_grow=1.0, padding_left=waxy.Length(2.0), size_width=waxy.Length(10.0)),
+        waxy.Style(flex_grow=1.0, padding_left=waxy.Length(2.0), size_width=waxy.Length(20.0)),
+        waxy.Style(flex_grow=1.0, padding_left=waxy.Length(2.0)),
+    ]
+    shared = siblings[0] & siblings[1] & siblings[2]
+    assert shared == waxy.Style(flex_grow=1.0, padding_left=waxy.Length(2.0))
+    for style in siblings:
+        assert shared | style == style
+
+
+def test_style_and_with_empty_style_is_empty() -> None:
+    assert (waxy.Style(flex_grow=1.0) & waxy.Style()).set_field_names() == []
+
+
 def test_style_repr() -> None:
     s = waxy.Style()
-    assert "Style" in repr(s)
+    assert repr(s) == "Style()"
+
+
+def test_style_repr_lists_set_fields() -> None:
+    s = waxy.Style(
+        display=waxy.Display.Flex,
+        size_width=waxy.Length(100.0),
+        margin_top=waxy.AUTO,
+    )
+    assert repr(s) == "Style(display=Display.Flex, size_width=Length(100), margin_top=Auto())"
+
+
+def test_style_repr_includes_explicit_defaults_and_none() -> None:
+    s = waxy.Style(flex_grow=0.0, align_items=None)
+    assert repr(s) == "Style(align_items=None, flex_grow=0.0)"
+
+
+@pytest.mark.parametrize(
+    "style",
+    [
+        waxy.Style(),
+        waxy.Style(display=waxy.Display.Nil, position=waxy.Position.Absolute),
+        waxy.Style(padding_left=waxy.Percent(0.25), aspect_ratio=1.5),
+        waxy.Style(
+            grid_template_columns=[waxy.Fraction(1.0), waxy.Minmax(waxy.Length(10.0), waxy.AUTO)],
+            grid_row=waxy.GridPlacement(start=waxy.GridLine(1), end=waxy.GridSpan(2)),
+        ),
+    ],
+)
+def test_style_repr_eval_round_trip(style: waxy.Style) -> None:
+    assert eval(repr(style), vars(waxy)) == style
+
+
+def test_style_rich_repr_empty() -> None:
+    assert list(waxy.Style().__rich_repr__()) == []
+
+
+def test_style_rich_repr_set_fields_in_declaration_order() -> None:
+    s = waxy.Style(flex_grow=1.0, display=waxy.Display.Grid, align_self=None)
+    assert list(s.__rich_repr__()) == [
+        ("display", waxy.Display.Grid),
+        ("align_self", None),
+        ("flex_grow", 1.0),
+    ]
 
 
 def test_style_eq_default() -> None:
@@ -215,6 +349,20 @@ def test_style_eq_different_fields() -> None:
     assert a != b
 
 
+def test_style_eq_explicit_default_differs_from_unset() -> None:
+    # flex_grow=0.0 is taffy's default, but setting it explicitly changes how
+    # the style merges, so it is not equal to a style that leaves it unset.
+    explicit = waxy.Style(flex_grow=0.0)
+    assert explicit != waxy.Style()
+    assert explicit == waxy.Style(flex_grow=0.0)
+    assert len({explicit, waxy.Style()}) == 2
+
+
+def test_style_eq_enum_values() -> None:
+    assert waxy.Style(display=waxy.Display.Flex) == waxy.Style(display=waxy.Display.Flex)
+    assert waxy.Style(display=waxy.Display.Flex) != waxy.Style(display=waxy.Display.Grid)
+
+
 def test_style_eq_non_style() -> None:
     assert waxy.Style() != "not a style"
     assert waxy.Style() != 42
@@ -228,6 +376,27 @@ def test_style_hash() -> None:
     assert len({a, b, waxy.Style()}) == 2
 
 
+def test_style_stable_hash_matches_eq() -> None:
+    a = waxy.Style(display=waxy.Display.Grid, flex_grow=2.0)
+    b = waxy.Style(display=waxy.Display.Grid, flex_grow=2.0)
+    assert a.stable_hash() == b.stable_hash()
+    assert waxy.Style(flex_grow=0.0).stable_hash() == waxy.Style(flex_grow=-0.0).stable_hash()
+    # Explicitly setting a default value changes the set-field mask.
+    assert waxy.Style().stable_hash() != waxy.Style(display=waxy.Display.Flex).stable_hash()
+
+
+def test_style_stable_hash_is_pinned() -> None:
+    # These values must not change between runs, platforms, or Rust releases;
+    # only a change to the Style fields themselves should ever require updating them.
+    assert waxy.Style().stable_hash() == 16089814888654656680
+    style = waxy.Style(
+        display=waxy.Display.Flex,
+        size_width=waxy.Length(100),
+        grid_template_columns=[waxy.Fraction(1.0)],
+    )
+    assert style.stable_hash() == 17068040545861922656
+
+
 def test_style_hash_float_normalization() -> None:
     # flex_grow is a plain f32; taffy's derived PartialEq uses IEEE float
     # equality where 0.0 == -0.0, so equal styles must also hash identically.
@@ -258,3 +427,643 @@ def test_style_hash_grid_tracks() -> None:
 
     # Different grid tracks hash differently and work as set/dict keys.
     assert len({a, c}) == 2
+
+
+def test_style_to_dict_empty() -> None:
+    assert waxy.Style().to_dict() == {}
+
+
+def test_style_to_dict_only_set_fields() -> None:
+    s = waxy.Style(
+        display=waxy.Display.Grid,
+        size_width=waxy.Length(100.0),
+        align_items=None,
+        grid_template_columns=[waxy.Fraction(1.0), waxy.AUTO],
+    )
+    assert s.to_dict() == {
+        "display": "Grid",
+        "size_width": waxy.Length(100.0),
+        "align_items": None,
+        "grid_template_columns": [waxy.Fraction(1.0), waxy.AUTO],
+    }
+
+
+def test_style_to_dict_display_none_uses_python_name() -> None:
+    assert waxy.Style(display=waxy.Display.Nil).to_dict() == {"display": "Nil"}
+
+
+@pytest.mark.parametrize(
+    "style",
+    [
+        waxy.Style(),
+        waxy.Style(display=waxy.Display.Block, position=waxy.Position.Absolute),
+        waxy.Style(flex_grow=1.0, flex_basis=waxy.Percent(0.5), padding_left=waxy.Length(4.0)),
+        waxy.Style(aspect_ratio=None, justify_content=waxy.AlignContent.SpaceBetween),
+        waxy.Style(
+            grid_row=waxy.GridPlacement(start=waxy.GridLine(1), end=waxy.GridSpan(2)),
+            grid_auto_rows=[waxy.Minmax(waxy.Length(10.0), waxy.Fraction(1.0))],
+        ),
+    ],
+)
+def test_style_dict_round_trip(style: waxy.Style) -> None:
+    restored = waxy.Style.from_dict(style.to_dict())
+    assert restored == style
+    assert restored.to_dict() == style.to_dict()
+
+
+def test_style_from_dict_preserves_set_fields_for_merge() -> None:
+    base = waxy.Style(flex_grow=2.0)
+    overlay = waxy.Style.from_dict({"display": "Block"})
+    merged = base | overlay
+    assert merged.flex_grow == 2.0
+    assert merged.display == waxy.Display.Block
+
+
+def test_style_from_dict_accepts_enum_members() -> None:
+    s = waxy.Style.from_dict({"display": waxy.Display.Grid})
+    assert s.display == waxy.Display.Grid
+
+
+def test_style_from_dict_unknown_field() -> None:
+    with pytest.raises(ValueError, match="unknown Style field"):
+        waxy.Style.from_dict({"colour": "red"})
+
+
+def test_style_from_dict_unknown_variant() -> None:
+    with pytest.raises(ValueError, match="unknown Display variant"):
+        waxy.Style.from_dict({"display": "Inline"})
+
+
+def test_style_diff_identical_is_empty() -> None:
+    s = waxy.Style(display=waxy.Display.Flex, flex_grow=1.0)
+    assert s.diff(s).set_field_names() == []
+
+
+@pytest.mark.parametrize(
+    ("old", "new", "field"),
+    [
+        (waxy.Style(flex_grow=1.0), waxy.Style(flex_grow=2.0), "flex_grow"),
+        (waxy.Style(), waxy.Style(display=waxy.Display.Grid), "display"),
+        (waxy.Style(size_width=waxy.Length(10.0)), waxy.Style(size_width=waxy.Percent(0.5)), "size_width"),
+        (
+            waxy.Style(grid_template_columns=[waxy.Fraction(1.0)]),
+            waxy.Style(grid_template_columns=[waxy.Fraction(2.0)]),
+            "grid_template_columns",
+        ),
+    ],
+)
+def test_style_diff_single_field(old: waxy.Style, new: waxy.Style, field: str) -> None:
+    changed = old.diff(new)
+    assert changed.set_field_names() == [field]
+    assert getattr(changed, field) == getattr(new, field)
+
+
+def test_style_diff_set_state_only() -> None:
+    """Explicitly setting a field to its default still counts as a difference."""
+    changed = waxy.Style().diff(waxy.Style(flex_grow=0.0))
+    assert changed.set_field_names() == ["flex_grow"]
+    assert changed.flex_grow == 0.0
+
+
+def test_style_diff_applied_to_old_gives_new_values() -> None:
+    old = waxy.Style(flex_grow=1.0, padding_top=waxy.Length(4.0))
+    new = waxy.Style(flex_grow=1.0, margin_top=waxy.Length(2.0))
+    changed = old.diff(new)
+    assert changed.set_field_names() == ["margin_top", "padding_top"]
+    assert (old | changed).padding_top == new.padding_top
+    assert (old | changed).margin_top == new.margin_top
+
+
+def test_style_unset_removes_field() -> None:
+    s = waxy.Style(display=waxy.Display.Grid, flex_grow=1.0)
+    u = s.unset("display")
+    assert "display" not in u.to_dict()
+    assert u.display == waxy.Display.Flex  # back to taffy default
+    assert u.flex_grow == 1.0
+    assert u == waxy.Style(flex_grow=1.0)
+
+
+def test_style_unset_does_not_mutate() -> None:
+    s = waxy.Style(display=waxy.Display.Grid)
+    s.unset("display")
+    assert s.display == waxy.Display.Grid
+
+
+def test_style_unset_multiple_and_unset_fields() -> None:
+    s = waxy.Style(size_width=waxy.Length(10.0), grid_auto_rows=[waxy.Length(5.0)])
+    assert s.unset("size_width", "grid_auto_rows", "margin_top") == waxy.Style()
+
+
+def test_style_unset_lets_lower_layer_show_through() -> None:
+    base = waxy.Style(display=waxy.Display.Block)
+    overlay = waxy.Style(display=waxy.Display.Grid, flex_grow=2.0).unset("display")
+    merged = base | overlay
+    assert merged.display == waxy.Display.Block
+    assert merged.flex_grow == 2.0
+
+
+def test_style_unset_unknown_field() -> None:
+    with pytest.raises(ValueError, match="colour"):
+        waxy.Style().unset("colour")
+
+
+def test_style_clear_kwarg_unsets_fields() -> None:
+    s = waxy.Style(display=waxy.Display.Grid, flex_grow=1.0, clear=["display"])
+    assert s.set_field_names() == ["flex_grow"]
+    assert s.display == waxy.Display.Flex  # back to taffy default
+
+
+def test_style_clear_kwarg_applies_after_shorthands() -> None:
+    s = waxy.Style(margin=waxy.Length(4.0), clear=["margin_top"])
+    assert not s.is_set("margin_top")
+    assert s.is_set("margin_left")
+    assert s.margin_top == waxy.Length(0.0)
+
+
+def test_style_clear_kwarg_unknown_field() -> None:
+    with pytest.raises(ValueError, match="colour"):
+        waxy.Style(clear=["colour"])
+
+
+@pytest.mark.parametrize("field", ["aspect_ratio", "align_items", "justify_content"])
+def test_style_nullable_fields_are_set_by_none(field: str) -> None:
+    assert waxy.Style(**{field: None}).set_field_names() == [field]
+
+
+def test_style_none_for_other_fields_leaves_them_unset() -> None:
+    assert waxy.Style(display=None, flex_grow=None).is_empty()
+
+
+def test_style_with_methods_chain_without_modifying_original() -> None:
+    original = waxy.Style(flex_shrink=0.0)
+    chained = original.with_display(waxy.Display.Flex).with_flex_grow(1.0)
+
+    assert chained == waxy.Style(display=waxy.Display.Flex, flex_grow=1.0, flex_shrink=0.0)
+    assert original == waxy.Style(flex_shrink=0.0)
+
+
+def test_style_with_shorthand_matches_constructor() -> None:
+    base = waxy.Style(margin_top=waxy.Length(1.0), display=waxy.Display.Grid)
+    margin = (waxy.Length(1.0), waxy.Length(2.0), waxy.Length(3.0), waxy.Length(4.0))
+    assert base.with_margin(margin) == base | waxy.Style(margin=margin)
+    assert base.with_size((waxy.Length(10.0), waxy.AUTO)) == base | waxy.Style(
+        size=(waxy.Length(10.0), waxy.AUTO)
+    )
+
+
+def test_style_with_none_follows_constructor() -> None:
+    style = waxy.Style(display=waxy.Display.Grid, aspect_ratio=2.0)
+    assert style.with_display(None) == style
+    assert style.with_aspect_ratio(None).set_field_names() == ["aspect_ratio", "display"]
+    assert style.with_aspect_ratio(None).aspect_ratio is None
+
+
+def test_style_with_invalid_value() -> None:
+    with pytest.raises(TypeError):
+        waxy.Style().with_display("flex")  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="aspect_ratio"):
+        waxy.Style().with_aspect_ratio(0.0)
+
+
+def test_style_is_empty() -> None:
+    s = waxy.Style(display=waxy.Display.Flex, flex_grow=1.0)
+    s = s | waxy.Style(padding_left=waxy.Length(4.0))
+    assert not s.is_empty()
+    assert waxy.Style().is_empty()
+    assert s.unset(*s.set_field_names()).is_empty()
+
+
+def test_style_is_empty_counts_explicit_defaults() -> None:
+    assert waxy.Style().is_empty()
+    assert not waxy.Style(flex_grow=0.0).is_empty()
+
+
+def test_style_validate_passes_for_sensible_style() -> None:
+    waxy.Style(flex_grow=1.0, flex_shrink=0.0, scrollbar_width=10.0, aspect_ratio=2.0).validate()
+    waxy.Style().validate()
+
+
+@pytest.mark.parametrize(
+    ("style", "match"),
+    [
+        (waxy.Style(flex_shrink=-1.0), "flex_shrink must be non-negative and finite, got -1"),
+        (waxy.Style(flex_grow=float("inf")), "flex_grow must be non-negative and finite, got inf"),
+        (waxy.Style(scrollbar_width=-2.0), "scrollbar_width must be non-negative and finite"),
+    ],
+)
+def test_style_validate_rejects_out_of_range_values(style: waxy.Style, match: str) -> None:
+    with pytest.raises(ValueError, match=match):
+        style.validate()
+
+
+def test_style_resolve_padding_lengths() -> None:
+    s = waxy.Style(
+        padding_left=waxy.Length(1.0),
+        padding_right=waxy.Length(2.0),
+        padding_top=waxy.Length(3.0),
+        padding_bottom=waxy.Length(4.0),
+    )
+    assert s.resolve_padding(500.0) == waxy.Rect(left=1.0, right=2.0, top=3.0, bottom=4.0)
+
+
+def test_style_resolve_padding_vertical_percentages_use_inline_size() -> None:
+    s = waxy.Style(
+        padding_left=waxy.Percent(0.1),
+        padding_top=waxy.Percent(0.25),
+        padding_bottom=waxy.Percent(0.5),
+    )
+    assert s.resolve_padding(200.0) == waxy.Rect(left=20.0, right=0.0, top=50.0, bottom=100.0)
+
+
+def test_style_resolve_padding_matches_layout() -> None:
+    tree = waxy.TaffyTree()
+    style = waxy.Style(padding_top=waxy.Percent(0.1), padding_left=waxy.Percent(0.1))
+    child = tree.new_leaf(style)
+    root = tree.new_with_children(
+        waxy.Style(size_width=waxy.Length(300.0), size_height=waxy.Length(100.0)), [child]
+    )
+    tree.compute_layout(root)
+    assert tree.layout(child).padding == style.resolve_padding(300.0)
+
+
+def test_style_set_field_names_empty() -> None:
+    assert waxy.Style().set_field_names() == []
+
+
+def test_style_set_field_names_single() -> None:
+    assert waxy.Style(gap_width=waxy.Length(4)).set_field_names() == ["gap_width"]
+
+
+def test_style_set_field_names_sorted() -> None:
+    s = waxy.Style(position=waxy.Position.Absolute, align_items=None, flex_grow=0.0)
+    assert s.set_field_names() == ["align_items", "flex_grow", "position"]
+
+
+def test_style_is_set() -> None:
+    s = waxy.Style(flex_grow=0.0)
+    assert s.is_set("flex_grow")
+    assert not s.is_set("flex_shrink")
+
+
+def test_style_is_set_unknown_field() -> None:
+    with pytest.raises(ValueError, match="colour"):
+        waxy.Style().is_set("colour")
+
+
+def test_style_set_field_names_cover_every_kwarg() -> None:
+    style = _fully_set_style()
+    names = style.set_field_names()
+    assert len(names) == 50
+    assert all(style.is_set(name) for name in names)
+    assert waxy.Style(**{name: getattr(style, name) for name in names}) == style
+
+
+def _grid_style() -> waxy.Style:
+    return waxy.Style(
+        display=waxy.Display.Grid,
+        flex_grow=0.0,
+        grid_template_columns=[waxy.Fraction(1.0), waxy.Minmax(waxy.Length(10.0), waxy.AUTO)],
+        grid_auto_rows=[waxy.FitContent(waxy.Percent(0.5))],
+        grid_column=waxy.GridPlacement(start=waxy.GridSpan(2)),
+    )
+
+
+@pytest.mark.parametrize("copier", [copy.copy, copy.deepcopy])
+def test_style_copy(copier: object) -> None:
+    s = _grid_style()
+    c = copier(s)  # type: ignore[operator]
+    assert c == s
+    assert c is not s
+    assert c.set_field_names() == s.set_field_names()
+
+
+def test_style_deepcopy_independent_of_original() -> None:
+    s = _grid_style()
+    c = copy.deepcopy(s).unset("grid_template_columns")
+    assert s.grid_template_columns == [
+        waxy.Fraction(1.0),
+        waxy.Minmax(waxy.Length(10.0), waxy.AUTO),
+    ]
+    assert c != s
+
+
+@pytest.mark.parametrize("style", [waxy.Style(), _grid_style()])
+def test_style_pickle_round_trip(style: waxy.Style) -> None:
+    restored = pickle.loads(pickle.dumps(style))
+    assert restored == style
+    assert restored.set_field_names() == style.set_field_names()
+    assert restored.grid_template_columns == style.grid_template_columns
+
+
+def test_style_interpolate_lengths_and_floats() -> None:
+    a = waxy.Style(size_width=waxy.Length(0.0), flex_grow=0.0, padding_top=waxy.Percent(0.0))
+    b = waxy.Style(size_width=waxy.Length(100.0), flex_grow=2.0, padding_top=waxy.Percent(0.5))
+    mid = a.interpolate(b, 0.25)
+    assert mid.size_width == waxy.Length(25.0)
+    assert mid.flex_grow == 0.5
+    assert mid.padding_top == waxy.Percent(0.125)
+    assert mid.set_field_names() == ["flex_grow", "padding_top", "size_width"]
+
+
+def test_style_interpolate_endpoints() -> None:
+    a = waxy.Style(size_width=waxy.Length(10.0), display=waxy.Display.Block)
+    b = waxy.Style(size_width=waxy.Length(20.0), display=waxy.Display.Grid)
+    assert a.interpolate(b, 0.0) == a
+    assert a.interpolate(b, 1.0) == b
+    assert a.interpolate(b, -1.0) == a
+    assert a.interpolate(b, 2.0) == b
+
+
+def test_style_interpolate_discrete_switches_at_half() -> None:
+    a = waxy.Style(display=waxy.Display.Block, size_width=waxy.Length(10.0))
+    b = waxy.Style(display=waxy.Display.Grid, size_width=waxy.Percent(0.5))
+    assert a.interpolate(b, 0.49).display == waxy.Display.Block
+    assert a.interpolate(b, 0.5).display == waxy.Display.Grid
+    # Mixed units don't interpolate.
+    assert a.interpolate(b, 0.49).size_width == waxy.Length(10.0)
+    assert a.interpolate(b, 0.5).size_width == waxy.Percent(0.5)
+
+
+def test_style_interpolate_fields_set_on_one_side() -> None:
+    a = waxy.Style(flex_grow=1.0)
+    b = waxy.Style(size_height=waxy.Length(50.0))
+    mid = a.interpolate(b, 0.1)
+    assert mid == waxy.Style(flex_grow=1.0, size_height=waxy.Length(50.0))
+
+
+def test_style_interpolate_nan() -> None:
+    with pytest.raises(ValueError, match="NaN"):
+        waxy.Style().interpolate(waxy.Style(), float("nan"))
+
+
+def test_style_animate_brackets_keyframes() -> None:
+    frames = [
+        (0.0, waxy.Style(size_width=waxy.Length(0.0))),
+        (0.5, waxy.Style(size_width=waxy.Length(100.0))),
+        (1.0, waxy.Style(size_width=waxy.Length(200.0))),
+    ]
+    assert waxy.Style.animate(frames, 0.25).size_width == waxy.Length(50.0)
+    assert waxy.Style.animate(frames, 0.5).size_width == waxy.Length(100.0)
+    assert waxy.Style.animate(frames, 0.75).size_width == waxy.Length(150.0)
+    assert waxy.Style.animate(frames, -1.0) == frames[0][1]
+    assert waxy.Style.animate(frames, 5.0) == frames[-1][1]
+
+
+def test_style_animate_single_frame() -> None:
+    style = waxy.Style(flex_grow=3.0)
+    assert waxy.Style.animate([(0.3, style)], 0.9) == style
+
+
+def test_style_animate_empty() -> None:
+    with pytest.raises(ValueError, match="at least one keyframe"):
+        waxy.Style.animate([], 0.5)
+
+
+def test_style_animate_unsorted() -> None:
+    with pytest.raises(ValueError, match="sorted"):
+        waxy.Style.animate([(1.0, waxy.Style()), (0.0, waxy.Style())], 0.5)
+
+
+@pytest.mark.parametrize("shorthand", ["inset", "margin", "padding", "border"])
+def test_style_edge_shorthand_single_value(shorthand: str) -> None:
+    s = waxy.Style(**{shorthand: waxy.Length(4.0)})
+    assert getattr(s, shorthand) == (waxy.Length(4.0),) * 4
+    assert s.set_field_names() == sorted(
+        f"{shorthand}_{edge}" for edge in ("left", "right", "top", "bottom")
+    )
+
+
+@pytest.mark.parametrize("shorthand", ["inset", "margin", "padding", "border"])
+def test_style_edge_shorthand_css_order(shorthand: str) -> None:
+    top, right, bottom, left = (waxy.Length(v) for v in (1.0, 2.0, 3.0, 4.0))
+    s = waxy.Style(**{shorthand: (top, right, bottom, left)})
+    assert getattr(s, f"{shorthand}_top") == top
+    assert getattr(s, f"{shorthand}_right") == right
+    assert getattr(s, f"{shorthand}_bottom") == bottom
+    assert getattr(s, f"{shorthand}_left") == left
+    assert getattr(s, shorthand) == (top, right, bottom, left)
+
+
+def test_style_margin_shorthand_accepts_auto() -> None:
+    s = waxy.Style(margin=(waxy.Length(0.0), waxy.AUTO, waxy.Length(0.0), waxy.AUTO))
+    assert s.margin_left == waxy.AUTO
+    assert s.margin_right == waxy.AUTO
+
+
+def test_style_padding_shorthand_rejects_auto() -> None:
+    with pytest.raises(TypeError):
+        waxy.Style(padding=waxy.AUTO)
+
+
+def test_style_edge_shorthand_wrong_arity() -> None:
+    with pytest.raises(ValueError, match="length 4"):
+        waxy.Style(margin=(waxy.Length(1.0), waxy.Length(2.0)))
+
+
+def test_style_edge_longhand_overrides_shorthand() -> None:
+    s = waxy.Style(padding=waxy.Length(1.0), padding_top=waxy.Length(9.0))
+    assert s.padding == (waxy.Length(9.0), waxy.Length(1.0), waxy.Length(1.0), waxy.Length(1.0))
+
+
+def test_style_edge_shorthand_merges_all_edges() -> None:
+    base = waxy.Style(margin_left=waxy.Length(5.0), flex_grow=1.0)
+    merged = base | waxy.Style(margin=waxy.Length(0.0))
+    assert merged.margin == (waxy.Length(0.0),) * 4
+    assert merged.flex_grow == 1.0
+
+
+@pytest.mark.parametrize("shorthand", ["size", "min_size", "max_size"])
+def test_style_size_shorthand(shorthand: str) -> None:
+    s = waxy.Style(**{shorthand: (waxy.Length(100), waxy.AUTO)})
+    assert getattr(s, f"{shorthand}_width") == waxy.Length(100)
+    assert getattr(s, f"{shorthand}_height") == waxy.AUTO
+    assert getattr(s, shorthand) == (waxy.Length(100), waxy.AUTO)
+    assert s.set_field_names() == [f"{shorthand}_height", f"{shorthand}_width"]
+
+
+def test_style_size_shorthand_requires_pair() -> None:
+    with pytest.raises(TypeError):
+        waxy.Style(size=waxy.Length(100))
+
+
+def test_style_gap_shorthand_single_value() -> None:
+    s = waxy.Style(gap=waxy.Length(4))
+    assert s.gap == (waxy.Length(4), waxy.Length(4))
+
+
+def test_style_gap_shorthand_pair() -> None:
+    s = waxy.Style(gap=(waxy.Length(4), waxy.Percent(0.1)))
+    assert s.gap_width == waxy.Length(4)
+    assert s.gap_height == waxy.Percent(0.1)
+
+
+def test_style_gap_shorthand_rejects_auto() -> None:
+    with pytest.raises(TypeError):
+        waxy.Style(gap=waxy.AUTO)
+
+
+def test_style_overflow_shorthand_single_value() -> None:
+    s = waxy.Style(overflow=waxy.Overflow.Hidden)
+    assert s.overflow_x == waxy.Overflow.Hidden
+    assert s.overflow_y == waxy.Overflow.Hidden
+    assert s.overflow == (waxy.Overflow.Hidden, waxy.Overflow.Hidden)
+    assert s.set_field_names() == ["overflow_x", "overflow_y"]
+
+
+def test_style_overflow_shorthand_pair_and_longhand_override() -> None:
+    s = waxy.Style(overflow=(waxy.Overflow.Scroll, waxy.Overflow.Clip))
+    assert s.overflow == (waxy.Overflow.Scroll, waxy.Overflow.Clip)
+    s = waxy.Style(overflow=waxy.Overflow.Scroll, overflow_y=waxy.Overflow.Visible)
+    assert s.overflow == (waxy.Overflow.Scroll, waxy.Overflow.Visible)
+
+
+def test_style_axis_longhand_overrides_shorthand() -> None:
+    s = waxy.Style(size=(waxy.Length(1), waxy.Length(2)), size_height=waxy.Percent(1.0))
+    assert s.size == (waxy.Length(1), waxy.Percent(1.0))
+
+
+def test_style_flex_row_preset_with_overrides() -> None:
+    s = waxy.Style.flex_row(gap_width=waxy.Length(8))
+    assert s.display == waxy.Display.Flex
+    assert s.flex_direction == waxy.FlexDirection.Row
+    assert s.gap_width == waxy.Length(8)
+    assert s.set_field_names() == ["display", "flex_direction", "gap_width"]
+
+
+def test_style_flex_column_preset() -> None:
+    s = waxy.Style.flex_column()
+    assert s == waxy.Style(display=waxy.Display.Flex, flex_direction=waxy.FlexDirection.Column)
+
+
+def test_style_grid_preset() -> None:
+    s = waxy.Style.grid(grid_template_columns=[waxy.Fraction(1.0), waxy.Fraction(1.0)])
+    assert s.display == waxy.Display.Grid
+    assert s.set_field_names() == ["display", "grid_template_columns"]
+
+
+def test_style_preset_overrides_win() -> None:
+    s = waxy.Style.flex_row(flex_direction=waxy.FlexDirection.RowReverse)
+    assert s.flex_direction == waxy.FlexDirection.RowReverse
+
+
+
+def test_style_is_visible() -> None:
+    assert waxy.Style().is_visible()
+    assert waxy.Style(display=waxy.Display.Grid).is_visible()
+    assert not waxy.Style(display=waxy.Display.Nil).is_visible()
+
+
+def test_intern_style_returns_canonical_instance() -> None:
+    a = waxy.Style(display=waxy.Display.Flex, size_width=waxy.Length(10.0))
+    b = waxy.Style(size_width=waxy.Length(10.0), display=waxy.Display.Flex)
+    assert a is not b
+
+    interned_a = waxy.intern_style(a)
+    interned_b = waxy.intern_style(b)
+    assert interned_a == interned_b == a
+    assert interned_b is interned_a
+
+
+def test_intern_style_distinguishes_unequal_styles() -> None:
+    explicit_default = waxy.intern_style(waxy.Style(flex_grow=0.0))
+    empty = waxy.intern_style(waxy.Style())
+    assert explicit_default is not empty
+    assert waxy.intern_style(waxy.Style()) is empty
+
+
+def test_interned_style_cannot_be_mutated() -> None:
+    interned = waxy.intern_style(waxy.Style(flex_grow=1.0))
+    with pytest.raises(AttributeError):
+        interned.flex_grow = 2.0  # type: ignore[misc]
+    assert waxy.intern_style(waxy.Style(flex_grow=1.0)).flex_grow == 1.0
+
+
+def test_intern_style_evicts_least_recently_used() -> None:
+    hot = waxy.intern_style(waxy.Style(flex_grow=3.5))
+    first_cold = waxy.intern_style(waxy.Style(flex_basis=waxy.Length(0.0)))
+
+    # Churn through more distinct styles than the table holds, reusing `hot` throughout.
+    for i in range(1, 5000):
+        waxy.intern_style(waxy.Style(flex_basis=waxy.Length(float(i))))
+        assert waxy.intern_style(waxy.Style(flex_grow=3.5)) is hot
+
+    assert waxy.intern_style(waxy.Style(flex_basis=waxy.Length(0.0))) is not first_cold
+
+
+def test_to_css_emits_set_fields_in_declaration_order() -> None:
+    style = waxy.Style(
+        display=waxy.Display.Flex,
+        size_width=waxy.Length(100.0),
+        margin_top=waxy.AUTO,
+        padding_left=waxy.Percent(0.5),
+        align_items=waxy.AlignItems.FlexStart,
+        flex_grow=2.0,
+    )
+    assert style.to_css() == (
+        "display: flex; width: 100px; margin-top: auto; padding-left: 50%; "
+        "align-items: flex-start; flex-grow: 2;"
+    )
+
+
+def test_to_css_of_empty_style_is_empty() -> None:
+    assert waxy.Style().to_css() == ""
+
+
+def test_to_css_grid_fields() -> None:
+    style = waxy.Style(
+        grid_template_columns=waxy.tracks("100px 1fr minmax(10px, 2fr) fit-content(50%)"),
+        grid_auto_rows=[waxy.MIN_CONTENT],
+        grid_column=waxy.GridPlacement(start=waxy.GridLine(2), end=waxy.GridSpan(3)),
+    )
+    assert style.to_css() == (
+        "grid-template-columns: 100px 1fr minmax(10px, 2fr) fit-content(50%); "
+        "grid-auto-rows: min-content; grid-column: 2 / span 3;"
+    )
+
+
+def test_to_css_unset_alignment_is_normal() -> None:
+    assert waxy.Style(justify_content=None).to_css() == "justify-content: normal;"
+
+
+def test_from_css_sets_only_declared_fields() -> None:
+    style = waxy.Style.from_css("display: flex; gap: 8px;")
+    assert style.display == waxy.Display.Flex
+    assert style.gap == (waxy.Length(8.0), waxy.Length(8.0))
+    assert style.set_field_names() == ["display", "gap_height", "gap_width"]
+
+
+def test_from_css_shorthands_follow_css_order() -> None:
+    style = waxy.Style.from_css(
+        "margin: 1px 2px; margin-top: auto; gap: 4px 8px; overflow: hidden scroll"
+    )
+    assert style.margin == (waxy.AUTO, waxy.Length(2.0), waxy.Length(1.0), waxy.Length(2.0))
+    assert style.gap == (waxy.Length(8.0), waxy.Length(4.0))
+    assert style.overflow == (waxy.Overflow.Hidden, waxy.Overflow.Scroll)
+    assert waxy.Style.from_css("margin-top: 5px; margin: 0").margin_top == waxy.Length(0.0)
+
+
+def test_from_css_round_trips_to_css() -> None:
+    style = waxy.Style(
+        display=waxy.Display.Grid,
+        size_width=waxy.Percent(0.25),
+        justify_content=None,
+        grid_template_columns=waxy.tracks("1fr minmax(10px, 2fr)"),
+        grid_row=waxy.GridPlacement.parse("1 / span 2"),
+        flex_shrink=0.5,
+    )
+    assert waxy.Style.from_css(style.to_css()) == style
+
+
+@pytest.mark.parametrize(
+    ("text", "match"),
+    [
+        ("color: red", 'unsupported CSS property "color"'),
+        ("width: 10em", 'invalid value "10em" for CSS property "width"'),
+        ("display: table", 'invalid value "table" for CSS property "display"'),
+        ("padding: auto", 'invalid value "auto" for CSS property "padding"'),
+        ("display flex", "property: value"),
+    ],
+)
+def test_from_css_rejects_invalid_declarations(text: str, match: str) -> None:
+    with pytest.raises(ValueError, match=match):
+        waxy.Style.from_css(text)
@@ -1,3 +1,9 @@
+import copy
+import re
+from typing import Literal
+
+import pytest
+
 import waxy
 
 
@@ -28,6 +34,37 @@ def test_new_with_children() -> None:
     assert len(children) == 2
 
 
+def test_component() -> None:
+    tree = waxy.TaffyTree()
+
+    def toolbar(tree: waxy.TaffyTree, node: waxy.NodeId) -> None:
+        for _ in range(3):
+            tree.add_child(node, tree.new_leaf(waxy.Style()))
+
+    def page(tree: waxy.TaffyTree, node: waxy.NodeId) -> None:
+        tree.add_child(node, tree.component(waxy.Style.flex_row(), toolbar))
+        tree.add_child(node, tree.new_leaf(waxy.Style()))
+
+    root = tree.component(waxy.Style.flex_column(), page)
+
+    assert tree.total_node_count() == 6
+    header, body = tree.children(root)
+    assert tree.child_count(header) == 3
+    assert tree.child_count(body) == 0
+    assert tree.style(root).flex_direction == waxy.FlexDirection.Column
+
+
+def test_component_builder_error_propagates() -> None:
+    tree = waxy.TaffyTree()
+
+    def builder(tree: waxy.TaffyTree, node: waxy.NodeId) -> None:
+        msg = "builder failed!"
+        raise RuntimeError(msg)
+
+    with pytest.raises(RuntimeError, match="builder failed!"):
+        tree.component(waxy.Style(), builder)
+
+
 def test_add_child() -> None:
     tree = waxy.TaffyTree()
     parent = tree.new_leaf(waxy.Style())
@@ -62,6 +99,50 @@ def test_child_at_index() -> None:
     assert tree.child_at_index(parent, 1) == c2
 
 
+def test_set_child_style_and_child_style() -> None:
+    tree = waxy.TaffyTree()
+    c1 = tree.new_leaf(waxy.Style())
+    c2 = tree.new_leaf(waxy.Style())
+    parent = tree.new_with_children(waxy.Style(), [c1, c2])
+
+    tree.set_child_style(parent, 1, waxy.Style(flex_grow=2.0))
+
+    assert tree.style(c2).flex_grow == 2.0
+    assert tree.child_style(parent, 1) == tree.style(c2)
+    assert tree.child_style(parent, 0) == tree.style(c1)
+
+
+def test_set_child_style_out_of_range() -> None:
+    tree = waxy.TaffyTree()
+    parent = tree.new_with_children(waxy.Style(), [tree.new_leaf(waxy.Style())])
+
+    with pytest.raises(waxy.ChildIndexOutOfBounds):
+        tree.set_child_style(parent, 1, waxy.Style())
+    with pytest.raises(waxy.ChildIndexOutOfBounds):
+        tree.child_style(parent, 5)
+
+
+def test_children_iter_matches_children() -> None:
+    tree = waxy.TaffyTree()
+    children = [tree.new_leaf(waxy.Style()) for _ in range(3)]
+    parent = tree.new_with_children(waxy.Style(), children)
+    assert list(tree.children_iter(parent)) == tree.children(parent) == children
+
+
+def test_children_iter_of_leaf_is_empty() -> None:
+    tree = waxy.TaffyTree()
+    leaf = tree.new_leaf(waxy.Style())
+    assert list(tree.children_iter(leaf)) == []
+
+
+def test_children_iter_is_lazy() -> None:
+    tree = waxy.TaffyTree()
+    first = tree.new_leaf(waxy.Style())
+    parent = tree.new_with_children(waxy.Style(), [first, tree.new_leaf(waxy.Style())])
+    it = tree.children_iter(parent)
+    assert next(it) == first
+
+
 def test_parent() -> None:
     tree = waxy.TaffyTree()
     child = tree.new_leaf(waxy.Style())
@@ -70,6 +151,22 @@ def test_parent() -> None:
     assert tree.parent(parent) is None
 
 
+def test_ancestors_and_depth_of_root() -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_leaf(waxy.Style())
+    assert tree.ancestors(root) == []
+    assert tree.depth(root) == 0
+
+
+def test_ancestors_and_depth_of_grandchild() -> None:
+    tree = waxy.TaffyTree()
+    grandchild = tree.new_leaf(waxy.Style())
+    child = tree.new_with_children(waxy.Style(), [grandchild])
+    root = tree.new_with_children(waxy.Style(), [child])
+    assert tree.ancestors(grandchild) == [child, root]
+    assert tree.depth(grandchild) == 2
+
+
 def test_remove_node() -> None:
     tree = waxy.TaffyTree()
     node = tree.new_leaf(waxy.Style())
@@ -85,6 +182,248 @@ def test_clear() -> None:
     assert tree.total_node_count() == 0
 
 
+def test_clone_preserves_structure_styles_and_contexts() -> None:
+    tree: waxy.TaffyTree[str] = waxy.TaffyTree()
+    leaf = tree.new_leaf_with_context(waxy.Style(flex_grow=1.0), "leaf")
+    root = tree.new_with_children(waxy.Style(display=waxy.Display.Grid), [leaf])
+    tree.compute_layout(root)
+
+    clone = tree.clone()
+    clone_root, clone_leaf = clone.translate(root), clone.translate(leaf)
+    assert clone.children(clone_root) == [clone_leaf]
+    assert clone.style(clone_leaf).flex_grow == 1.0
+    assert clone.get_node_context(clone_leaf) == "leaf"
+    assert clone.layout(clone_root) == tree.layout(root)
+
+
+def test_clone_mutations_do_not_affect_original() -> None:
+    tree = waxy.TaffyTree()
+    leaf = tree.new_leaf(waxy.Style())
+    root = tree.new_with_children(waxy.Style(), [leaf])
+
+    clone = tree.clone()
+    clone.add_child(clone.translate(root), clone.new_leaf(waxy.Style()))
+    clone.set_style(clone.translate(leaf), waxy.Style(flex_grow=2.0))
+    clone.remove(clone.translate(leaf))
+
+    assert tree.children(root) == [leaf]
+    assert tree.style(leaf).flex_grow == 0.0
+    assert tree.total_node_count() == 2
+
+
+def test_copy_copy_clones_tree() -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_leaf(waxy.Style(flex_grow=1.0))
+    clone = copy.copy(tree)
+    clone.set_style(clone.translate(root), waxy.Style())
+    assert tree.style(root).flex_grow == 1.0
+
+
+def test_clone_is_a_different_tree() -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_leaf(waxy.Style())
+    clone = tree.clone()
+
+    with pytest.raises(waxy.WrongTree):
+        clone.style(root)
+    with pytest.raises(waxy.WrongTree):
+        tree.style(clone.translate(root))
+
+    # Nodes created afterwards in each tree never alias each other.
+    ours = tree.new_leaf(waxy.Style(flex_grow=1.0))
+    theirs = clone.new_leaf(waxy.Style(flex_grow=2.0))
+    assert ours != theirs
+    with pytest.raises(waxy.WrongTree):
+        tree.style(theirs)
+
+
+def test_translate_rejects_unrelated_and_later_nodes() -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_leaf(waxy.Style())
+    clone = tree.clone()
+
+    assert clone.translate(clone.translate(root)) == clone.translate(root)
+    with pytest.raises(waxy.InvalidNodeId, match="after this tree was cloned"):
+        clone.translate(tree.new_leaf(waxy.Style()))
+    with pytest.raises(waxy.WrongTree):
+        clone.translate(waxy.TaffyTree().new_leaf(waxy.Style()))
+    with pytest.raises(waxy.WrongTree):
+        tree.translate(clone.translate(root))
+
+
+def test_to_dict_nested_shape() -> None:
+    tree = waxy.TaffyTree()
+    first = tree.new_leaf(waxy.Style(size_width=waxy.Length(10.0), size_height=waxy.Length(5.0)))
+    second = tree.new_leaf(waxy.Style(size_width=waxy.Length(20.0), size_height=waxy.Length(5.0)))
+    root = tree.new_with_children(waxy.Style(display=waxy.Display.Flex), [first, second])
+    tree.compute_layout(root)
+
+    data = tree.to_dict(root)
+    assert set(data) == {"node_id", "style", "layout", "children"}
+    style = data["style"]
+    assert isinstance(style, dict)
+    assert style["display"] == "Flex"
+    assert data["layout"] == tree.layout(root).to_dict()
+
+    children = data["children"]
+    assert isinstance(children, list)
+    assert [child["layout"]["size"]["width"] for child in children] == [10.0, 20.0]
+    assert [child["layout"]["location"]["x"] for child in children] == [0.0, 10.0]
+    assert all(child["children"] == [] for child in children)
+
+
+def test_to_dict_omits_layout_of_dirty_nodes() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(waxy.Style())
+    assert "layout" not in tree.to_dict(node)
+
+
+def test_to_svg_draws_each_laid_out_node() -> None:
+    tree = waxy.TaffyTree()
+    first = tree.new_leaf(waxy.Style(size_width=waxy.Length(10.0), size_height=waxy.Length(5.0)))
+    second = tree.new_leaf(waxy.Style(size_width=waxy.Length(20.0), size_height=waxy.Length(5.0)))
+    root = tree.new_with_children(
+        waxy.Style(display=waxy.Display.Flex, padding_left=waxy.Length(2.0)), [first, second]
+    )
+    tree.compute_layout(root)
+
+    svg = tree.to_svg(root)
+    assert svg.startswith("<svg xmlns=\"http://www.w3.org/2000/svg\"")
+    assert svg.rstrip().endswith("</svg>")
+    # A border box and a padding box per node.
+    assert svg.count("<rect") == 6
+    assert svg.count("<text") == 3
+    assert '<rect class="border-box" x="12" y="0" width="20" height="5"' in svg
+
+
+def test_to_svg_requires_computed_layout() -> None:
+    tree = waxy.TaffyTree()
+    child = tree.new_leaf(waxy.Style(size_width=waxy.Length(10.0)))
+    root = tree.new_with_children(waxy.Style(), [child])
+    with pytest.raises(ValueError, match="has not been computed"):
+        tree.to_svg(root)
+
+    tree.compute_layout(root)
+    tree.add_child(root, tree.new_leaf(waxy.Style()))
+    with pytest.raises(ValueError, match="has not been computed"):
+        tree.to_svg(root)
+
+
+def test_context_manager_clears_tree_and_releases_contexts() -> None:
+    released: list[str] = []
+
+    class Context:
+        def __init__(self, name: str) -> None:
+            self.name = name
+
+        def __del__(self) -> None:
+            released.append(self.name)
+
+    with waxy.TaffyTree[Context]() as tree:
+        tree.new_leaf_with_context(waxy.Style(), Context("a"))
+        tree.new_leaf_with_context(waxy.Style(), Context("b"))
+        assert released == []
+
+    assert sorted(released) == ["a", "b"]
+    assert tree.total_node_count() == 0
+
+
+def test_context_manager_does_not_suppress_exceptions() -> None:
+    with pytest.raises(RuntimeError), waxy.TaffyTree() as tree:
+        tree.new_leaf(waxy.Style())
+        raise RuntimeError
+    assert tree.total_node_count() == 0
+
+
+def test_contains_live_and_removed_nodes() -> None:
+    tree = waxy.TaffyTree()
+    live = tree.new_leaf(waxy.Style())
+    removed = tree.new_leaf(waxy.Style())
+    tree.remove(removed)
+
+    assert tree.contains(live)
+    assert not tree.contains(removed)
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.style(removed)
+
+
+def test_subtree_node_count() -> None:
+    tree = waxy.TaffyTree()
+    leaf = tree.new_leaf(waxy.Style())
+    branch = tree.new_with_children(waxy.Style(), [leaf, tree.new_leaf(waxy.Style())])
+    root = tree.new_with_children(waxy.Style(), [branch, tree.new_leaf(waxy.Style())])
+
+    assert tree.subtree_node_count(root) == tree.total_node_count() == 5
+    assert tree.subtree_node_count(branch) == 3
+    assert tree.subtree_node_count(leaf) == 1
+
+
+def test_subtree_node_count_of_removed_node() -> None:
+    tree = waxy.TaffyTree()
+    removed = tree.new_leaf(waxy.Style())
+    tree.remove(removed)
+
+    with pytest.raises(waxy.InvalidNodeId, match=re.escape(repr(removed))):
+        tree.subtree_node_count(removed)
+
+
+def test_contains_after_clear_and_across_trees() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(waxy.Style())
+    other = waxy.TaffyTree()
+    other.new_leaf(waxy.Style())
+
+    assert not other.contains(node)
+    tree.clear()
+    assert not tree.contains(node)
+
+
+def test_node_ids_from_before_clear_stay_invalid() -> None:
+    tree = waxy.TaffyTree()
+    old = tree.new_leaf(waxy.Style(flex_grow=1.0))
+    tree.clear()
+    new = tree.new_leaf(waxy.Style(flex_grow=7.0))
+
+    assert old != new
+    assert not tree.contains(old)
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.style(old)
+
+
+def test_node_ids_from_before_with_block_stay_invalid() -> None:
+    with waxy.TaffyTree() as tree:
+        old = tree.new_leaf(waxy.Style())
+    tree.new_leaf(waxy.Style())
+
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.style(old)
+
+
+def test_roots_reports_each_separate_subtree() -> None:
+    tree = waxy.TaffyTree()
+    first = tree.new_with_children(waxy.Style(), [tree.new_leaf(waxy.Style())])
+    second = tree.new_with_children(waxy.Style(), [tree.new_leaf(waxy.Style())])
+    assert set(tree.roots()) == {first, second}
+
+
+def test_roots_includes_detached_and_orphaned_nodes() -> None:
+    tree = waxy.TaffyTree()
+    assert tree.roots() == []
+    leaf = tree.new_leaf(waxy.Style())
+    assert tree.roots() == [leaf]
+
+    child = tree.new_leaf(waxy.Style())
+    parent = tree.new_with_children(waxy.Style(), [child])
+    tree.add_child(parent, leaf)
+    assert tree.roots() == [parent]
+
+    tree.remove(parent)
+    assert set(tree.roots()) == {child, leaf}
+
+    tree.clear()
+    assert tree.roots() == []
+
+
 def test_set_and_get_style() -> None:
     tree = waxy.TaffyTree()
     node = tree.new_leaf(waxy.Style(flex_grow=1.0))
@@ -96,6 +435,77 @@ def test_set_and_get_style() -> None:
     assert style.flex_grow == 2.0
 
 
+def test_style_round_trips_through_the_tree() -> None:
+    tree = waxy.TaffyTree()
+    style = waxy.Style(display=waxy.Display.Flex)
+    node = tree.new_leaf(style)
+    assert tree.style(node) == style
+    assert tree.style(tree.new_leaf(waxy.Style())) == waxy.Style()
+    assert tree.style(tree.new_with_children(style, [])) == style
+
+    tree.set_style(node, waxy.Style(flex_grow=0.0))
+    assert tree.style(node) == waxy.Style(flex_grow=0.0)
+    assert not tree.style(node).is_set("display")
+
+    tree.set_styles([(node, style)])
+    assert tree.style(node) == style
+
+
+def test_new_leaves_returns_ids_in_input_order() -> None:
+    tree = waxy.TaffyTree()
+    styles = [waxy.Style(flex_grow=float(i)) for i in range(5)]
+
+    nodes = tree.new_leaves(styles)
+
+    assert len(set(nodes)) == len(styles)
+    assert [tree.style(node).flex_grow for node in nodes] == [0.0, 1.0, 2.0, 3.0, 4.0]
+    assert all(tree.child_count(node) == 0 for node in nodes)
+
+
+def test_new_leaves_with_context() -> None:
+    tree = waxy.TaffyTree()
+    nodes = tree.new_leaves_with_context([(waxy.Style(), "a"), (waxy.Style(), "b")])
+    assert [tree.get_node_context(node) for node in nodes] == ["a", "b"]
+
+
+def test_new_leaves_empty() -> None:
+    tree = waxy.TaffyTree()
+    assert tree.new_leaves([]) == []
+    assert tree.total_node_count() == 0
+
+
+def test_set_styles_applies_every_update() -> None:
+    tree = waxy.TaffyTree()
+    nodes = [tree.new_leaf(waxy.Style()) for _ in range(3)]
+    tree.set_styles([(node, waxy.Style(flex_grow=float(i))) for i, node in enumerate(nodes)])
+    assert [tree.style(node).flex_grow for node in nodes] == [0.0, 1.0, 2.0]
+
+
+def test_set_styles_stops_at_first_removed_node() -> None:
+    tree = waxy.TaffyTree()
+    first, removed, last = (tree.new_leaf(waxy.Style()) for _ in range(3))
+    tree.remove(removed)
+    with pytest.raises(waxy.InvalidNodeId, match=re.escape(repr(removed))):
+        tree.set_styles(
+            [
+                (first, waxy.Style(flex_grow=1.0)),
+                (removed, waxy.Style(flex_grow=1.0)),
+                (last, waxy.Style(flex_grow=1.0)),
+            ]
+        )
+    assert tree.style(first).flex_grow == 1.0
+    assert tree.style(last).flex_grow == 0.0
+
+
+def test_set_styles_checks_every_node_before_applying() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(waxy.Style())
+    foreign = waxy.TaffyTree().new_leaf(waxy.Style())
+    with pytest.raises(waxy.WrongTree):
+        tree.set_styles([(node, waxy.Style(flex_grow=1.0)), (foreign, waxy.Style())])
+    assert tree.style(node).flex_grow == 0.0
+
+
 def test_dirty() -> None:
     tree = waxy.TaffyTree()
     node = tree.new_leaf(waxy.Style(size_width=waxy.Length(100.0)))
@@ -121,6 +531,57 @@ def test_compute_layout() -> None:
     assert layout.location.y == 0.0
 
 
+def test_compute_layout_all_lays_out_every_root() -> None:
+    tree = waxy.TaffyTree()
+    child = tree.new_leaf(waxy.Style(size=(waxy.Length(30.0), waxy.Length(10.0))))
+    first = tree.new_with_children(waxy.Style(padding=waxy.Length(5.0)), [child])
+    second = tree.new_leaf(waxy.Style(size_width=waxy.Percent(0.5)))
+
+    tree.compute_layout_all([first, second], available=(100.0, 50.0))
+
+    assert not tree.dirty(first)
+    assert not tree.dirty(second)
+    assert tree.layout(child).location == waxy.Point(5.0, 5.0)
+    assert tree.layout(second).size.width == 50.0
+    assert tree.last_layout_stats()["nodes_laid_out"] == 3
+
+
+def test_compute_layout_all_stops_at_first_error() -> None:
+    tree = waxy.TaffyTree[str]()
+    first = tree.new_leaf_with_context(waxy.Style(), "bad")
+    second = tree.new_leaf_with_context(waxy.Style(), "good")
+
+    with pytest.raises(TypeError, match="must return Size or MeasureResult"):
+        tree.compute_layout_all([first, second], measure=lambda kd, avail, ctx: None)
+    assert tree.dirty(second)
+
+
+def test_compute_layout_all_rejects_nodes_from_other_trees() -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_leaf(waxy.Style())
+    other = waxy.TaffyTree().new_leaf(waxy.Style())
+
+    with pytest.raises(waxy.WrongTree):
+        tree.compute_layout_all([root, other])
+    assert tree.dirty(root)
+
+
+def test_compute_and_get_layout_matches_two_step() -> None:
+    def build() -> tuple[waxy.TaffyTree, waxy.NodeId]:
+        tree = waxy.TaffyTree()
+        child = tree.new_leaf(waxy.Style(flex_grow=1.0))
+        root = tree.new_with_children(waxy.Style(padding_left=waxy.Length(5.0)), [child])
+        return tree, root
+
+    available = waxy.AvailableSize(waxy.Definite(200.0), waxy.Definite(100.0))
+    tree, root = build()
+    tree.compute_layout(root, available=available)
+    expected = tree.layout(root)
+
+    tree, root = build()
+    assert tree.compute_and_get_layout(root, available=available) == expected
+
+
 def test_compute_layout_with_available_space() -> None:
     tree = waxy.TaffyTree()
     style = waxy.Style(
@@ -140,6 +601,46 @@ def test_compute_layout_with_available_space() -> None:
     assert layout.size.height == 50.0
 
 
+@pytest.mark.parametrize(
+    ("shorthand", "explicit"),
+    [
+        ((200.0, 100.0), waxy.AvailableSize(waxy.Definite(200.0), waxy.Definite(100.0))),
+        ((300, 50), waxy.AvailableSize(waxy.Definite(300.0), waxy.Definite(50.0))),
+        (150.0, waxy.AvailableSize(waxy.Definite(150.0), waxy.Definite(150.0))),
+    ],
+)
+def test_compute_layout_available_shorthands_match_available_size(
+    shorthand: tuple[float, float] | float, explicit: waxy.AvailableSize
+) -> None:
+    def build() -> tuple[waxy.TaffyTree, waxy.NodeId, waxy.NodeId]:
+        tree = waxy.TaffyTree()
+        child = tree.new_leaf(waxy.Style(flex_grow=1.0, size_height=waxy.Percent(0.5)))
+        root = tree.new_with_children(
+            waxy.Style(
+                display=waxy.Display.Flex,
+                size_width=waxy.Percent(1.0),
+                size_height=waxy.Percent(1.0),
+            ),
+            [child],
+        )
+        return tree, root, child
+
+    tree, root, child = build()
+    tree.compute_layout(root, available=shorthand)
+    expected_tree, expected_root, expected_child = build()
+    expected_tree.compute_layout(expected_root, available=explicit)
+
+    assert tree.layout(root) == expected_tree.layout(expected_root)
+    assert tree.layout(child) == expected_tree.layout(expected_child)
+
+
+def test_compute_layout_rejects_malformed_available() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(waxy.Style())
+    with pytest.raises(TypeError):
+        tree.compute_layout(node, available=(1.0, 2.0, 3.0))  # type: ignore[arg-type]
+
+
 def test_rounding() -> None:
     tree = waxy.TaffyTree()
     style = waxy.Style(
@@ -161,6 +662,53 @@ def test_rounding() -> None:
     assert layout.size.width == 10.5
 
 
+def test_clear_keeps_rounding_disabled() -> None:
+    tree = waxy.TaffyTree()
+    tree.disable_rounding()
+    tree.clear()
+    node = tree.new_leaf(waxy.Style(size_width=waxy.Length(10.5)))
+    tree.compute_layout(node)
+    assert tree.layout(node).size.width == 10.5
+
+
+def test_is_rounding_enabled_tracks_toggles() -> None:
+    tree = waxy.TaffyTree()
+    assert tree.is_rounding_enabled()
+    tree.disable_rounding()
+    assert not tree.is_rounding_enabled()
+    tree.enable_rounding()
+    assert tree.is_rounding_enabled()
+
+
+def test_rounding_disabled_restores_enabled_rounding() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(waxy.Style(size_width=waxy.Length(10.5)))
+
+    with tree.rounding_disabled():
+        assert not tree.is_rounding_enabled()
+        tree.compute_layout(node)
+        assert tree.layout(node).size.width == 10.5
+
+    assert tree.is_rounding_enabled()
+    tree.compute_layout(node)
+    assert tree.layout(node).size.width == 11.0
+
+
+def test_rounding_disabled_keeps_disabled_rounding() -> None:
+    tree = waxy.TaffyTree()
+    tree.disable_rounding()
+    with tree.rounding_disabled():
+        assert not tree.is_rounding_enabled()
+    assert not tree.is_rounding_enabled()
+
+
+def test_rounding_disabled_restores_on_exception() -> None:
+    tree = waxy.TaffyTree()
+    with pytest.raises(RuntimeError), tree.rounding_disabled():
+        raise RuntimeError
+    assert tree.is_rounding_enabled()
+
+
 def test_node_id_eq_and_hash() -> None:
     tree = waxy.TaffyTree()
     n1 = tree.new_leaf(waxy.Style())
@@ -203,3 +751,245 @@ def test_layout_hash() -> None:
     layout = tree.layout(node)
     assert hash(layout) == hash(tree.layout(node))
     assert len({layout, tree.layout(node)}) == 1
+
+
+def test_layout_eq_from_identical_inputs() -> None:
+    def layout_of(margin_left: float) -> waxy.Layout:
+        tree = waxy.TaffyTree()
+        child = tree.new_leaf(waxy.Style(flex_grow=1.0, margin_left=waxy.Length(margin_left)))
+        root = tree.new_with_children(waxy.Style(size=(waxy.Length(100.0), waxy.Length(50.0))), [child])
+        tree.compute_layout(root)
+        return tree.layout(child)
+
+    assert layout_of(3.0) == layout_of(3.0)
+    assert hash(layout_of(3.0)) == hash(layout_of(3.0))
+    assert layout_of(3.0) != layout_of(4.0)
+
+
+def test_layout_eq_is_bit_exact() -> None:
+    def layout_of(margin_left: float) -> waxy.Layout:
+        tree = waxy.TaffyTree()
+        node = tree.new_leaf(waxy.Style(margin_left=waxy.Length(margin_left)))
+        tree.compute_layout(node)
+        return tree.layout(node)
+
+    assert layout_of(0.0) != layout_of(-0.0)
+
+
+def test_format_tree() -> None:
+    tree = waxy.TaffyTree()
+    a = tree.new_leaf(waxy.Style(size=(waxy.Length(10.0), waxy.Length(20.0))))
+    b = tree.new_leaf(waxy.Style(size=(waxy.Length(30.0), waxy.Length(5.0))))
+    root = tree.new_with_children(waxy.Style.flex_column(), [a, b])
+    tree.compute_layout(root)
+
+    lines = tree.format_tree(root).splitlines()
+
+    assert lines[0] == "TREE"
+    assert len(lines) == 1 + tree.total_node_count()
+    assert "FLEX COL" in lines[1]
+    assert "y: 20   w: 30   h: 5 " in lines[3]
+
+
+def test_node_labels_survive_relayout_and_show_in_format_tree() -> None:
+    tree = waxy.TaffyTree()
+    leaf = tree.new_leaf_with_context(waxy.Style(), "context")
+    root = tree.new_with_children(waxy.Style(), [leaf])
+    assert tree.node_label(leaf) is None
+
+    tree.set_node_label(leaf, "sidebar")
+    tree.compute_layout(root)
+    tree.set_style(root, waxy.Style(size_width=waxy.Length(50.0)))
+    tree.compute_layout(root)
+
+    assert tree.node_label(leaf) == "sidebar"
+    assert tree.get_node_context(leaf) == "context"
+    lines = tree.format_tree(root).splitlines()
+    assert '"sidebar"' in lines[2]
+    assert '"' not in lines[1]
+
+
+def test_node_label_can_be_cleared() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(waxy.Style())
+    tree.set_node_label(node, "header")
+    tree.set_node_label(node, None)
+    assert tree.node_label(node) is None
+
+
+def test_node_label_dropped_on_remove() -> None:
+    tree = waxy.TaffyTree()
+    node = tree.new_leaf(waxy.Style())
+    tree.set_node_label(node, "header")
+    tree.remove(node)
+    with pytest.raises(waxy.InvalidNodeId):
+        tree.node_label(node)
+
+    # taffy reuses the freed slot, and the new node must not inherit the label.
+    reused = tree.new_leaf(waxy.Style())
+    assert tree.node_label(reused) is None
+
+
+def test_print_tree_writes_format_tree(capsys: pytest.CaptureFixture[str]) -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_leaf(waxy.Style())
+    tree.compute_layout(root)
+
+    tree.print_tree(root)
+
+    assert capsys.readouterr().out == tree.format_tree(root) + "\n"
+
+
+def test_visible_layouts_skips_hidden_subtrees() -> None:
+    tree = waxy.TaffyTree()
+    hidden_child = tree.new_leaf(waxy.Style(size=(waxy.Length(5.0), waxy.Length(5.0))))
+    hidden = tree.new_with_children(waxy.Style(display=waxy.Display.Nil), [hidden_child])
+    first = tree.new_leaf(waxy.Style(size=(waxy.Length(10.0), waxy.Length(10.0))))
+    nested = tree.new_leaf(waxy.Style(size=(waxy.Length(20.0), waxy.Length(20.0))))
+    second = tree.new_with_children(waxy.Style(), [nested])
+    root = tree.new_with_children(waxy.Style(), [first, hidden, second])
+    tree.compute_layout(root)
+
+    visible = tree.visible_layouts(root)
+
+    assert [node for node, _ in visible] == [root, first, second, nested]
+    assert all(layout == tree.layout(node) for node, layout in visible)
+
+
+def test_visible_layouts_hidden_root() -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_with_children(
+        waxy.Style(display=waxy.Display.Nil), [tree.new_leaf(waxy.Style())]
+    )
+    tree.compute_layout(root)
+
+    assert tree.visible_layouts(root) == []
+
+
+def _three_level_tree() -> tuple[waxy.TaffyTree, dict[str, waxy.NodeId]]:
+    tree = waxy.TaffyTree()
+    nodes = {name: tree.new_leaf(waxy.Style()) for name in ["a1", "a2", "b1"]}
+    nodes["a"] = tree.new_with_children(waxy.Style(), [nodes["a1"], nodes["a2"]])
+    nodes["b"] = tree.new_with_children(waxy.Style(), [nodes["b1"]])
+    nodes["root"] = tree.new_with_children(waxy.Style(), [nodes["a"], nodes["b"]])
+    return tree, nodes
+
+
+@pytest.mark.parametrize(
+    ("order", "expected"),
+    [
+        ("dfs", ["root", "a", "a1", "a2", "b", "b1"]),
+        ("bfs", ["root", "a", "b", "a1", "a2", "b1"]),
+    ],
+)
+def test_descendants(order: Literal["dfs", "bfs"], expected: list[str]) -> None:
+    tree, nodes = _three_level_tree()
+    names = {node: name for name, node in nodes.items()}
+
+    visited = [names[node] for node in tree.descendants(nodes["root"], order=order)]
+
+    assert visited == expected
+
+
+def test_descendants_defaults_to_dfs() -> None:
+    tree, nodes = _three_level_tree()
+    assert list(tree.descendants(nodes["a"])) == [nodes["a"], nodes["a1"], nodes["a2"]]
+
+
+def test_descendants_invalid_order() -> None:
+    tree, nodes = _three_level_tree()
+    with pytest.raises(ValueError, match="order must be"):
+        tree.descendants(nodes["root"], order="sideways")  # type: ignore[arg-type]
+
+
+def test_find_by_context() -> None:
+    sentinel = object()
+    tree = waxy.TaffyTree()
+    target = tree.new_leaf_with_context(waxy.Style(), sentinel)
+    other = tree.new_leaf_with_context(waxy.Style(), object())
+    root = tree.new_with_children(waxy.Style(), [other, target])
+
+    assert tree.find(root, lambda node: tree.get_node_context(node) is sentinel) == target
+    assert tree.find(root, lambda node: False) is None
+
+
+def test_find_all_in_dfs_order() -> None:
+    tree, nodes = _three_level_tree()
+    names = {node: name for name, node in nodes.items()}
+
+    leaves = tree.find_all(nodes["root"], lambda node: tree.child_count(node) == 0)
+
+    assert [names[node] for node in leaves] == ["a1", "a2", "b1"]
+    assert tree.find_all(nodes["b"], lambda node: False) == []
+
+
+def test_find_propagates_predicate_errors() -> None:
+    tree, nodes = _three_level_tree()
+
+    def predicate(node: waxy.NodeId) -> bool:
+        raise RuntimeError("boom")
+
+    with pytest.raises(RuntimeError, match="boom"):
+        tree.find(nodes["root"], predicate)
+    with pytest.raises(RuntimeError, match="boom"):
+        tree.find_all(nodes["root"], predicate)
+
+
+def test_lint_clean_tree() -> None:
+    tree = waxy.TaffyTree()
+    child = tree.new_leaf(waxy.Style(flex_grow=1.0))
+    root = tree.new_with_children(waxy.Style.flex_row(), [child])
+    assert tree.lint(root) == []
+
+
+def test_lint_reports_single_style_issues() -> None:
+    tree = waxy.TaffyTree()
+    root = tree.new_leaf(
+        waxy.Style(
+            display=waxy.Display.Flex,
+            grid_template_columns=[waxy.Fraction(1.0)],
+            min_size_width=waxy.Length(200.0),
+            max_size_width=waxy.Length(100.0),
+        )
+    )
+
+    issues = tree.lint(root)
+
+    assert issues == [
+        (root, "grid_template_columns has no effect unless display is Display.Grid"),
+        (root, "min_size_width (200) is larger than max_size_width (100)"),
+    ]
+
+
+def test_lint_reports_the_same_value_issues_as_validate() -> None:
+    tree = waxy.TaffyTree()
+    style = waxy.Style(flex_grow=float("nan"), flex_shrink=-1.0, scrollbar_width=-2.0)
+    root = tree.new_leaf(style)
+
+    issues = tree.lint(root)
+
+    assert issues == [
+        (root, "flex_grow must be non-negative and finite, got NaN"),
+        (root, "flex_shrink must be non-negative and finite, got -1"),
+        (root, "scrollbar_width must be non-negative and finite, got -2"),
+    ]
+    with pytest.raises(ValueError, match=re.escape(issues[0][1])):
+        style.validate()
+
+
+def test_lint_reports_cross_node_issues() -> None:
+    tree = waxy.TaffyTree()
+    misplaced = tree.new_leaf(
+        waxy.Style(grid_column=waxy.GridPlacement(start=waxy.GridLine(2)), flex_grow=1.0)
+    )
+    block = tree.new_with_children(waxy.Style(display=waxy.Display.Block), [misplaced])
+    implicit = tree.new_leaf(waxy.Style(grid_row=waxy.GridPlacement(start=waxy.GridLine(3))))
+    grid = tree.new_with_children(waxy.Style.grid(), [implicit])
+    root = tree.new_with_children(waxy.Style.flex_column(), [block, grid])
+
+    issues = tree.lint(root)
+
+    assert [node for node, _ in issues] == [misplaced, misplaced, implicit]
+    assert "grid_column has no effect because the parent is not a grid container" in issues[0][1]
+    assert "flex_grow has no effect" in issues[1][1]
+    assert "no grid_template_rows" in issues[2][1]
@@ -1,5 +1,6 @@
 """Tests for the new standalone value types."""
 
+import pickle
 from dataclasses import dataclass
 
 import pytest
@@ -92,6 +93,22 @@ def test_percent_match() -> None:
             pytest.fail("pattern match failed")
 
 
+
+def test_percent_from_percentage() -> None:
+    assert waxy.Percent.from_percentage(50).value == 0.5
+    assert waxy.Percent.from_percentage(100) == waxy.Percent(1.0)
+
+
+@pytest.mark.parametrize("value", [150.0, -1.0])
+def test_percent_from_percentage_out_of_range(value: float) -> None:
+    with pytest.raises(waxy.InvalidPercent, match=r"percentage must be in \[0.0, 100.0\]"):
+        waxy.Percent.from_percentage(value)
+
+
+def test_percent_as_percentage() -> None:
+    assert waxy.Percent(0.25).as_percentage() == 25.0
+
+
 # --- Auto ---
 
 
@@ -335,6 +352,21 @@ def test_grid_span_construction() -> None:
     assert gs.count == 3
 
 
+def test_grid_span_minimum_count() -> None:
+    assert waxy.GridSpan(1).count == 1
+
+
+def test_grid_span_zero_rejected() -> None:
+    with pytest.raises(waxy.InvalidGridSpan):
+        waxy.GridSpan(0)
+
+
+def test_grid_span_valid_placement_round_trips_through_style() -> None:
+    s = waxy.Style(grid_column=waxy.GridPlacement(start=waxy.GridSpan(1), end=waxy.GridSpan(5)))
+    assert s.grid_column.start == waxy.GridSpan(1)
+    assert s.grid_column.end == waxy.GridSpan(5)
+
+
 def test_grid_span_repr() -> None:
     assert repr(waxy.GridSpan(3)) == "GridSpan(3)"
 
@@ -356,6 +388,82 @@ def test_grid_span_match() -> None:
             pytest.fail("pattern match failed")
 
 
+# --- GridNamedLine / GridNamedSpan ---
+
+
+def test_grid_named_line_construction() -> None:
+    nl = waxy.GridNamedLine("header")
+    assert nl.name == "header"
+    assert nl.index is None
+    assert waxy.GridNamedLine("header", -2).index == -2
+
+
+def test_grid_named_line_zero_index_rejected() -> None:
+    with pytest.raises(waxy.InvalidGridLine):
+        waxy.GridNamedLine("header", 0)
+
+
+def test_grid_named_line_empty_name_rejected() -> None:
+    with pytest.raises(ValueError, match="empty"):
+        waxy.GridNamedLine("")
+
+
+def test_grid_named_line_repr() -> None:
+    assert repr(waxy.GridNamedLine("header")) == 'GridNamedLine("header")'
+    assert repr(waxy.GridNamedLine("header", 2)) == 'GridNamedLine("header", 2)'
+
+
+def test_grid_named_line_eq_and_hash() -> None:
+    assert waxy.GridNamedLine("a", 1) == waxy.GridNamedLine("a", 1)
+    assert waxy.GridNamedLine("a", 1) != waxy.GridNamedLine("a")
+    assert waxy.GridNamedLine("a") != waxy.GridNamedSpan("a")
+    assert hash(waxy.GridNamedLine("a", 1)) == hash(waxy.GridNamedLine("a", 1))
+
+
+def test_grid_named_line_match() -> None:
+    match waxy.GridNamedLine("main", 3):
+        case waxy.GridNamedLine(name, index):
+            assert (name, index) == ("main", 3)
+        case _:
+            pytest.fail("pattern match failed")
+
+
+def test_grid_named_span_construction() -> None:
+    ns = waxy.GridNamedSpan("footer")
+    assert ns.name == "footer"
+    assert ns.count == 1
+    assert waxy.GridNamedSpan("footer", 3).count == 3
+
+
+def test_grid_named_span_zero_count_rejected() -> None:
+    with pytest.raises(waxy.InvalidGridSpan):
+        waxy.GridNamedSpan("footer", 0)
+
+
+def test_grid_named_span_repr() -> None:
+    assert repr(waxy.GridNamedSpan("footer", 2)) == 'GridNamedSpan("footer", 2)'
+
+
+def test_grid_named_span_eq_and_hash() -> None:
+    assert waxy.GridNamedSpan("a", 2) == waxy.GridNamedSpan("a", 2)
+    assert waxy.GridNamedSpan("a", 2) != waxy.GridNamedSpan("b", 2)
+    assert hash(waxy.GridNamedSpan("a", 2)) == hash(waxy.GridNamedSpan("a", 2))
+
+
+@pytest.mark.parametrize("field", ["grid_row", "grid_column"])
+def test_grid_named_placement_round_trips_through_style(field: str) -> None:
+    placement = waxy.GridPlacement(start=waxy.GridNamedLine("content", 2), end=waxy.GridNamedSpan("aside", 3))
+    s = waxy.Style(**{field: placement})
+    assert getattr(s, field) == placement
+    assert getattr(s, field).start == waxy.GridNamedLine("content", 2)
+    assert getattr(s, field).end == waxy.GridNamedSpan("aside", 3)
+
+
+def test_grid_named_line_without_index_round_trips_through_style() -> None:
+    s = waxy.Style(grid_row=waxy.GridPlacement(start=waxy.GridNamedLine("header")))
+    assert s.grid_row.start == waxy.GridNamedLine("header")
+
+
 # --- GridPlacement with new value types ---
 
 
@@ -405,6 +513,186 @@ def test_grid_placement_hash() -> None:
     assert len(s) == 2
 
 
+@pytest.mark.parametrize(
+    ("text", "expected"),
+    [
+        ("auto", waxy.GridPlacement()),
+        ("2", waxy.GridPlacement(start=waxy.GridLine(2))),
+        ("-1", waxy.GridPlacement(start=waxy.GridLine(-1))),
+        ("span 3", waxy.GridPlacement(start=waxy.GridSpan(3))),
+        ("1 / 3", waxy.GridPlacement(start=waxy.GridLine(1), end=waxy.GridLine(3))),
+        ("1 / span 2", waxy.GridPlacement(start=waxy.GridLine(1), end=waxy.GridSpan(2))),
+        ("span 2 / -1", waxy.GridPlacement(start=waxy.GridSpan(2), end=waxy.GridLine(-1))),
+        ("  auto/auto  ", waxy.GridPlacement()),
+    ],
+)
+def test_grid_placement_parse(text: str, expected: waxy.GridPlacement) -> None:
+    gp = waxy.GridPlacement.parse(text)
+    assert gp == expected
+    assert eval(repr(gp), vars(waxy)) == gp
+
+
+@pytest.mark.parametrize("text", ["", "/", "1 /", "span", "span x", "one", "1 2", "1 / 2 / 3", "header"])
+def test_grid_placement_parse_malformed(text: str) -> None:
+    with pytest.raises(ValueError, match="invalid grid placement"):
+        waxy.GridPlacement.parse(text)
+
+
+def test_grid_placement_parse_zero_line() -> None:
+    with pytest.raises(waxy.InvalidGridLine):
+        waxy.GridPlacement.parse("0")
+
+
+def test_grid_placement_parse_zero_span() -> None:
+    with pytest.raises(waxy.InvalidGridSpan):
+        waxy.GridPlacement.parse("span 0")
+
+
+# --- Grid track list parsing ---
+
+
+@pytest.mark.parametrize(
+    ("token", "expected"),
+    [
+        ("1fr", waxy.Fraction(1)),
+        ("0.5fr", waxy.Fraction(0.5)),
+        ("100px", waxy.Length(100)),
+        ("20%", waxy.Percent(0.2)),
+        ("auto", waxy.Auto()),
+        ("min-content", waxy.MinContent()),
+        ("max-content", waxy.MaxContent()),
+        ("minmax(100px, 1fr)", waxy.Minmax(waxy.Length(100), waxy.Fraction(1))),
+        ("minmax(min-content,max-content)", waxy.Minmax(waxy.MinContent(), waxy.MaxContent())),
+        ("fit-content(200px)", waxy.FitContent(waxy.Length(200))),
+        ("fit-content(50%)", waxy.FitContent(waxy.Percent(0.5))),
+    ],
+)
+def test_tracks_token(token: str, expected: waxy.GridTrackValue) -> None:
+    assert waxy.tracks(token) == [expected]
+
+
+def test_tracks_mixed_template() -> None:
+    assert waxy.tracks("  100px 1fr\tminmax(100px, 1fr) auto fit-content(25%) ") == [
+        waxy.Length(100),
+        waxy.Fraction(1),
+        waxy.Minmax(waxy.Length(100), waxy.Fraction(1)),
+        waxy.Auto(),
+        waxy.FitContent(waxy.Percent(0.25)),
+    ]
+
+
+def test_tracks_empty() -> None:
+    assert waxy.tracks("") == []
+
+
+def test_tracks_usable_as_grid_template() -> None:
+    style = waxy.Style(grid_template_columns=waxy.tracks("1fr 100px 1fr"))
+    assert style.grid_template_columns == [waxy.Fraction(1), waxy.Length(100), waxy.Fraction(1)]
+
+
+@pytest.mark.parametrize(
+    ("spec", "token"),
+    [
+        ("1fr 10em", "10em"),
+        ("100", "100"),
+        ("-1fr", "-1fr"),
+        ("nanpx", "nanpx"),
+        ("150%", "150%"),
+        ("repeat(0, 1fr)", "repeat(0, 1fr)"),
+        ("repeat(3)", "repeat(3)"),
+        ("repeat(auto-fill, repeat(2, 1fr))", "repeat(auto-fill, repeat(2, 1fr))"),
+        ("minmax(1fr, 100px)", "minmax(1fr, 100px)"),
+        ("minmax(100px)", "minmax(100px)"),
+        ("fit-content(1fr)", "fit-content(1fr)"),
+        ("1fr minmax(1px, 2px", None),
+        ("1fr)", None),
+    ],
+)
+def test_tracks_invalid(spec: str, token: str | None) -> None:
+    with pytest.raises(ValueError) as exc_info:
+        waxy.tracks(spec)
+    if token is None:
+        assert "unbalanced parentheses" in str(exc_info.value)
+    else:
+        assert f'invalid track "{token}"' in str(exc_info.value)
+
+
+# --- Repeat ---
+
+
+def test_repeat_fixed_count_round_trips_through_style() -> None:
+    repeat = waxy.Repeat(3, [waxy.Length(100)])
+    style = waxy.Style(grid_template_columns=[waxy.Fraction(1), repeat])
+
+    assert style.grid_template_columns == [waxy.Fraction(1), repeat]
+    assert waxy.Style.from_dict(style.to_dict()) == style
+    assert waxy.Style.from_css(style.to_css()) == style
+
+
+def test_repeat_fields_and_repr() -> None:
+    repeat = waxy.Repeat(waxy.AUTO_FILL, [waxy.Length(100), waxy.Fraction(1)])
+    assert repeat.count == waxy.AutoFill()
+    assert repeat.tracks == [waxy.Length(100), waxy.Fraction(1)]
+    assert repr(repeat) == "Repeat(AutoFill(), [Length(100), Fraction(1)])"
+    match repeat:
+        case waxy.Repeat(waxy.AutoFill(), [first, _]):
+            assert first == waxy.Length(100)
+        case _:
+            pytest.fail("Repeat did not match")
+
+
+def test_repeat_eq_and_hash() -> None:
+    a = waxy.Repeat(2, [waxy.Length(10)])
+    assert a == waxy.Repeat(2, [waxy.Length(10)])
+    assert hash(a) == hash(waxy.Repeat(2, [waxy.Length(10)]))
+    assert a != waxy.Repeat(3, [waxy.Length(10)])
+    assert waxy.Repeat(waxy.AUTO_FILL, [waxy.Length(10)]) != waxy.Repeat(
+        waxy.AUTO_FIT, [waxy.Length(10)]
+    )
+
+
+@pytest.mark.parametrize("count", [0, -1, 65536])
+def test_repeat_invalid_count(count: int) -> None:
+    with pytest.raises(ValueError, match="Repeat count"):
+        waxy.Repeat(count, [waxy.Length(10)])
+
+
+def test_repeat_requires_tracks() -> None:
+    with pytest.raises(ValueError, match="at least one track"):
+        waxy.Repeat(2, [])
+
+
+def test_tracks_parses_repeat() -> None:
+    assert waxy.tracks("repeat(3, 100px) repeat(auto-fit, minmax(50px, 1fr) 10%)") == [
+        waxy.Repeat(3, [waxy.Length(100)]),
+        waxy.Repeat(
+            waxy.AUTO_FIT,
+            [waxy.Minmax(waxy.Length(50), waxy.Fraction(1)), waxy.Percent(0.1)],
+        ),
+    ]
+
+
+def test_repeat_not_allowed_in_auto_tracks() -> None:
+    with pytest.raises(TypeError):
+        waxy.Style(grid_auto_rows=[waxy.Repeat(2, [waxy.Length(10)])])  # type: ignore[list-item]
+
+
+def test_auto_fill_repeat_lays_out_as_many_tracks_as_fit() -> None:
+    tree = waxy.TaffyTree()
+    items = [tree.new_leaf(waxy.Style()) for _ in range(4)]
+    root = tree.new_with_children(
+        waxy.Style(
+            display=waxy.Display.Grid,
+            size_width=waxy.Length(350),
+            grid_template_columns=[waxy.Repeat(waxy.AUTO_FILL, [waxy.Length(100)])],
+        ),
+        items,
+    )
+    tree.compute_layout(root)
+
+    assert [tree.layout(item).location.x for item in items] == [0, 100, 200, 0]
+
+
 # --- Style construction with new types ---
 
 
@@ -581,3 +869,71 @@ def test_type_aliases_importable() -> None:
     assert hasattr(waxy, "GridTrackMinValue")
     assert hasattr(waxy, "GridTrackMaxValue")
     assert hasattr(waxy, "GridPlacementValue")
+
+
+@pytest.mark.parametrize(
+    "value",
+    [
+        waxy.Length(10.0),
+        waxy.Percent(0.5),
+        waxy.Auto(),
+        waxy.MinContent(),
+        waxy.MaxContent(),
+        waxy.Definite(3.0),
+        waxy.Fraction(2.0),
+        waxy.FitContent(waxy.Length(100.0)),
+        waxy.Minmax(waxy.MIN_CONTENT, waxy.Fraction(1.0)),
+        waxy.GridLine(-1),
+        waxy.GridSpan(2),
+        waxy.GridNamedLine("header", 2),
+        waxy.GridNamedSpan("footer"),
+        waxy.GridPlacement(start=waxy.GridLine(1), end=waxy.GridSpan(3)),
+    ],
+)
+def test_value_pickle_round_trip(value: object) -> None:
+    assert pickle.loads(pickle.dumps(value)) == value
+
+
+@pytest.mark.parametrize(
+    ("value", "expected"),
+    [
+        (waxy.Length(-5.0), waxy.Length(5.0)),
+        (waxy.Length(5.0), waxy.Length(5.0)),
+        (waxy.Definite(-12.5), waxy.Definite(12.5)),
+        (waxy.Fraction(-2.0), waxy.Fraction(2.0)),
+        (waxy.Percent(0.5), waxy.Percent(0.5)),
+    ],
+)
+def test_value_abs(
+    value: waxy.Length | waxy.Definite | waxy.Fraction | waxy.Percent, expected: object
+) -> None:
+    result = abs(value)
+    assert type(result) is type(expected)
+    assert result == expected
+
+
+def test_length_sorting() -> None:
+    lengths = [waxy.Length(30.0), waxy.Length(-5.0), waxy.Length(10.0)]
+    assert sorted(lengths) == [waxy.Length(-5.0), waxy.Length(10.0), waxy.Length(30.0)]
+
+
+@pytest.mark.parametrize("cls", [waxy.Length, waxy.Percent, waxy.Definite, waxy.Fraction])
+def test_value_ordering(cls: type[waxy.Length]) -> None:
+    small, large = cls(0.25), cls(0.5)
+    assert small < large
+    assert small <= large
+    assert small <= cls(0.25)
+    assert large > small
+    assert large >= small
+    assert not large < small
+
+
+def test_value_ordering_across_types_raises() -> None:
+    with pytest.raises(TypeError):
+        _ = waxy.Length(0.5) < waxy.Percent(0.5)  # type: ignore[operator]
+
+
+def test_value_ordering_nan_follows_float_semantics() -> None:
+    nan = waxy.Definite(float("nan"))
+    assert not nan < waxy.Definite(1.0)
+    assert not nan >= waxy.Definite(1.0)